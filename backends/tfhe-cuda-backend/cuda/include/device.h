@@ -42,6 +42,8 @@ void cuda_destroy_stream(cudaStream_t stream, uint32_t gpu_index);
 
 void cuda_synchronize_stream(cudaStream_t stream, uint32_t gpu_index);
 
+uint32_t cuda_try_synchronize_stream(cudaStream_t stream, uint32_t gpu_index);
+
 uint32_t cuda_is_available();
 
 void *cuda_malloc(uint64_t size, uint32_t gpu_index);
@@ -50,6 +52,9 @@ void *cuda_malloc_async(uint64_t size, cudaStream_t stream, uint32_t gpu_index);
 
 void cuda_check_valid_malloc(uint64_t size, uint32_t gpu_index);
 
+uint32_t cuda_get_mem_info(uint32_t gpu_index, uint64_t *free_mem,
+                           uint64_t *total_mem);
+
 void cuda_memcpy_async_to_gpu(void *dest, void *src, uint64_t size,
                               cudaStream_t stream, uint32_t gpu_index);
 
@@ -72,6 +77,10 @@ void cuda_synchronize_device(uint32_t gpu_index);
 void cuda_drop(void *ptr, uint32_t gpu_index);
 
 void cuda_drop_async(void *ptr, cudaStream_t stream, uint32_t gpu_index);
+
+void *cuda_malloc_host(uint64_t size);
+
+void cuda_drop_host(void *ptr);
 }
 
 int cuda_get_max_shared_memory(uint32_t gpu_index);
@@ -4564,4 +4564,77 @@ template <typename Torus> struct int_div_rem_memory {
   }
 };
 
+template <typename Torus> struct int_count_ones_buffer {
+  int_radix_params params;
+
+  int_radix_lut<Torus> *popcount_lut;
+  int_sum_ciphertexts_vec_memory<Torus> *sum_ciphertexts_vec_mem;
+  int_sc_prop_memory<Torus> *sc_prop_mem;
+
+  // Number of set bits of each input block
+  Torus *block_counts;
+  // One radix of num_output_blocks per input block, whose first block holds
+  // the count of the corresponding input block and the others are zero
+  Torus *terms;
+
+  int_count_ones_buffer(cudaStream_t const *streams,
+                        uint32_t const *gpu_indexes, uint32_t gpu_count,
+                        int_radix_params params, uint32_t num_input_blocks,
+                        uint32_t num_output_blocks, bool allocate_gpu_memory) {
+    this->params = params;
+
+    if (allocate_gpu_memory) {
+      uint32_t lwe_size_bytes =
+          (params.big_lwe_dimension + 1) * sizeof(Torus);
+
+      block_counts = (Torus *)cuda_malloc_async(
+          num_input_blocks * lwe_size_bytes, streams[0], gpu_indexes[0]);
+      terms = (Torus *)cuda_malloc_async(num_input_blocks * num_output_blocks *
+                                             lwe_size_bytes,
+                                         streams[0], gpu_indexes[0]);
+      cuda_memset_async(terms, 0,
+                        num_input_blocks * num_output_blocks * lwe_size_bytes,
+                        streams[0], gpu_indexes[0]);
+
+      popcount_lut =
+          new int_radix_lut<Torus>(streams, gpu_indexes, gpu_count, params, 1,
+                                   num_input_blocks, allocate_gpu_memory);
+      auto message_modulus = params.message_modulus;
+      auto popcount_f = [message_modulus](Torus x) -> Torus {
+        Torus count = 0;
+        for (Torus msg = x % message_modulus; msg != 0; msg >>= 1)
+          count += msg & 1;
+        return count;
+      };
+      generate_device_accumulator<Torus>(
+          streams[0], gpu_indexes[0], popcount_lut->get_lut(0, 0),
+          params.glwe_dimension, params.polynomial_size, params.message_modulus,
+          params.carry_modulus, popcount_f);
+      popcount_lut->broadcast_lut(streams, gpu_indexes, 0);
+
+      sum_ciphertexts_vec_mem = new int_sum_ciphertexts_vec_memory<Torus>(
+          streams, gpu_indexes, gpu_count, params, num_output_blocks,
+          num_input_blocks, allocate_gpu_memory);
+      uint32_t uses_carry = 0;
+      uint32_t requested_flag = outputFlag::FLAG_NONE;
+      sc_prop_mem = new int_sc_prop_memory<Torus>(
+          streams, gpu_indexes, gpu_count, params, num_output_blocks,
+          requested_flag, uses_carry, allocate_gpu_memory);
+    }
+  }
+
+  void release(cudaStream_t const *streams, uint32_t const *gpu_indexes,
+               uint32_t gpu_count) {
+    popcount_lut->release(streams, gpu_indexes, gpu_count);
+    sum_ciphertexts_vec_mem->release(streams, gpu_indexes, gpu_count);
+    sc_prop_mem->release(streams, gpu_indexes, gpu_count);
+    delete popcount_lut;
+    delete sum_ciphertexts_vec_mem;
+    delete sc_prop_mem;
+
+    cuda_drop_async(block_counts, streams[0], gpu_indexes[0]);
+    cuda_drop_async(terms, streams[0], gpu_indexes[0]);
+  }
+};
+
 #endif // CUDA_INTEGER_UTILITIES_H
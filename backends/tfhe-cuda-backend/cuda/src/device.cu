@@ -45,6 +45,19 @@ void cuda_synchronize_stream(cudaStream_t stream, uint32_t gpu_index) {
   check_cuda_error(cudaStreamSynchronize(stream));
 }
 
+/// Synchronizes the stream and returns the CUDA error code, 0 on success.
+/// Unlike cuda_synchronize_stream it does not abort on error, the error is
+/// cleared so that it is not reported by later calls (errors that corrupt the
+/// context cannot be cleared and will still be reported)
+uint32_t cuda_try_synchronize_stream(cudaStream_t stream, uint32_t gpu_index) {
+  cudaError_t code = cudaSetDevice(gpu_index);
+  if (code == cudaSuccess)
+    code = cudaStreamSynchronize(stream);
+  if (code != cudaSuccess)
+    cudaGetLastError();
+  return (uint32_t)code;
+}
+
 // Determine if a CUDA device is available at runtime
 uint32_t cuda_is_available() { return cudaSetDevice(0) == cudaSuccess; }
 
@@ -96,6 +109,23 @@ void cuda_check_valid_malloc(uint64_t size, uint32_t gpu_index) {
   }
 }
 
+/// Writes the free and total memory of the device, in bytes.
+/// Unlike the other functions it does not abort on error, it returns 0 if the
+/// memory info could not be queried (e.g. invalid gpu_index) and 1 otherwise
+uint32_t cuda_get_mem_info(uint32_t gpu_index, uint64_t *free_mem,
+                           uint64_t *total_mem) {
+  size_t free_bytes, total_bytes;
+  if (cudaSetDevice(gpu_index) != cudaSuccess ||
+      cudaMemGetInfo(&free_bytes, &total_bytes) != cudaSuccess) {
+    // Clear the error so that it is not reported by later calls
+    cudaGetLastError();
+    return 0;
+  }
+  *free_mem = free_bytes;
+  *total_mem = total_bytes;
+  return 1;
+}
+
 /// Returns
 ///  false if Cooperative Groups is not supported.
 ///  true otherwise
@@ -292,6 +322,18 @@ void cuda_drop_async(void *ptr, cudaStream_t stream, uint32_t gpu_index) {
 #endif
 }
 
+/// Allocates a size-byte array of page-locked host memory, usable by all the
+/// GPUs for faster transfers
+void *cuda_malloc_host(uint64_t size) {
+  void *ptr;
+  check_cuda_error(cudaHostAlloc((void **)&ptr, size, cudaHostAllocPortable));
+
+  return ptr;
+}
+
+/// Drop a page-locked host array
+void cuda_drop_host(void *ptr) { check_cuda_error(cudaFreeHost(ptr)); }
+
 /// Get the maximum size for the shared memory
 int cuda_get_max_shared_memory(uint32_t gpu_index) {
   int max_shared_memory = 0;
@@ -0,0 +1,91 @@
+#include "integer/count_ones.cuh"
+
+void scratch_cuda_integer_count_ones_kb_64(
+    void *const *streams, uint32_t const *gpu_indexes, uint32_t gpu_count,
+    int8_t **mem_ptr, uint32_t glwe_dimension, uint32_t polynomial_size,
+    uint32_t big_lwe_dimension, uint32_t small_lwe_dimension, uint32_t ks_level,
+    uint32_t ks_base_log, uint32_t pbs_level, uint32_t pbs_base_log,
+    uint32_t grouping_factor, uint32_t num_input_blocks,
+    uint32_t num_output_blocks, uint32_t message_modulus,
+    uint32_t carry_modulus, PBS_TYPE pbs_type, bool allocate_gpu_memory) {
+
+  int_radix_params params(pbs_type, glwe_dimension, polynomial_size,
+                          big_lwe_dimension, small_lwe_dimension, ks_level,
+                          ks_base_log, pbs_level, pbs_base_log, grouping_factor,
+                          message_modulus, carry_modulus);
+
+  scratch_cuda_integer_count_ones_kb<uint64_t>(
+      (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+      (int_count_ones_buffer<uint64_t> **)mem_ptr, num_input_blocks,
+      num_output_blocks, params, allocate_gpu_memory);
+}
+
+void cuda_integer_count_ones_kb_64(
+    void *const *streams, uint32_t const *gpu_indexes, uint32_t gpu_count,
+    void *lwe_array_out, void const *lwe_array_in, int8_t *mem_ptr,
+    void *const *bsks, void *const *ksks, uint32_t polynomial_size,
+    uint32_t num_input_blocks, uint32_t num_output_blocks) {
+
+  switch (polynomial_size) {
+  case 512:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<512>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  case 1024:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<1024>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  case 2048:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<2048>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  case 4096:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<4096>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  case 8192:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<8192>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  case 16384:
+    host_integer_count_ones_kb<uint64_t, AmortizedDegree<16384>>(
+        (cudaStream_t *)(streams), gpu_indexes, gpu_count,
+        static_cast<uint64_t *>(lwe_array_out),
+        static_cast<const uint64_t *>(lwe_array_in),
+        (int_count_ones_buffer<uint64_t> *)mem_ptr, bsks, (uint64_t **)(ksks),
+        num_input_blocks, num_output_blocks);
+    break;
+  default:
+    PANIC("Cuda error (count ones): unsupported polynomial size. "
+          "Only N = 512, 1024, 2048, 4096, 8192, 16384 are supported.")
+  }
+}
+
+void cleanup_cuda_integer_count_ones(void *const *streams,
+                                     uint32_t const *gpu_indexes,
+                                     uint32_t gpu_count,
+                                     int8_t **mem_ptr_void) {
+  int_count_ones_buffer<uint64_t> *mem_ptr =
+      (int_count_ones_buffer<uint64_t> *)(*mem_ptr_void);
+  mem_ptr->release((cudaStream_t *)(streams), gpu_indexes, gpu_count);
+}
@@ -0,0 +1,65 @@
+#ifndef TFHE_RS_COUNT_ONES_CUH
+#define TFHE_RS_COUNT_ONES_CUH
+
+#include "device.h"
+#include "integer/integer.cuh"
+#include "integer/integer_utilities.h"
+#include "integer/multiplication.cuh"
+
+template <typename Torus>
+__host__ void scratch_cuda_integer_count_ones_kb(
+    cudaStream_t const *streams, uint32_t const *gpu_indexes,
+    uint32_t gpu_count, int_count_ones_buffer<Torus> **mem_ptr,
+    uint32_t num_input_blocks, uint32_t num_output_blocks,
+    int_radix_params params, bool allocate_gpu_memory) {
+
+  *mem_ptr = new int_count_ones_buffer<Torus>(
+      streams, gpu_indexes, gpu_count, params, num_input_blocks,
+      num_output_blocks, allocate_gpu_memory);
+}
+
+/// Computes the number of bits set in the message of lwe_array_in, and
+/// writes it to lwe_array_out as a radix of num_output_blocks blocks.
+/// The input blocks must not hold any carry.
+template <typename Torus, class params>
+__host__ void host_integer_count_ones_kb(
+    cudaStream_t const *streams, uint32_t const *gpu_indexes,
+    uint32_t gpu_count, Torus *lwe_array_out, Torus const *lwe_array_in,
+    int_count_ones_buffer<Torus> *mem, void *const *bsks,
+    uint64_t *const *ksks, uint32_t num_input_blocks,
+    uint32_t num_output_blocks) {
+
+  auto big_lwe_size = mem->params.big_lwe_dimension + 1;
+  auto big_lwe_size_bytes = big_lwe_size * sizeof(Torus);
+
+  integer_radix_apply_univariate_lookup_table_kb<Torus>(
+      streams, gpu_indexes, gpu_count, mem->block_counts, lwe_array_in, bsks,
+      ksks, num_input_blocks, mem->popcount_lut);
+
+  // Each count goes in the least significant block of its own term, the other
+  // blocks of the terms were zeroed at allocation and are never written
+  for (uint i = 0; i < num_input_blocks; i++) {
+    cuda_memcpy_async_gpu_to_gpu(
+        mem->terms + i * num_output_blocks * big_lwe_size,
+        mem->block_counts + i * big_lwe_size, big_lwe_size_bytes, streams[0],
+        gpu_indexes[0]);
+  }
+
+  int terms_degree[num_input_blocks * num_output_blocks];
+  for (int i = 0; i < num_input_blocks * num_output_blocks; i++) {
+    terms_degree[i] = mem->params.message_modulus - 1;
+  }
+  host_integer_partial_sum_ciphertexts_vec_kb<Torus, params>(
+      streams, gpu_indexes, gpu_count, lwe_array_out, mem->terms,
+      terms_degree, bsks, ksks, mem->sum_ciphertexts_vec_mem,
+      num_output_blocks, num_input_blocks, nullptr);
+
+  uint32_t requested_flag = outputFlag::FLAG_NONE;
+  uint32_t uses_carry = 0;
+  host_propagate_single_carry<Torus>(
+      streams, gpu_indexes, gpu_count, lwe_array_out, nullptr, nullptr,
+      mem->sc_prop_mem, bsks, ksks, num_output_blocks, requested_flag,
+      uses_carry);
+}
+
+#endif // TFHE_RS_COUNT_ONES_CUH
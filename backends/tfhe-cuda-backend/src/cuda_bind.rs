@@ -9,6 +9,8 @@ extern "C" {
 
     pub fn cuda_synchronize_stream(stream: *mut c_void, gpu_index: u32);
 
+    pub fn cuda_try_synchronize_stream(stream: *mut c_void, gpu_index: u32) -> u32;
+
     pub fn cuda_is_available() -> u32;
 
     pub fn cuda_malloc(size: u64, gpu_index: u32) -> *mut c_void;
@@ -17,6 +19,8 @@ extern "C" {
 
     pub fn cuda_check_valid_malloc(size: u64, gpu_index: u32);
 
+    pub fn cuda_get_mem_info(gpu_index: u32, free_mem: *mut u64, total_mem: *mut u64) -> u32;
+
     pub fn cuda_memcpy_async_to_gpu(
         dest: *mut c_void,
         src: *const c_void,
@@ -59,6 +63,10 @@ extern "C" {
 
     pub fn cuda_drop_async(ptr: *mut c_void, stream: *mut c_void, gpu_index: u32);
 
+    pub fn cuda_malloc_host(size: u64) -> *mut c_void;
+
+    pub fn cuda_drop_host(ptr: *mut c_void);
+
     pub fn cuda_setup_multi_gpu() -> i32;
 
 } // extern "C"
@@ -1312,6 +1312,7 @@ mod cuda {
     use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
     use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
     use tfhe::integer::gpu::server_key::CudaServerKey;
+    use tfhe::integer::gpu::ComparisonType;
     use tfhe_csprng::seeders::Seed;
 
     fn bench_cuda_server_key_unary_function_clean_inputs<F>(
@@ -1732,6 +1733,314 @@ mod cuda {
         bench_group.finish()
     }
 
+    fn bench_cuda_server_key_sum_function<F>(
+        c: &mut Criterion,
+        bench_name: &str,
+        display_name: &str,
+        sum_op: F,
+    ) where
+        F: Fn(&CudaServerKey, Vec<CudaUnsignedRadixCiphertext>, &CudaStreams) + Sync,
+    {
+        const NUM_TERMS: usize = 100;
+
+        let mut bench_group = c.benchmark_group(bench_name);
+        bench_group
+            .sample_size(15)
+            .measurement_time(std::time::Duration::from_secs(60));
+        let mut rng = rand::thread_rng();
+
+        let streams = CudaStreams::new_multi_gpu();
+
+        for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
+            let param_name = param.name();
+
+            let bench_id;
+
+            match BENCH_TYPE.get().unwrap() {
+                BenchmarkType::Latency => {
+                    bench_id =
+                        format!("{bench_name}_{NUM_TERMS}_ctxts::{param_name}::{bit_size}_bits");
+
+                    bench_group.bench_function(&bench_id, |b| {
+                        let (cks, _cpu_sks) =
+                            KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+                        let gpu_sks = CudaServerKey::new(&cks, &streams);
+
+                        let encrypt_terms = || {
+                            (0..NUM_TERMS)
+                                .map(|_| {
+                                    let ct =
+                                        cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                                    CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                                        &ct, &streams,
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        };
+
+                        b.iter_batched(
+                            encrypt_terms,
+                            |terms| {
+                                sum_op(&gpu_sks, terms, &streams);
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+                }
+                BenchmarkType::Throughput => {
+                    bench_id = format!(
+                        "{bench_name}_{NUM_TERMS}_ctxts::throughput::{param_name}::{bit_size}_bits"
+                    );
+                    bench_group
+                        .sample_size(10)
+                        .measurement_time(std::time::Duration::from_secs(30));
+                    let elements = throughput_num_threads(num_block);
+                    bench_group.throughput(Throughput::Elements(elements));
+                    bench_group.bench_function(&bench_id, |b| {
+                        let (cks, _cpu_sks) =
+                            KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+                        let num_gpus = streams.gpu_indexes.len();
+
+                        // Each element is summed on its own GPU, with the key of that GPU
+                        let gpu_sks_vec = streams
+                            .gpu_indexes
+                            .iter()
+                            .map(|&gpu_index| {
+                                let gpu_streams = CudaStreams::new_single_gpu(gpu_index);
+                                CudaServerKey::new(&cks, &gpu_streams)
+                            })
+                            .collect::<Vec<_>>();
+                        let local_streams = (0..elements as usize)
+                            .map(|i| CudaStreams::new_single_gpu(streams.gpu_indexes[i % num_gpus]))
+                            .collect::<Vec<_>>();
+
+                        let encrypt_terms = || {
+                            local_streams
+                                .iter()
+                                .map(|local_stream| {
+                                    (0..NUM_TERMS)
+                                        .map(|_| {
+                                            let ct = cks.encrypt_radix(
+                                                gen_random_u256(&mut rng),
+                                                num_block,
+                                            );
+                                            CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                                                &ct,
+                                                local_stream,
+                                            )
+                                        })
+                                        .collect::<Vec<_>>()
+                                })
+                                .collect::<Vec<_>>()
+                        };
+
+                        b.iter_batched(
+                            encrypt_terms,
+                            |all_terms| {
+                                all_terms
+                                    .into_par_iter()
+                                    .zip(local_streams.par_iter())
+                                    .enumerate()
+                                    .for_each(|(i, (terms, local_stream))| {
+                                        sum_op(&gpu_sks_vec[i % num_gpus], terms, local_stream);
+                                    })
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+                }
+            }
+
+            write_to_json::<u64, _>(
+                &bench_id,
+                param,
+                param.name(),
+                display_name,
+                &OperatorType::Atomic,
+                bit_size as u32,
+                vec![param.message_modulus().0.ilog2(); num_block],
+            );
+        }
+
+        bench_group.finish()
+    }
+
+    fn cuda_sum_ciphertexts(c: &mut Criterion) {
+        bench_cuda_server_key_sum_function(
+            c,
+            "integer::cuda::sum_ciphertexts",
+            "sum_ctxts",
+            |server_key, terms, streams| {
+                server_key.sum_ciphertexts(terms, streams);
+            },
+        )
+    }
+
+    // Baseline for `cuda_sum_ciphertexts`: the same sum computed with one addition per term
+    fn cuda_sum_ciphertexts_with_add(c: &mut Criterion) {
+        bench_cuda_server_key_sum_function(
+            c,
+            "integer::cuda::sum_ciphertexts_with_add",
+            "sum_ctxts_with_add",
+            |server_key, terms, streams| {
+                let mut terms = terms.into_iter();
+                let mut acc = terms.next().unwrap();
+                for term in terms {
+                    server_key.add_assign(&mut acc, &term, streams);
+                }
+            },
+        )
+    }
+
+    fn bench_cuda_server_key_comparison_loop_function<F>(
+        c: &mut Criterion,
+        bench_name: &str,
+        display_name: &str,
+        loop_op: F,
+    ) where
+        F: Fn(
+                &CudaServerKey,
+                &CudaUnsignedRadixCiphertext,
+                &CudaUnsignedRadixCiphertext,
+                usize,
+                &CudaStreams,
+            ) + Sync,
+    {
+        const NUM_OPS: usize = 100;
+
+        let mut bench_group = c.benchmark_group(bench_name);
+        bench_group
+            .sample_size(15)
+            .measurement_time(std::time::Duration::from_secs(60));
+        let mut rng = rand::thread_rng();
+
+        let streams = CudaStreams::new_multi_gpu();
+
+        for (param, num_block, bit_size) in ParamsAndNumBlocksIter::default() {
+            let param_name = param.name();
+
+            let bench_id;
+
+            match BENCH_TYPE.get().unwrap() {
+                BenchmarkType::Latency => {
+                    bench_id = format!("{bench_name}_{NUM_OPS}_ops::{param_name}::{bit_size}_bits");
+
+                    bench_group.bench_function(&bench_id, |b| {
+                        let (cks, _cpu_sks) =
+                            KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+                        let gpu_sks = CudaServerKey::new(&cks, &streams);
+
+                        let encrypt_two_values = || {
+                            let ct_0 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                            let ct_1 = cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                            (
+                                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct_0, &streams),
+                                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct_1, &streams),
+                            )
+                        };
+
+                        b.iter_batched(
+                            encrypt_two_values,
+                            |(ct_0, ct_1)| {
+                                loop_op(&gpu_sks, &ct_0, &ct_1, NUM_OPS, &streams);
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+                }
+                BenchmarkType::Throughput => {
+                    bench_id = format!(
+                        "{bench_name}_{NUM_OPS}_ops::throughput::{param_name}::{bit_size}_bits"
+                    );
+                    bench_group
+                        .sample_size(10)
+                        .measurement_time(std::time::Duration::from_secs(30));
+                    let elements = throughput_num_threads(num_block);
+                    bench_group.throughput(Throughput::Elements(elements));
+                    bench_group.bench_function(&bench_id, |b| {
+                        let (cks, _cpu_sks) =
+                            KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+                        let gpu_sks = CudaServerKey::new(&cks, &streams);
+
+                        let encrypt_values = || {
+                            (0..elements)
+                                .map(|_| {
+                                    let ct_0 =
+                                        cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                                    let ct_1 =
+                                        cks.encrypt_radix(gen_random_u256(&mut rng), num_block);
+                                    (
+                                        CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                                            &ct_0, &streams,
+                                        ),
+                                        CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                                            &ct_1, &streams,
+                                        ),
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        };
+
+                        b.iter_batched(
+                            encrypt_values,
+                            |cts| {
+                                cts.par_iter().for_each(|(ct_0, ct_1)| {
+                                    loop_op(&gpu_sks, ct_0, ct_1, NUM_OPS, &streams);
+                                })
+                            },
+                            criterion::BatchSize::SmallInput,
+                        )
+                    });
+                }
+            }
+
+            write_to_json::<u64, _>(
+                &bench_id,
+                param,
+                param.name(),
+                display_name,
+                &OperatorType::Atomic,
+                bit_size as u32,
+                vec![param.message_modulus().0.ilog2(); num_block],
+            );
+        }
+
+        bench_group.finish()
+    }
+
+    // Baseline for `cuda_unchecked_eq_with_scratch`: buffers are allocated and released by
+    // every comparison
+    fn cuda_unchecked_eq_loop(c: &mut Criterion) {
+        bench_cuda_server_key_comparison_loop_function(
+            c,
+            "integer::cuda::unchecked_eq_loop",
+            "eq_loop",
+            |server_key, lhs, rhs, num_ops, streams| {
+                for _ in 0..num_ops {
+                    server_key.unchecked_eq(lhs, rhs, streams);
+                }
+            },
+        )
+    }
+
+    fn cuda_unchecked_eq_with_scratch(c: &mut Criterion) {
+        bench_cuda_server_key_comparison_loop_function(
+            c,
+            "integer::cuda::unchecked_eq_with_scratch",
+            "eq_with_scratch",
+            |server_key, lhs, rhs, num_ops, streams| {
+                let mut scratch = server_key.comparison_scratch::<CudaUnsignedRadixCiphertext>(
+                    ComparisonType::EQ,
+                    lhs.ciphertext.info.blocks.len(),
+                    streams,
+                );
+                for _ in 0..num_ops {
+                    server_key.unchecked_comparison_with_scratch(lhs, rhs, &mut scratch, streams);
+                }
+            },
+        )
+    }
+
     pub fn cuda_unsigned_oprf(c: &mut Criterion) {
         let bench_name = "integer::cuda::unsigned_oprf";
 
@@ -2360,6 +2669,12 @@ mod cuda {
         rng_func: div_scalar
     );
 
+    define_cuda_server_key_bench_clean_input_scalar_fn!(
+        method_name: scalar_div_floor,
+        display_name: div_floor,
+        rng_func: div_scalar
+    );
+
     define_cuda_server_key_bench_clean_input_scalar_fn!(
         method_name: scalar_rem,
         display_name: modulo,
@@ -2389,6 +2704,8 @@ mod cuda {
         cuda_unchecked_add,
         cuda_unchecked_eq,
         cuda_unchecked_ne,
+        cuda_unchecked_eq_loop,
+        cuda_unchecked_eq_with_scratch,
         cuda_unchecked_left_shift,
         cuda_unchecked_right_shift,
         cuda_unchecked_rotate_left,
@@ -2455,6 +2772,8 @@ mod cuda {
         cuda_trailing_zeros,
         cuda_trailing_ones,
         cuda_ilog2,
+        cuda_sum_ciphertexts,
+        cuda_sum_ciphertexts_with_add,
         cuda_unsigned_oprf,
     );
 
@@ -2498,8 +2817,9 @@ mod cuda {
         cuda_scalar_lt,
         cuda_scalar_max,
         cuda_scalar_min,
-        //cuda_scalar_div_rem,
+        cuda_scalar_div_rem,
         cuda_scalar_div,
+        cuda_scalar_div_floor,
         cuda_scalar_rem,
         cuda_unsigned_overflowing_scalar_add,
     );
@@ -0,0 +1,73 @@
+#[path = "../utilities.rs"]
+mod utilities;
+
+use crate::utilities::{write_to_json, OperatorType};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tfhe::integer::keycache::KEY_CACHE;
+use tfhe::integer::{IntegerCiphertext, IntegerKeyKind};
+use tfhe::keycache::NamedParam;
+use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+use tfhe::shortint::PBSParameters;
+use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+
+// (str, from, to)
+const REPLACE_CASES: [(&str, &str, &str); 3] = [
+    ("aaaaaa", "a", "bb"),
+    ("abcabcabc", "bc", "d"),
+    ("banana", "an", "AN"),
+];
+
+fn replace(c: &mut Criterion) {
+    let bench_name = "strings::replace";
+    let mut bench_group = c.benchmark_group(bench_name);
+    bench_group
+        .sample_size(10)
+        .measurement_time(std::time::Duration::from_secs(30));
+
+    let param: PBSParameters = PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64.into();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let ck = tfhe::strings::ClientKey::new(cks);
+    let sk = tfhe::strings::ServerKey::new(sks);
+
+    for (str, from, to) in REPLACE_CASES {
+        let expected = str.replace(from, to);
+
+        let enc_str = FheString::new(&ck, str, None);
+        let enc_to = FheString::new(&ck, to, None);
+
+        let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
+        let enc_from = GenericPattern::Enc(FheString::new(&ck, from, None));
+
+        for (pattern_kind, pattern) in [("clear", clear_from), ("encrypted", enc_from)] {
+            let result = sk.replace(&enc_str, pattern.as_ref(), &enc_to);
+            assert_eq!(ck.decrypt_ascii(&result), expected);
+
+            let bench_id = format!("{bench_name}::{pattern_kind}::{str}::{from}::{to}");
+            bench_group.bench_function(&bench_id, |b| {
+                b.iter(|| {
+                    let result = sk.replace(&enc_str, pattern.as_ref(), &enc_to);
+
+                    _ = black_box(result);
+                })
+            });
+
+            let num_blocks = enc_str.to_uint().blocks().len();
+
+            write_to_json::<u64, _>(
+                &bench_id,
+                param,
+                param.name(),
+                "replace",
+                &OperatorType::Atomic,
+                (str.len() * 8) as u32,
+                vec![param.message_modulus().0.ilog2(); num_blocks],
+            );
+        }
+    }
+
+    bench_group.finish()
+}
+
+criterion_group!(replace_ops, replace);
+criterion_main!(replace_ops);
@@ -18,6 +18,8 @@ use tfhe_cuda_backend::cuda_bind::*;
 pub struct CudaStreams {
     pub ptr: Vec<*mut c_void>,
     pub gpu_indexes: Vec<GpuIndex>,
+    // Streams given by the caller through `from_raw` are not destroyed on drop
+    pub(crate) owned: bool,
 }
 
 #[allow(clippy::non_send_fields_in_send_ty)]
@@ -38,6 +40,7 @@ impl CudaStreams {
         Self {
             ptr: ptr_array,
             gpu_indexes,
+            owned: true,
         }
     }
     /// Create a new `CudaStreams` structure with one GPU, whose index corresponds to the one given
@@ -46,6 +49,26 @@ impl CudaStreams {
         Self {
             ptr: vec![unsafe { cuda_create_stream(gpu_index.0) }],
             gpu_indexes: vec![gpu_index],
+            owned: true,
+        }
+    }
+    /// Create a `CudaStreams` structure from a CUDA stream created by the caller on the GPU with
+    /// index `gpu_index`, so that computations can be interleaved with the caller's own kernels.
+    ///
+    /// The stream is borrowed: it is not destroyed when the returned `CudaStreams` is dropped.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` __must__ be a valid `cudaStream_t` created on the GPU with index `gpu_index`
+    /// - the stream __must__ not be destroyed while the returned `CudaStreams`, or any value
+    ///   computed asynchronously on it, is in use
+    /// - as with any `CudaStreams`, the stream __must__ be synchronized (e.g. with
+    ///   [`Self::synchronize`] or by the caller) before results are read
+    pub unsafe fn from_raw(ptr: *mut c_void, gpu_index: GpuIndex) -> Self {
+        Self {
+            ptr: vec![ptr],
+            gpu_indexes: vec![gpu_index],
+            owned: false,
         }
     }
     /// Synchronize all cuda streams in the `CudaStreams` structure
@@ -65,6 +88,27 @@ impl CudaStreams {
             );
         }
     }
+    /// Synchronize all cuda streams in the `CudaStreams` structure, returning an error instead of
+    /// aborting if the backend reports one
+    ///
+    /// All the streams are synchronized even if one of them fails, the first error is returned.
+    pub fn try_synchronize(&self) -> Result<(), CudaError> {
+        let mut result = Ok(());
+        for i in 0..self.len() {
+            let code = unsafe { cuda_try_synchronize_stream(self.ptr[i], self.gpu_indexes[i].0) };
+            if code != 0 && result.is_ok() {
+                result = Err(CudaError::from_code(self.gpu_indexes[i], code));
+            }
+        }
+        result
+    }
+    /// Return the indexes of the GPUs as raw integers, as expected by the backend functions
+    ///
+    /// This is a view of `gpu_indexes`, no allocation is done.
+    pub fn raw_gpu_indexes(&self) -> &[u32] {
+        // SAFETY: GpuIndex is a repr(transparent) wrapper around u32
+        unsafe { std::slice::from_raw_parts(self.gpu_indexes.as_ptr().cast::<u32>(), self.len()) }
+    }
     /// Return the number of GPU indexes, which is the same as the number of Cuda streams
     pub fn len(&self) -> usize {
         self.gpu_indexes.len()
@@ -73,10 +117,36 @@ impl CudaStreams {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /// Returns the free and total memory, in bytes, of the GPU with the given index
+    ///
+    /// This can be used to decide batch sizes before launching memory-hungry operations. An
+    /// error is returned if the memory cannot be queried, e.g. if there is no GPU with this index.
+    pub fn available_memory(gpu_index: GpuIndex) -> crate::Result<(u64, u64)> {
+        let mut free_mem = 0u64;
+        let mut total_mem = 0u64;
+        let success = unsafe {
+            cuda_get_mem_info(
+                gpu_index.0,
+                std::ptr::addr_of_mut!(free_mem),
+                std::ptr::addr_of_mut!(total_mem),
+            )
+        };
+        if success == 1 {
+            Ok((free_mem, total_mem))
+        } else {
+            Err(crate::Error::new(format!(
+                "Could not query the memory of GPU {}",
+                gpu_index.0
+            )))
+        }
+    }
 }
 
 impl Drop for CudaStreams {
     fn drop(&mut self) {
+        if !self.owned {
+            return;
+        }
         for (i, &s) in self.ptr.iter().enumerate() {
             unsafe {
                 cuda_destroy_stream(s, self.gpu_indexes[i].0);
@@ -85,6 +155,170 @@ impl Drop for CudaStreams {
     }
 }
 
+/// Errors reported by the fallible GPU functions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CudaError {
+    /// The GPU is estimated not to have enough free memory, in bytes, for the operation
+    OutOfMemory {
+        gpu_index: GpuIndex,
+        required: u64,
+        available: u64,
+    },
+    /// The backend failed to allocate memory on the GPU
+    BackendOutOfMemory { gpu_index: GpuIndex },
+    /// An input resides on another GPU than the one the operation runs on
+    GpuMismatch { expected: GpuIndex, got: GpuIndex },
+    /// The free memory of the GPU could not be queried
+    MemoryQuery { gpu_index: GpuIndex },
+    /// The backend reported an error, `code` is the CUDA error code
+    Backend { gpu_index: GpuIndex, code: u32 },
+}
+
+impl CudaError {
+    // Value of cudaErrorMemoryAllocation
+    const CUDA_ERROR_MEMORY_ALLOCATION: u32 = 2;
+
+    fn from_code(gpu_index: GpuIndex, code: u32) -> Self {
+        if code == Self::CUDA_ERROR_MEMORY_ALLOCATION {
+            Self::BackendOutOfMemory { gpu_index }
+        } else {
+            Self::Backend { gpu_index, code }
+        }
+    }
+}
+
+impl std::fmt::Display for CudaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfMemory {
+                gpu_index,
+                required,
+                available,
+            } => write!(
+                f,
+                "Cuda error: not enough memory on GPU {}, required: {required} bytes, \
+                available: {available} bytes",
+                gpu_index.0
+            ),
+            Self::BackendOutOfMemory { gpu_index } => {
+                write!(f, "Cuda error: out of memory on GPU {}", gpu_index.0)
+            }
+            Self::GpuMismatch { expected, got } => write!(
+                f,
+                "GPU error: all data should reside on the same GPU, \
+                expected GPU {} but got data on GPU {}",
+                expected.0, got.0
+            ),
+            Self::MemoryQuery { gpu_index } => {
+                write!(
+                    f,
+                    "Cuda error: could not query the memory of GPU {}",
+                    gpu_index.0
+                )
+            }
+            Self::Backend { gpu_index, code } => {
+                write!(f, "Cuda error: code {code} on GPU {}", gpu_index.0)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CudaError {}
+
+impl From<CudaError> for crate::Error {
+    fn from(value: CudaError) -> Self {
+        Self::new(value.to_string())
+    }
+}
+
+/// Wrapper around a value computed asynchronously on some [`CudaStreams`], that synchronizes
+/// these streams when it goes out of scope.
+///
+/// Values returned by `_async` functions are only valid once the streams they were computed on
+/// have been synchronized. Wrapping them in a `CudaSyncGuard` makes sure this synchronization
+/// happens, at the latest when the guard is dropped.
+///
+/// To keep chaining asynchronous computations on the same streams without synchronizing, the
+/// value can be taken back with [`CudaSyncGuard::into_inner_unsynchronized`].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::gpu::vec::GpuIndex;
+/// use tfhe::core_crypto::gpu::{CudaStreams, CudaSyncGuard};
+/// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+/// use tfhe::integer::gpu::gen_keys_radix_gpu;
+/// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+///
+/// let gpu_index = 0;
+/// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+///
+/// let size = 4;
+/// // Generate the client key and the server key:
+/// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+///
+/// let msg1 = 14u64;
+/// let msg2 = 97u64;
+///
+/// // Copy to GPU
+/// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+/// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+///
+/// let guard = unsafe { CudaSyncGuard::new(sks.sub_async(&d_ct2, &d_ct1, &streams), &streams) };
+///
+/// // Synchronize and get the result back
+/// let d_ct_res = guard.wait();
+///
+/// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+/// let dec_result: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(dec_result, msg2 - msg1);
+/// ```
+pub struct CudaSyncGuard<'a, T> {
+    value: Option<T>,
+    streams: &'a CudaStreams,
+}
+
+impl<'a, T> CudaSyncGuard<'a, T> {
+    /// Wraps a `value` computed asynchronously on `streams`.
+    ///
+    /// # Safety
+    ///
+    /// - `value` must only be computed on `streams`
+    /// - the inputs used to compute `value` must not be dropped until the guard is dropped or
+    ///   waited on
+    pub unsafe fn new(value: T, streams: &'a CudaStreams) -> Self {
+        Self {
+            value: Some(value),
+            streams,
+        }
+    }
+
+    /// Synchronizes the streams and returns the wrapped value
+    pub fn wait(mut self) -> T {
+        self.streams.synchronize();
+        self.value.take().unwrap()
+    }
+
+    /// Returns the wrapped value without synchronizing the streams
+    ///
+    /// # Safety
+    ///
+    /// - the streams __must__ be synchronized before the value is read from the CPU, and the
+    ///   inputs used to compute it must not be dropped until then
+    pub unsafe fn into_inner_unsynchronized(mut self) -> T {
+        self.value.take().unwrap()
+    }
+}
+
+impl<T> Drop for CudaSyncGuard<'_, T> {
+    fn drop(&mut self) {
+        // Nothing to wait for if the value was already taken back
+        if self.value.is_some() {
+            self.streams.synchronize();
+        }
+    }
+}
+
 /// Programmable bootstrap on a vector of LWE ciphertexts
 ///
 /// # Safety
@@ -593,12 +827,7 @@ pub unsafe fn negate_integer_radix_async<T: UnsignedInteger>(
 ) {
     cuda_negate_integer_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         lwe_array_out.as_mut_c_ptr(0),
         lwe_array_in.as_c_ptr(0),
@@ -715,12 +944,23 @@ pub fn is_cuda_available() -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core_crypto::gpu::vec::PinnedHostBuffer;
 
     #[test]
     fn print_gpu_info() {
         println!("Number of GPUs: {}", get_number_of_gpus());
     }
     #[test]
+    fn available_memory() {
+        let (free_mem, total_mem) = CudaStreams::available_memory(GpuIndex(0)).unwrap();
+        assert!(free_mem > 0);
+        assert!(free_mem <= total_mem);
+
+        // There is no GPU with this index
+        let gpu_count = get_number_of_gpus() as u32;
+        assert!(CudaStreams::available_memory(GpuIndex(gpu_count)).is_err());
+    }
+    #[test]
     fn allocate_and_copy() {
         let vec = vec![1_u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
         let stream = CudaStreams::new_single_gpu(GpuIndex(0));
@@ -733,4 +973,57 @@ mod tests {
             assert_eq!(vec, empty);
         }
     }
+    #[test]
+    fn allocate_and_copy_pinned() {
+        let vec = vec![1_u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let stream = CudaStreams::new_single_gpu(GpuIndex(0));
+        let h_pinned = PinnedHostBuffer::from_slice(&vec);
+        let mut h_pinned_out = PinnedHostBuffer::<u64>::new(vec.len());
+        let mut empty = vec![0_u64; vec.len()];
+        unsafe {
+            let mut d_vec: CudaVec<u64> = CudaVec::<u64>::new_async(vec.len(), &stream, 0);
+            d_vec.copy_from_cpu_async(&h_pinned, &stream, 0);
+            d_vec.copy_to_cpu_async(&mut h_pinned_out, &stream, 0);
+            // The pinned transfers must match the pageable ones
+            d_vec.copy_to_cpu_async(&mut empty, &stream, 0);
+            stream.synchronize();
+        }
+        assert_eq!(vec, &*h_pinned_out);
+        assert_eq!(vec, empty);
+    }
+    #[test]
+    fn sync_guard_synchronizes_on_drop() {
+        let vec = vec![1_u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let stream = CudaStreams::new_single_gpu(GpuIndex(0));
+        let mut empty = vec![0_u64; vec.len()];
+        unsafe {
+            let mut d_vec: CudaVec<u64> = CudaVec::<u64>::new_async(vec.len(), &stream, 0);
+            d_vec.copy_from_cpu_async(&vec, &stream, 0);
+            d_vec.copy_to_cpu_async(&mut empty, &stream, 0);
+            // No explicit synchronization, the guard does it when dropped
+            let _guard = CudaSyncGuard::new(d_vec, &stream);
+        }
+        assert_eq!(vec, empty);
+    }
+    #[test]
+    fn sync_guard_wait() {
+        let vec = vec![1_u64, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let stream = CudaStreams::new_single_gpu(GpuIndex(0));
+        let d_vec = unsafe {
+            let mut d_vec: CudaVec<u64> = CudaVec::<u64>::new_async(vec.len(), &stream, 0);
+            d_vec.copy_from_cpu_async(&vec, &stream, 0);
+            let guard = CudaSyncGuard::new(d_vec, &stream);
+            // Chain another computation on the same stream before synchronizing
+            let d_vec = guard.into_inner_unsynchronized();
+            let mut d_vec_copy: CudaVec<u64> = CudaVec::<u64>::new_async(vec.len(), &stream, 0);
+            d_vec_copy.copy_from_gpu_async(&d_vec, &stream, 0);
+            CudaSyncGuard::new((d_vec, d_vec_copy), &stream).wait().1
+        };
+        let mut empty = vec![0_u64; vec.len()];
+        unsafe {
+            d_vec.copy_to_cpu_async(&mut empty, &stream, 0);
+        }
+        stream.synchronize();
+        assert_eq!(vec, empty);
+    }
 }
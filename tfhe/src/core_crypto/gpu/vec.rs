@@ -4,13 +4,16 @@ use crate::core_crypto::prelude::Numeric;
 use std::collections::Bound::{Excluded, Included, Unbounded};
 use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 use tfhe_cuda_backend::cuda_bind::{
-    cuda_drop, cuda_malloc, cuda_malloc_async, cuda_memcpy_async_gpu_to_gpu,
-    cuda_memcpy_async_to_cpu, cuda_memcpy_async_to_gpu, cuda_memcpy_gpu_to_gpu, cuda_memset_async,
-    cuda_synchronize_device,
+    cuda_drop, cuda_drop_host, cuda_malloc, cuda_malloc_async, cuda_malloc_host,
+    cuda_memcpy_async_gpu_to_gpu, cuda_memcpy_async_to_cpu, cuda_memcpy_async_to_gpu,
+    cuda_memcpy_gpu_to_gpu, cuda_memset_async, cuda_synchronize_device,
 };
 
+// Transparent so that a slice of GpuIndex can be passed to the backend as a slice of u32
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
 pub struct GpuIndex(pub u32);
 
 /// A contiguous array type stored in the gpu memory.
@@ -455,6 +458,81 @@ impl<T: Numeric> Drop for CudaVec<T> {
     }
 }
 
+/// A contiguous array type stored in page-locked (pinned) host memory.
+///
+/// Transfers between the gpu and pinned memory do not need to go through an intermediate
+/// staging buffer, so they are faster than transfers from pageable memory, in particular for
+/// large batches of ciphertexts.
+///
+/// The buffer dereferences to a slice, so it can be given to [CudaVec::copy_from_cpu_async] and
+/// [CudaVec::copy_to_cpu_async] in place of a `Vec`.
+///
+/// Pinned memory is a limited resource of the host, so such buffers are meant to be allocated once
+/// and reused for many transfers.
+#[derive(Debug)]
+pub struct PinnedHostBuffer<T: Numeric> {
+    ptr: *mut T,
+    len: usize,
+}
+
+impl<T: Numeric> PinnedHostBuffer<T> {
+    /// Allocates a zeroed pinned buffer of `len` elements
+    pub fn new(len: usize) -> Self {
+        if len == 0 {
+            return Self {
+                ptr: std::ptr::NonNull::dangling().as_ptr(),
+                len,
+            };
+        }
+
+        let ptr = unsafe {
+            let ptr = cuda_malloc_host((len * std::mem::size_of::<T>()) as u64).cast::<T>();
+            std::ptr::write_bytes(ptr, 0, len);
+            ptr
+        };
+        Self { ptr, len }
+    }
+
+    /// Allocates a pinned buffer holding a copy of `src`
+    pub fn from_slice(src: &[T]) -> Self {
+        let mut buffer = Self::new(src.len());
+        buffer.copy_from_slice(src);
+        buffer
+    }
+}
+
+impl<T: Numeric> Deref for PinnedHostBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T: Numeric> DerefMut for PinnedHostBuffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+// SAFETY
+//
+// The buffer uniquely owns a contiguous chunk of T on the host, like a Vec.
+unsafe impl<T> Send for PinnedHostBuffer<T> where T: Send + Numeric {}
+unsafe impl<T> Sync for PinnedHostBuffer<T> where T: Sync + Numeric {}
+
+impl<T: Numeric> Drop for PinnedHostBuffer<T> {
+    /// Free the pinned memory
+    ///
+    /// The buffer must not be the source or destination of a transfer that is still running, which
+    /// the borrow of the buffer by the `_async` copies does not guarantee.
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe { cuda_drop_host(self.ptr.cast()) };
+        }
+    }
+}
+
 pub(crate) fn range_bounds_to_start_end<R>(len: usize, range: R) -> std::ops::RangeInclusive<usize>
 where
     R: std::ops::RangeBounds<usize>,
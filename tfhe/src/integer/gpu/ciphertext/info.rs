@@ -27,49 +27,16 @@ pub struct CudaRadixCiphertextInfo {
 
 impl CudaRadixCiphertextInfo {
     // Creates an iterator that return decomposed blocks of the negated
-    // value of `scalar`
-    //
-    // Returns
-    // - `None` if scalar is zero
-    // - `Some` if scalar is non-zero
-    //
-    fn create_negated_block_decomposer<T>(&self, scalar: T) -> Option<impl Iterator<Item = u8>>
+    // value of `scalar`, see [create_negated_block_decomposer]
+    pub(crate) fn create_negated_block_decomposer<T>(
+        &self,
+        scalar: T,
+    ) -> Option<impl Iterator<Item = u8>>
     where
         T: TwosComplementNegation + DecomposableInto<u8>,
     {
-        if scalar == T::ZERO {
-            return None;
-        }
         let message_modulus = self.blocks.first().unwrap().message_modulus;
-        let bits_in_message = message_modulus.0.ilog2();
-        assert!(bits_in_message <= u8::BITS);
-
-        // The whole idea behind this iterator we construct is:
-        // - to support combos of parameters and num blocks for which the total number of bits is
-        //   not a multiple of T::BITS
-        //
-        // - Support subtraction in the case the T::BITS is lower than the target ciphertext bits.
-        //   In clear rust this would require an upcast, to support that we have to do a few things
-
-        let neg_scalar = scalar.twos_complement_negation();
-
-        // If we had upcasted the scalar, its msb would be zeros (0)
-        // then they would become ones (1) after the bitwise_not (!).
-        // The only case where these msb could become 0 after the addition
-        // is if scalar == T::ZERO (=> !T::ZERO == T::MAX => T::MAX + 1 == overflow),
-        // but this case has been handled earlier.
-        let padding_bit = 1u32; // To handle when bits is not a multiple of T::BITS
-                                // All bits of message set to one
-        let pad_block = (1 << bits_in_message as u8) - 1;
-
-        let decomposer = BlockDecomposer::with_padding_bit(
-            neg_scalar,
-            bits_in_message,
-            T::cast_from(padding_bit),
-        )
-        .iter_as::<u8>()
-        .chain(std::iter::repeat(pad_block));
-        Some(decomposer)
+        create_negated_block_decomposer(scalar, message_modulus)
     }
 
     pub(crate) fn after_add(&self, other: &Self) -> Self {
@@ -144,6 +111,22 @@ impl CudaRadixCiphertextInfo {
         }
     }
 
+    pub(crate) fn after_reset_degrees(&self) -> Self {
+        Self {
+            blocks: self
+                .blocks
+                .iter()
+                .map(|left| CudaBlockInfo {
+                    degree: Degree::new(left.message_modulus.0 * left.carry_modulus.0 - 1),
+                    message_modulus: left.message_modulus,
+                    carry_modulus: left.carry_modulus,
+                    pbs_order: left.pbs_order,
+                    noise_level: left.noise_level.max(NoiseLevel::NOMINAL),
+                })
+                .collect(),
+        }
+    }
+
     pub(crate) fn after_div_rem(&self) -> Self {
         Self {
             blocks: self
@@ -651,3 +634,48 @@ impl CudaRadixCiphertextInfo {
         new_block_info
     }
 }
+
+// Creates an iterator that return decomposed blocks of the negated
+// value of `scalar`
+//
+// Returns
+// - `None` if scalar is zero
+// - `Some` if scalar is non-zero
+//
+pub(crate) fn create_negated_block_decomposer<T>(
+    scalar: T,
+    message_modulus: MessageModulus,
+) -> Option<impl Iterator<Item = u8>>
+where
+    T: TwosComplementNegation + DecomposableInto<u8>,
+{
+    if scalar == T::ZERO {
+        return None;
+    }
+    let bits_in_message = message_modulus.0.ilog2();
+    assert!(bits_in_message <= u8::BITS);
+
+    // The whole idea behind this iterator we construct is:
+    // - to support combos of parameters and num blocks for which the total number of bits is
+    //   not a multiple of T::BITS
+    //
+    // - Support subtraction in the case the T::BITS is lower than the target ciphertext bits.
+    //   In clear rust this would require an upcast, to support that we have to do a few things
+
+    let neg_scalar = scalar.twos_complement_negation();
+
+    // If we had upcasted the scalar, its msb would be zeros (0)
+    // then they would become ones (1) after the bitwise_not (!).
+    // The only case where these msb could become 0 after the addition
+    // is if scalar == T::ZERO (=> !T::ZERO == T::MAX => T::MAX + 1 == overflow),
+    // but this case has been handled earlier.
+    let padding_bit = 1u32; // To handle when bits is not a multiple of T::BITS
+                            // All bits of message set to one
+    let pad_block = (1 << bits_in_message as u8) - 1;
+
+    let decomposer =
+        BlockDecomposer::with_padding_bit(neg_scalar, bits_in_message, T::cast_from(padding_bit))
+            .iter_as::<u8>()
+            .chain(std::iter::repeat(pad_block));
+    Some(decomposer)
+}
@@ -5,10 +5,11 @@ pub mod info;
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{LweCiphertextList, LweCiphertextOwned};
+use crate::core_crypto::prelude::{LweCiphertextCount, LweCiphertextList, LweCiphertextOwned};
 use crate::integer::gpu::ciphertext::info::{CudaBlockInfo, CudaRadixCiphertextInfo};
 use crate::integer::{IntegerCiphertext, RadixCiphertext, SignedRadixCiphertext};
 use crate::shortint::Ciphertext;
+use tfhe_cuda_backend::cuda_bind::cuda_memcpy_async_gpu_to_gpu;
 
 pub trait CudaIntegerRadixCiphertext: Sized {
     const IS_SIGNED: bool;
@@ -16,6 +17,9 @@ pub trait CudaIntegerRadixCiphertext: Sized {
     fn as_mut(&mut self) -> &mut CudaRadixCiphertext;
     fn from(ct: CudaRadixCiphertext) -> Self;
 
+    /// Returns a copy of the ciphertext
+    ///
+    /// The blocks are copied from device to device, the ciphertext never goes through the host.
     fn duplicate(&self, streams: &CudaStreams) -> Self {
         Self::from(self.as_ref().duplicate(streams))
     }
@@ -263,6 +267,103 @@ impl CudaUnsignedRadixCiphertext {
     pub fn to_radix_ciphertext(&self, streams: &CudaStreams) -> RadixCiphertext {
         RadixCiphertext::from(self.ciphertext.to_cpu_blocks(streams))
     }
+
+    /// Keeps the `num_blocks` least significant blocks of the ciphertext and drops the others,
+    /// in place.
+    ///
+    /// If `num_blocks` is greater than or equal to the current number of blocks, this has no
+    /// effect.
+    ///
+    /// Nothing is copied on the GPU: the memory of the dropped blocks is only released when the
+    /// ciphertext is dropped. Unlike
+    /// [CudaServerKey::checked_cast_to](crate::integer::gpu::CudaServerKey::checked_cast_to), no
+    /// check is done on the encrypted value, the caller __must__ know that the dropped blocks
+    /// encrypt 0, otherwise the encrypted value is reduced modulo the new number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::integer::IntegerCiphertext;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// // 7 fits in the 2 least significant blocks
+    /// let msg = 7u64;
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// d_ct.truncate_blocks(2);
+    /// let ct = d_ct.to_radix_ciphertext(&streams);
+    /// assert_eq!(ct.blocks().len(), 2);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn truncate_blocks(&mut self, num_blocks: usize) {
+        let d_blocks = &mut self.ciphertext.d_blocks.0;
+        if num_blocks >= d_blocks.lwe_ciphertext_count.0 {
+            return;
+        }
+
+        d_blocks.d_vec.len = num_blocks * d_blocks.lwe_dimension.to_lwe_size().0;
+        d_blocks.lwe_ciphertext_count = LweCiphertextCount(num_blocks);
+        self.ciphertext.info.blocks.truncate(num_blocks);
+    }
+
+    /// Appends `num_blocks` trivial zero blocks as the most significant blocks of the
+    /// ciphertext, in place.
+    ///
+    /// The encrypted value is unchanged, the extra blocks give headroom for operations whose
+    /// result would not fit in the current number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::integer::IntegerCiphertext;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg = 119u64;
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// d_ct.extend_blocks(2, &streams);
+    /// let ct = d_ct.to_radix_ciphertext(&streams);
+    /// assert_eq!(ct.blocks().len(), 6);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn extend_blocks(&mut self, num_blocks: usize, streams: &CudaStreams) {
+        if num_blocks == 0 {
+            return;
+        }
+        let extended_ct = unsafe {
+            self.ciphertext
+                .extended_with_trivial_zero_blocks_msb_async(num_blocks, streams)
+        };
+        // The old blocks are the source of the copy, they are released once it is done
+        streams.synchronize();
+        self.ciphertext = extended_ct;
+    }
 }
 
 impl CudaSignedRadixCiphertext {
@@ -439,6 +540,85 @@ impl CudaRadixCiphertext {
         }
     }
 
+    /// Returns a copy of `self` with `num_blocks` trivial zero blocks appended on the MSB side
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub(crate) unsafe fn extended_with_trivial_zero_blocks_msb_async(
+        &self,
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> Self {
+        let new_num_blocks = self.d_blocks.lwe_ciphertext_count().0 + num_blocks;
+        let lwe_size = self.d_blocks.lwe_dimension().to_lwe_size().0;
+
+        let mut extended_ct_vec = CudaVec::new_async(new_num_blocks * lwe_size, streams, 0);
+        // A trivial zero block is a block whose mask and body are all zeros
+        extended_ct_vec.memset_async(0u64, streams, 0);
+        extended_ct_vec.copy_from_gpu_async(&self.d_blocks.0.d_vec, streams, 0);
+        let d_blocks = CudaLweCiphertextList::from_cuda_vec(
+            extended_ct_vec,
+            LweCiphertextCount(new_num_blocks),
+            self.d_blocks.ciphertext_modulus(),
+        );
+
+        Self {
+            d_blocks,
+            info: self
+                .info
+                .after_extend_radix_with_trivial_zero_blocks_msb(num_blocks),
+        }
+    }
+
+    /// Copies the blocks of `src` in `src_range` into `self`, starting at block `dest_start`
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub(crate) unsafe fn copy_blocks_from_async(
+        &mut self,
+        dest_start: usize,
+        src: &Self,
+        src_range: std::ops::Range<usize>,
+        streams: &CudaStreams,
+    ) {
+        if src_range.is_empty() {
+            return;
+        }
+        let num_blocks = src_range.len();
+        let dest_end = dest_start + num_blocks;
+        assert!(dest_end <= self.d_blocks.lwe_ciphertext_count().0);
+        assert!(src_range.end <= src.d_blocks.lwe_ciphertext_count().0);
+        let lwe_size = self.d_blocks.lwe_dimension().to_lwe_size().0;
+        assert_eq!(lwe_size, src.d_blocks.lwe_dimension().to_lwe_size().0);
+
+        let mut dest_slice = self
+            .d_blocks
+            .0
+            .d_vec
+            .as_mut_slice(dest_start * lwe_size..dest_end * lwe_size, 0)
+            .unwrap();
+        let src_slice = src
+            .d_blocks
+            .0
+            .d_vec
+            .as_slice(src_range.start * lwe_size..src_range.end * lwe_size, 0)
+            .unwrap();
+        let size = num_blocks * lwe_size * std::mem::size_of::<u64>();
+        cuda_memcpy_async_gpu_to_gpu(
+            dest_slice.as_mut_c_ptr(0),
+            src_slice.as_c_ptr(0),
+            size as u64,
+            streams.ptr[0],
+            streams.gpu_indexes[0].0,
+        );
+
+        self.info.blocks[dest_start..dest_end].copy_from_slice(&src.info.blocks[src_range]);
+    }
+
     fn is_equal(&self, other: &Self, streams: &CudaStreams) -> bool {
         let self_size = self.d_blocks.0.d_vec.len();
         let other_size = other.d_blocks.0.d_vec.len();
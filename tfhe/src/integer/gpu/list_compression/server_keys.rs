@@ -4,8 +4,8 @@ use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::{
-    CiphertextModulus, CiphertextModulusLog, GlweCiphertextCount, LweCiphertextCount,
-    PolynomialSize,
+    CiphertextModulus, CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount,
+    GlweCiphertextCount, LweBskGroupingFactor, LweCiphertextCount, PolynomialSize,
 };
 use crate::integer::ciphertext::DataKind;
 use crate::integer::compression_keys::CompressionKey;
@@ -13,7 +13,8 @@ use crate::integer::gpu::ciphertext::info::{CudaBlockInfo, CudaRadixCiphertextIn
 use crate::integer::gpu::ciphertext::CudaRadixCiphertext;
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    compress_integer_radix_async, cuda_memcpy_async_gpu_to_gpu, decompress_integer_radix_async,
+    compress_integer_radix_with_params_async, cuda_memcpy_async_gpu_to_gpu,
+    decompress_integer_radix_with_params_async, CudaPbsParams, PBSType,
 };
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::prelude::GlweDimension;
@@ -152,18 +153,25 @@ impl CudaCompressionKey {
         unsafe {
             let input_lwes = Self::flatten_async(ciphertexts, streams);
 
-            compress_integer_radix_async(
+            compress_integer_radix_with_params_async(
                 streams,
                 &mut output_glwe.0.d_vec,
                 &input_lwes.0.d_vec,
                 &self.packing_key_switching_key.d_vec,
-                message_modulus,
-                carry_modulus,
-                compress_glwe_size.to_glwe_dimension(),
-                compress_polynomial_size,
-                lwe_dimension,
-                lwe_pksk.decomposition_base_log(),
-                lwe_pksk.decomposition_level_count(),
+                &CudaPbsParams {
+                    message_modulus,
+                    carry_modulus,
+                    glwe_dimension: compress_glwe_size.to_glwe_dimension(),
+                    polynomial_size: compress_polynomial_size,
+                    big_lwe_dimension: lwe_dimension,
+                    small_lwe_dimension: lwe_dimension,
+                    ks_level: lwe_pksk.decomposition_level_count(),
+                    ks_base_log: lwe_pksk.decomposition_base_log(),
+                    pbs_level: DecompositionLevelCount(0),
+                    pbs_base_log: DecompositionBaseLog(0),
+                    pbs_type: PBSType::Classical,
+                    grouping_factor: LweBskGroupingFactor(0),
+                },
                 self.lwe_per_glwe.0 as u32,
                 self.storage_log_modulus.0 as u32,
                 num_lwes as u32,
@@ -240,21 +248,28 @@ impl CudaDecompressionKey {
                 );
 
                 unsafe {
-                    decompress_integer_radix_async(
+                    decompress_integer_radix_with_params_async(
                         streams,
                         &mut output_lwe.0.d_vec,
                         &glwe_ciphertext_list.0.d_vec,
                         &bsk.d_vec,
                         packed_list.bodies_count as u32,
-                        message_modulus,
-                        carry_modulus,
-                        encryption_glwe_dimension,
-                        encryption_polynomial_size,
+                        &CudaPbsParams {
+                            message_modulus,
+                            carry_modulus,
+                            glwe_dimension: encryption_glwe_dimension,
+                            polynomial_size: encryption_polynomial_size,
+                            big_lwe_dimension: lwe_dimension,
+                            small_lwe_dimension: lwe_dimension,
+                            ks_level: DecompositionLevelCount(0),
+                            ks_base_log: DecompositionBaseLog(0),
+                            pbs_level: bsk.decomp_level_count(),
+                            pbs_base_log: bsk.decomp_base_log(),
+                            pbs_type: PBSType::Classical,
+                            grouping_factor: LweBskGroupingFactor(0),
+                        },
                         compression_glwe_dimension,
                         compression_polynomial_size,
-                        lwe_dimension,
-                        bsk.decomp_base_log(),
-                        bsk.decomp_level_count(),
                         storage_log_modulus.0 as u32,
                         indexes_array.as_slice(),
                         indexes_array_len.0 as u32,
@@ -5,11 +5,14 @@ pub mod server_key;
 
 use crate::core_crypto::gpu::slice::{CudaSlice, CudaSliceMut};
 use crate::core_crypto::gpu::vec::CudaVec;
-use crate::core_crypto::gpu::CudaStreams;
+use crate::core_crypto::gpu::{CudaError, CudaStreams};
 use crate::core_crypto::prelude::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
     LweDimension, Numeric, PolynomialSize, UnsignedInteger,
 };
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::gpu::ciphertext::info::create_negated_block_decomposer;
+use crate::integer::server_key::TwosComplementNegation;
 use crate::integer::{ClientKey, RadixClientKey};
 use crate::shortint::{CarryModulus, MessageModulus};
 pub use server_key::CudaServerKey;
@@ -31,12 +34,35 @@ pub enum BitOpType {
 }
 
 #[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum PBSType {
     MultiBit = 0,
     Classical = 1,
 }
 
+/// The parameters of the keyswitch and programmable bootstrap performed by the integer operations,
+/// passed at once to the functions of this module.
+///
+/// It can be obtained from a server key with [CudaServerKey::pbs_params].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CudaPbsParams {
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    // Dimension of the LWE ciphertexts in the big key, i.e. before the keyswitch
+    pub big_lwe_dimension: LweDimension,
+    // Dimension of the LWE ciphertexts in the small key, i.e. the input of the bootstrap
+    pub small_lwe_dimension: LweDimension,
+    pub ks_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_type: PBSType,
+    pub grouping_factor: LweBskGroupingFactor,
+}
+
 #[repr(u32)]
 pub enum ShiftRotateType {
     LeftShift = 0,
@@ -45,6 +71,7 @@ pub enum ShiftRotateType {
     RightRotate = 3,
 }
 
+#[derive(Clone, Copy)]
 #[repr(u32)]
 pub enum ComparisonType {
     EQ = 0,
@@ -181,12 +208,7 @@ pub unsafe fn scalar_addition_integer_radix_assign_async<T: UnsignedInteger>(
     );
     cuda_scalar_addition_integer_radix_ciphertext_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         lwe_array.as_mut_c_ptr(0),
         scalar_input.as_c_ptr(0),
@@ -198,30 +220,69 @@ pub unsafe fn scalar_addition_integer_radix_assign_async<T: UnsignedInteger>(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Subtracts a clear scalar from a radix ciphertext.
+///
+/// The scalar is decomposed into the blocks of its two's complement negation, padded with blocks
+/// whose message bits are all set to one up to `num_samples` blocks, so that the subtraction
+/// reduces to a blockwise scalar addition. Subtracting zero does nothing.
+///
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_mul_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn scalar_subtraction_integer_radix_assign_async<T, Scalar>(
+    streams: &CudaStreams,
+    lwe_array: &mut CudaVec<T>,
+    scalar: Scalar,
+    lwe_dimension: LweDimension,
+    num_samples: u32,
+    message_modulus: u32,
+    carry_modulus: u32,
+) where
+    T: UnsignedInteger,
+    Scalar: TwosComplementNegation + DecomposableInto<u8>,
+{
+    let Some(decomposer) =
+        create_negated_block_decomposer(scalar, MessageModulus(message_modulus as u64))
+    else {
+        return;
+    };
+    let decomposed_scalar = decomposer
+        .take(num_samples as usize)
+        .map(|block| T::cast_from(u128::from(block)))
+        .collect::<Vec<_>>();
+    let mut d_decomposed_scalar = CudaVec::<T>::new_async(num_samples as usize, streams, 0);
+    d_decomposed_scalar.copy_from_cpu_async(decomposed_scalar.as_slice(), streams, 0);
+
+    scalar_addition_integer_radix_assign_async(
+        streams,
+        lwe_array,
+        &d_decomposed_scalar,
+        lwe_dimension,
+        num_samples,
+        message_modulus,
+        carry_modulus,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_mul_integer_radix_kb_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
     streams: &CudaStreams,
     lwe_array: &mut CudaVec<T>,
     decomposed_scalar: &[T],
     has_at_least_one_set: &[T],
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<u64>,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
-    glwe_dimension: GlweDimension,
-    polynomial_size: PolynomialSize,
-    lwe_dimension: LweDimension,
-    pbs_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
-    ks_base_log: DecompositionBaseLog,
-    ks_level: DecompositionLevelCount,
+    params: &CudaPbsParams,
     num_blocks: u32,
     num_scalars: u32,
-    pbs_type: PBSType,
-    grouping_factor: LweBskGroupingFactor,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -241,37 +302,27 @@ pub unsafe fn unchecked_scalar_mul_integer_radix_kb_async<T: UnsignedInteger, B:
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_scalar_mul_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
 
     cuda_scalar_multiplication_integer_radix_ciphertext_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         lwe_array.as_mut_c_ptr(0),
         decomposed_scalar.as_ptr().cast::<u64>(),
@@ -279,43 +330,91 @@ pub unsafe fn unchecked_scalar_mul_integer_radix_kb_async<T: UnsignedInteger, B:
         mem_ptr,
         bootstrapping_key.ptr.as_ptr(),
         keyswitch_key.ptr.as_ptr(),
-        (glwe_dimension.0 * polynomial_size.0) as u32,
-        polynomial_size.0 as u32,
-        message_modulus.0 as u32,
+        (params.glwe_dimension.0 * params.polynomial_size.0) as u32,
+        params.polynomial_size.0 as u32,
+        params.message_modulus.0 as u32,
         num_blocks,
         num_scalars,
     );
 
     cleanup_cuda_integer_radix_scalar_mul(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_scalar_mul_integer_radix_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn compress_integer_radix_async<T: UnsignedInteger>(
+pub unsafe fn unchecked_scalar_mul_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
-    glwe_array_out: &mut CudaVec<T>,
-    lwe_array_in: &CudaVec<T>,
-    fp_keyswitch_key: &CudaVec<u64>,
+    lwe_array: &mut CudaVec<T>,
+    decomposed_scalar: &[T],
+    has_at_least_one_set: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<u64>,
     message_modulus: MessageModulus,
     carry_modulus: CarryModulus,
-    compression_glwe_dimension: GlweDimension,
-    compression_polynomial_size: PolynomialSize,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
     lwe_dimension: LweDimension,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
     ks_base_log: DecompositionBaseLog,
     ks_level: DecompositionLevelCount,
+    num_blocks: u32,
+    num_scalars: u32,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_mul_integer_radix_kb_with_params_async(
+        streams,
+        lwe_array,
+        decomposed_scalar,
+        has_at_least_one_set,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        num_scalars,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Packs the `num_blocks` LWE ciphertexts of `lwe_array_in` into GLWE ciphertexts.
+///
+/// `params.glwe_dimension` and `params.polynomial_size` describe the compression GLWE,
+/// `params.big_lwe_dimension` the input LWE ciphertexts and `params.ks_level`,
+/// `params.ks_base_log` the packing keyswitch key.
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn compress_integer_radix_with_params_async<T: UnsignedInteger>(
+    streams: &CudaStreams,
+    glwe_array_out: &mut CudaVec<T>,
+    lwe_array_in: &CudaVec<T>,
+    fp_keyswitch_key: &CudaVec<u64>,
+    params: &CudaPbsParams,
     lwe_per_glwe: u32,
     storage_log_modulus: u32,
     num_blocks: u32,
@@ -338,23 +437,18 @@ pub unsafe fn compress_integer_radix_async<T: UnsignedInteger>(
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_compress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        compression_glwe_dimension.0 as u32,
-        compression_polynomial_size.0 as u32,
-        lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        PBSType::Classical as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         lwe_per_glwe,
         storage_log_modulus,
         true,
@@ -362,12 +456,7 @@ pub unsafe fn compress_integer_radix_async<T: UnsignedInteger>(
 
     cuda_integer_compress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         glwe_array_out.as_mut_c_ptr(0),
         lwe_array_in.as_c_ptr(0),
@@ -378,37 +467,80 @@ pub unsafe fn compress_integer_radix_async<T: UnsignedInteger>(
 
     cleanup_cuda_integer_compress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `compress_integer_radix_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn decompress_integer_radix_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn compress_integer_radix_async<T: UnsignedInteger>(
+    streams: &CudaStreams,
+    glwe_array_out: &mut CudaVec<T>,
+    lwe_array_in: &CudaVec<T>,
+    fp_keyswitch_key: &CudaVec<u64>,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    compression_glwe_dimension: GlweDimension,
+    compression_polynomial_size: PolynomialSize,
+    lwe_dimension: LweDimension,
+    ks_base_log: DecompositionBaseLog,
+    ks_level: DecompositionLevelCount,
+    lwe_per_glwe: u32,
+    storage_log_modulus: u32,
+    num_blocks: u32,
+) {
+    compress_integer_radix_with_params_async(
+        streams,
+        glwe_array_out,
+        lwe_array_in,
+        fp_keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension: compression_glwe_dimension,
+            polynomial_size: compression_polynomial_size,
+            big_lwe_dimension: lwe_dimension,
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level: DecompositionLevelCount(0),
+            pbs_base_log: DecompositionBaseLog(0),
+            pbs_type: PBSType::Classical,
+            grouping_factor: LweBskGroupingFactor(0),
+        },
+        lwe_per_glwe,
+        storage_log_modulus,
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Extracts the LWE ciphertexts at `vec_indexes` from the packed GLWE ciphertexts of `glwe_in`
+/// and bootstraps them back to the encryption parameters.
+///
+/// `params.glwe_dimension`, `params.polynomial_size` and `params.big_lwe_dimension` describe the
+/// encryption key the output is bootstrapped to, while the compression GLWE is described by
+/// `compression_glwe_dimension` and `compression_polynomial_size`.
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn decompress_integer_radix_with_params_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     lwe_array_out: &mut CudaVec<T>,
     glwe_in: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     bodies_count: u32,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
-    encryption_glwe_dimension: GlweDimension,
-    encryption_polynomial_size: PolynomialSize,
+    params: &CudaPbsParams,
     compression_glwe_dimension: GlweDimension,
     compression_polynomial_size: PolynomialSize,
-    lwe_dimension: LweDimension,
-    pbs_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
     storage_log_modulus: u32,
     vec_indexes: &[u32],
     num_lwes: u32,
@@ -431,25 +563,20 @@ pub unsafe fn decompress_integer_radix_async<T: UnsignedInteger, B: Numeric>(
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_decompress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        encryption_glwe_dimension.0 as u32,
-        encryption_polynomial_size.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
         compression_glwe_dimension.0 as u32,
         compression_polynomial_size.0 as u32,
-        lwe_dimension.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
         num_lwes,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        PBSType::Classical as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         storage_log_modulus,
         bodies_count,
         true,
@@ -457,12 +584,7 @@ pub unsafe fn decompress_integer_radix_async<T: UnsignedInteger, B: Numeric>(
 
     cuda_integer_decompress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         lwe_array_out.as_mut_c_ptr(0),
         glwe_in.as_c_ptr(0),
@@ -474,39 +596,96 @@ pub unsafe fn decompress_integer_radix_async<T: UnsignedInteger, B: Numeric>(
 
     cleanup_cuda_integer_decompress_radix_ciphertext_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
+#[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `decompress_integer_radix_with_params_async` instead")]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn decompress_integer_radix_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    lwe_array_out: &mut CudaVec<T>,
+    glwe_in: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    bodies_count: u32,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    encryption_glwe_dimension: GlweDimension,
+    encryption_polynomial_size: PolynomialSize,
+    compression_glwe_dimension: GlweDimension,
+    compression_polynomial_size: PolynomialSize,
+    lwe_dimension: LweDimension,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    storage_log_modulus: u32,
+    vec_indexes: &[u32],
+    num_lwes: u32,
+) {
+    decompress_integer_radix_with_params_async(
+        streams,
+        lwe_array_out,
+        glwe_in,
+        bootstrapping_key,
+        bodies_count,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension: encryption_glwe_dimension,
+            polynomial_size: encryption_polynomial_size,
+            big_lwe_dimension: lwe_dimension,
+            small_lwe_dimension: lwe_dimension,
+            ks_level: DecompositionLevelCount(0),
+            ks_base_log: DecompositionBaseLog(0),
+            pbs_level,
+            pbs_base_log,
+            pbs_type: PBSType::Classical,
+            grouping_factor: LweBskGroupingFactor(0),
+        },
+        compression_glwe_dimension,
+        compression_polynomial_size,
+        storage_log_modulus,
+        vec_indexes,
+        num_lwes,
+    )
+}
+
+/// Returns an error if `vec` does not reside on the GPU of the first stream of `streams`
+fn check_gpu_index<T: Numeric>(streams: &CudaStreams, vec: &CudaVec<T>) -> crate::Result<()> {
+    if streams.gpu_indexes[0] == vec.gpu_index(0) {
+        Ok(())
+    } else {
+        Err(CudaError::GpuMismatch {
+            expected: streams.gpu_indexes[0],
+            got: vec.gpu_index(0),
+        }
+        .into())
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
+///
+/// # Errors
+///
+/// Returns an error if the ciphertexts do not reside on the GPU of the first stream of `streams`
 pub unsafe fn unchecked_add_integer_radix_assign_async<T: UnsignedInteger>(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
     radix_lwe_right: &CudaVec<T>,
     lwe_dimension: LweDimension,
     num_blocks: u32,
-) {
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_left.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_right.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
+) -> crate::Result<()> {
+    check_gpu_index(streams, radix_lwe_left)?;
+    check_gpu_index(streams, radix_lwe_right)?;
     cuda_add_lwe_ciphertext_vector_64(
         streams.ptr[0],
         streams.gpu_indexes[0].0,
@@ -516,6 +695,7 @@ pub unsafe fn unchecked_add_integer_radix_assign_async<T: UnsignedInteger>(
         lwe_dimension.0 as u32,
         num_blocks,
     );
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -523,7 +703,10 @@ pub unsafe fn unchecked_add_integer_radix_assign_async<T: UnsignedInteger>(
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_mul_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_mul_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
     is_boolean_left: bool,
@@ -531,18 +714,8 @@ pub unsafe fn unchecked_mul_integer_radix_kb_assign_async<T: UnsignedInteger, B:
     is_boolean_right: bool,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
-    glwe_dimension: GlweDimension,
-    lwe_dimension: LweDimension,
-    polynomial_size: PolynomialSize,
-    pbs_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
-    ks_base_log: DecompositionBaseLog,
-    ks_level: DecompositionLevelCount,
+    params: &CudaPbsParams,
     num_blocks: u32,
-    pbs_type: PBSType,
-    grouping_factor: LweBskGroupingFactor,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -567,38 +740,28 @@ pub unsafe fn unchecked_mul_integer_radix_kb_assign_async<T: UnsignedInteger, B:
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_mult_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         is_boolean_left,
         is_boolean_right,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        glwe_dimension.0 as u32,
-        lwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        pbs_base_log.0 as u32,
-        pbs_level.0 as u32,
-        ks_base_log.0 as u32,
-        ks_level.0 as u32,
-        grouping_factor.0 as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.ks_level.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        pbs_type as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_integer_mult_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_lwe_left.as_c_ptr(0),
@@ -608,103 +771,166 @@ pub unsafe fn unchecked_mul_integer_radix_kb_assign_async<T: UnsignedInteger, B:
         bootstrapping_key.ptr.as_ptr(),
         keyswitch_key.ptr.as_ptr(),
         mem_ptr,
-        polynomial_size.0 as u32,
+        params.polynomial_size.0 as u32,
         num_blocks,
     );
     cleanup_cuda_integer_mult(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_mul_integer_radix_kb_assign_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_bitop_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_mul_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
+    is_boolean_left: bool,
     radix_lwe_right: &CudaVec<T>,
+    is_boolean_right: bool,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
     carry_modulus: CarryModulus,
     glwe_dimension: GlweDimension,
+    lwe_dimension: LweDimension,
     polynomial_size: PolynomialSize,
-    big_lwe_dimension: LweDimension,
-    small_lwe_dimension: LweDimension,
-    ks_level: DecompositionLevelCount,
-    ks_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
-    op: BitOpType,
+    pbs_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    ks_level: DecompositionLevelCount,
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
 ) {
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_left.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_right.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        bootstrapping_key.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        keyswitch_key.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
-    scratch_cuda_integer_radix_bitop_kb_64(
-        streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
-        streams.len() as u32,
-        std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+    unchecked_mul_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        is_boolean_left,
+        radix_lwe_right,
+        is_boolean_right,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_bitop_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_lwe_right: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    op: BitOpType,
+    num_blocks: u32,
+) {
+    unchecked_bitop_integer_radix_kb_slice_assign_async(
+        streams,
+        &mut radix_lwe_left.as_mut_slice(.., 0).unwrap(),
+        &radix_lwe_right.as_slice(.., 0).unwrap(),
+        bootstrapping_key,
+        keyswitch_key,
+        params,
+        op,
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Same as [unchecked_bitop_integer_radix_kb_assign_with_params_async], on `num_blocks` blocks
+/// of slices of radix ciphertexts
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub(crate) unsafe fn unchecked_bitop_integer_radix_kb_slice_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaSliceMut<T>,
+    radix_lwe_right: &CudaSlice<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    op: BitOpType,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_left.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_right.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+    scratch_cuda_integer_radix_bitop_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         op as u32,
         true,
     );
     cuda_bitop_integer_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_lwe_left.as_c_ptr(0),
@@ -716,29 +942,22 @@ pub unsafe fn unchecked_bitop_integer_radix_kb_assign_async<T: UnsignedInteger,
     );
     cleanup_cuda_integer_bitop(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_bitop_integer_radix_kb_assign_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_async<
-    T: UnsignedInteger,
-    B: Numeric,
->(
+pub unsafe fn unchecked_bitop_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
-    radix_lwe: &mut CudaVec<T>,
-    clear_blocks: &CudaVec<T>,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_lwe_right: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -755,6 +974,82 @@ pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_async<
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_bitop_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        radix_lwe_right,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        op,
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe: &mut CudaVec<T>,
+    clear_blocks: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    op: BitOpType,
+    num_blocks: u32,
+) {
+    unchecked_scalar_bitop_integer_radix_kb_slice_assign_async(
+        streams,
+        &mut radix_lwe.as_mut_slice(.., 0).unwrap(),
+        clear_blocks,
+        bootstrapping_key,
+        keyswitch_key,
+        params,
+        op,
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Same as [unchecked_scalar_bitop_integer_radix_kb_assign_with_params_async], on `num_blocks`
+/// blocks of a slice of a radix ciphertext
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub(crate) unsafe fn unchecked_scalar_bitop_integer_radix_kb_slice_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe: &mut CudaSliceMut<T>,
+    clear_blocks: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    op: BitOpType,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -779,38 +1074,28 @@ pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_bitop_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         op as u32,
         true,
     );
     cuda_scalar_bitop_integer_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe.as_mut_c_ptr(0),
         radix_lwe.as_mut_c_ptr(0),
@@ -824,27 +1109,27 @@ pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_bitop(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_bitop_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_comparison_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_scalar_bitop_integer_radix_kb_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
     streams: &CudaStreams,
-    radix_lwe_out: &mut CudaVec<T>,
-    radix_lwe_left: &CudaVec<T>,
-    radix_lwe_right: &CudaVec<T>,
+    radix_lwe: &mut CudaVec<T>,
+    clear_blocks: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -857,61 +1142,85 @@ pub unsafe fn unchecked_comparison_integer_radix_kb_async<T: UnsignedInteger, B:
     ks_base_log: DecompositionBaseLog,
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
+    op: BitOpType,
     num_blocks: u32,
-    op: ComparisonType,
-    is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
 ) {
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_out.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_left.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_right.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        bootstrapping_key.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        keyswitch_key.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
+    unchecked_scalar_bitop_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe,
+        clear_blocks,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        op,
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+///
+/// # Errors
+///
+/// Returns an error if the ciphertexts or the keys do not reside on the GPU of the first stream of
+/// `streams`
+pub unsafe fn unchecked_comparison_integer_radix_kb_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_left: &CudaVec<T>,
+    radix_lwe_right: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    op: ComparisonType,
+    is_signed: bool,
+) -> crate::Result<()> {
+    check_gpu_index(streams, radix_lwe_out)?;
+    check_gpu_index(streams, radix_lwe_left)?;
+    check_gpu_index(streams, radix_lwe_right)?;
+    check_gpu_index(streams, bootstrapping_key)?;
+    check_gpu_index(streams, keyswitch_key)?;
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_comparison_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         op as u32,
         is_signed,
         true,
@@ -919,12 +1228,7 @@ pub unsafe fn unchecked_comparison_integer_radix_kb_async<T: UnsignedInteger, B:
 
     cuda_comparison_integer_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_out.as_mut_c_ptr(0),
         radix_lwe_left.as_c_ptr(0),
@@ -937,27 +1241,24 @@ pub unsafe fn unchecked_comparison_integer_radix_kb_async<T: UnsignedInteger, B:
 
     cleanup_cuda_integer_comparison(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_comparison_integer_radix_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_comparison_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_out: &mut CudaVec<T>,
-    radix_lwe_in: &CudaVec<T>,
-    scalar_blocks: &CudaVec<T>,
+    radix_lwe_left: &CudaVec<T>,
+    radix_lwe_right: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -971,11 +1272,59 @@ pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInte
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    num_scalar_blocks: u32,
     op: ComparisonType,
-    signed_with_positive_scalar: bool,
+    is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_comparison_integer_radix_kb_with_params_async(
+        streams,
+        radix_lwe_out,
+        radix_lwe_left,
+        radix_lwe_right,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        op,
+        is_signed,
+    )
+    .unwrap_or_else(|err| panic!("{err}"));
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_in: &CudaVec<T>,
+    scalar_blocks: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    num_scalar_blocks: u32,
+    op: ComparisonType,
+    signed_with_positive_scalar: bool,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1005,27 +1354,22 @@ pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInte
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_comparison_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         op as u32,
         signed_with_positive_scalar,
         true,
@@ -1033,12 +1377,7 @@ pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInte
 
     cuda_scalar_comparison_integer_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_out.as_mut_c_ptr(0),
         radix_lwe_in.as_c_ptr(0),
@@ -1052,42 +1391,85 @@ pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInte
 
     cleanup_cuda_integer_comparison(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_scalar_comparison_integer_radix_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn full_propagate_assign_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_scalar_comparison_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
-    radix_lwe_input: &mut CudaVec<T>,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_in: &CudaVec<T>,
+    scalar_blocks: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
-    lwe_dimension: LweDimension,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
     glwe_dimension: GlweDimension,
     polynomial_size: PolynomialSize,
+    big_lwe_dimension: LweDimension,
+    small_lwe_dimension: LweDimension,
     ks_level: DecompositionLevelCount,
     ks_base_log: DecompositionBaseLog,
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
+    num_scalar_blocks: u32,
+    op: ComparisonType,
+    signed_with_positive_scalar: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
 ) {
-    assert_eq!(
-        streams.gpu_indexes[0],
+    unchecked_scalar_comparison_integer_radix_kb_with_params_async(
+        streams,
+        radix_lwe_out,
+        radix_lwe_in,
+        scalar_blocks,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        num_scalar_blocks,
+        op,
+        signed_with_positive_scalar,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn full_propagate_assign_with_params_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_input: &mut CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
         radix_lwe_input.gpu_index(0),
         "GPU error: all data should reside on the same GPU."
     );
@@ -1104,35 +1486,25 @@ pub unsafe fn full_propagate_assign_async<T: UnsignedInteger, B: Numeric>(
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_full_propagation_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        lwe_dimension.0 as u32,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_full_propagation_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_input.as_mut_c_ptr(0),
         mem_ptr,
@@ -1142,17 +1514,59 @@ pub unsafe fn full_propagate_assign_async<T: UnsignedInteger, B: Numeric>(
     );
     cleanup_cuda_full_propagation(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
+#[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `full_propagate_assign_with_params_async` instead")]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn full_propagate_assign_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_input: &mut CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ks_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    pbs_base_log: DecompositionBaseLog,
+    num_blocks: u32,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+) {
+    full_propagate_assign_with_params_async(
+        streams,
+        radix_lwe_input,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 /// # Safety
 ///
@@ -1199,12 +1613,7 @@ pub(crate) unsafe fn propagate_single_carry_assign_async<T: UnsignedInteger, B:
     let big_lwe_dimension: u32 = glwe_dimension.0 as u32 * polynomial_size.0 as u32;
     scratch_cuda_propagate_single_carry_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         glwe_dimension.0 as u32,
@@ -1226,12 +1635,7 @@ pub(crate) unsafe fn propagate_single_carry_assign_async<T: UnsignedInteger, B:
     );
     cuda_propagate_single_carry_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_input.as_mut_c_ptr(0),
         carry_out.as_mut_c_ptr(0),
@@ -1245,12 +1649,7 @@ pub(crate) unsafe fn propagate_single_carry_assign_async<T: UnsignedInteger, B:
     );
     cleanup_cuda_propagate_single_carry(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
@@ -1308,12 +1707,7 @@ pub(crate) unsafe fn add_and_propagate_single_carry_assign_async<T: UnsignedInte
     let big_lwe_dimension: u32 = glwe_dimension.0 as u32 * polynomial_size.0 as u32;
     scratch_cuda_add_and_propagate_single_carry_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         glwe_dimension.0 as u32,
@@ -1335,12 +1729,7 @@ pub(crate) unsafe fn add_and_propagate_single_carry_assign_async<T: UnsignedInte
     );
     cuda_add_and_propagate_single_carry_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_lhs_input.as_mut_c_ptr(0),
         radix_lwe_rhs_input.as_c_ptr(0),
@@ -1355,12 +1744,7 @@ pub(crate) unsafe fn add_and_propagate_single_carry_assign_async<T: UnsignedInte
     );
     cleanup_cuda_add_and_propagate_single_carry(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
@@ -1371,7 +1755,7 @@ pub(crate) unsafe fn add_and_propagate_single_carry_assign_async<T: UnsignedInte
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
@@ -1380,19 +1764,8 @@ pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_async<
     shift: u32,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
-    glwe_dimension: GlweDimension,
-    polynomial_size: PolynomialSize,
-    big_lwe_dimension: LweDimension,
-    small_lwe_dimension: LweDimension,
-    ks_level: DecompositionLevelCount,
-    ks_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
-    pbs_base_log: DecompositionBaseLog,
+    params: &CudaPbsParams,
     num_blocks: u32,
-    pbs_type: PBSType,
-    grouping_factor: LweBskGroupingFactor,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1412,38 +1785,28 @@ pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_logical_scalar_shift_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::LeftShift as u32,
         true,
     );
     cuda_integer_radix_logical_scalar_shift_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         shift,
@@ -1454,23 +1817,21 @@ pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_logical_scalar_shift(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_left_shift_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
@@ -1492,6 +1853,47 @@ pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    shift: u32,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1511,38 +1913,28 @@ pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_logical_scalar_shift_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::RightShift as u32,
         true,
     );
     cuda_integer_radix_logical_scalar_shift_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         shift,
@@ -1553,23 +1945,21 @@ pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async
     );
     cleanup_cuda_integer_radix_logical_scalar_shift(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
@@ -1591,6 +1981,47 @@ pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_as
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    shift: u32,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1610,38 +2041,28 @@ pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_as
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_arithmetic_scalar_shift_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::RightShift as u32,
         true,
     );
     cuda_integer_radix_arithmetic_scalar_shift_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         shift,
@@ -1652,29 +2073,27 @@ pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_as
     );
     cleanup_cuda_integer_radix_arithmetic_scalar_shift(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
-    radix_shift: &CudaVec<T>,
+    shift: u32,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -1688,9 +2107,50 @@ pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_async<
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_shift: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    is_signed: bool,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1715,39 +2175,29 @@ pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_shift_and_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::RightShift as u32,
         is_signed,
         true,
     );
     cuda_integer_radix_shift_and_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_shift.as_c_ptr(0),
@@ -1758,23 +2208,24 @@ pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_shift_and_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_right_shift_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_right_shift_integer_radix_kb_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
     radix_shift: &CudaVec<T>,
@@ -1794,6 +2245,49 @@ pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_async<T: UnsignedInte
     is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_right_shift_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        radix_shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        is_signed,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_shift: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    is_signed: bool,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1818,39 +2312,29 @@ pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_async<T: UnsignedInte
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_shift_and_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::LeftShift as u32,
         is_signed,
         true,
     );
     cuda_integer_radix_shift_and_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_shift.as_c_ptr(0),
@@ -1861,26 +2345,19 @@ pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_async<T: UnsignedInte
     );
     cleanup_cuda_integer_radix_shift_and_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_left_shift_integer_radix_kb_assign_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_async<
-    T: UnsignedInteger,
-    B: Numeric,
->(
+pub unsafe fn unchecked_left_shift_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_left: &mut CudaVec<T>,
     radix_shift: &CudaVec<T>,
@@ -1900,6 +2377,49 @@ pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_async<
     is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_left_shift_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        radix_shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        is_signed,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_shift: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    is_signed: bool,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -1924,39 +2444,29 @@ pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_shift_and_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::RightRotate as u32,
         is_signed,
         true,
     );
     cuda_integer_radix_shift_and_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_shift.as_c_ptr(0),
@@ -1967,23 +2477,21 @@ pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_shift_and_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_rotate_right_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_rotate_right_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
@@ -2007,15 +2515,58 @@ pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_async<
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
 ) {
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_lwe_left.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
-    );
-    assert_eq!(
-        streams.gpu_indexes[0],
-        radix_shift.gpu_index(0),
-        "GPU error: all data should reside on the same GPU."
+    unchecked_rotate_right_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        radix_shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        is_signed,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_shift: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    is_signed: bool,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_left.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_shift.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
     );
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2030,39 +2581,29 @@ pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_shift_and_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::LeftRotate as u32,
         is_signed,
         true,
     );
     cuda_integer_radix_shift_and_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         radix_shift.as_c_ptr(0),
@@ -2073,28 +2614,27 @@ pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_shift_and_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_rotate_left_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_cmux_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn unchecked_rotate_left_integer_radix_kb_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
     streams: &CudaStreams,
-    radix_lwe_out: &mut CudaVec<T>,
-    radix_lwe_condition: &CudaVec<T>,
-    radix_lwe_true: &CudaVec<T>,
-    radix_lwe_false: &CudaVec<T>,
+    radix_lwe_left: &mut CudaVec<T>,
+    radix_shift: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -2108,8 +2648,50 @@ pub unsafe fn unchecked_cmux_integer_radix_kb_async<T: UnsignedInteger, B: Numer
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
+    is_signed: bool,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_rotate_left_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        radix_shift,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        is_signed,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_cmux_integer_radix_kb_with_params_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_condition: &CudaVec<T>,
+    radix_lwe_true: &CudaVec<T>,
+    radix_lwe_false: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2144,37 +2726,27 @@ pub unsafe fn unchecked_cmux_integer_radix_kb_async<T: UnsignedInteger, B: Numer
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_cmux_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_cmux_integer_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_out.as_mut_c_ptr(0),
         radix_lwe_condition.as_c_ptr(0),
@@ -2187,29 +2759,24 @@ pub unsafe fn unchecked_cmux_integer_radix_kb_async<T: UnsignedInteger, B: Numer
     );
     cleanup_cuda_integer_radix_cmux(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_cmux_integer_radix_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_async<
-    T: UnsignedInteger,
-    B: Numeric,
->(
+pub unsafe fn unchecked_cmux_integer_radix_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
-    radix_lwe_left: &mut CudaVec<T>,
-    n: u32,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_condition: &CudaVec<T>,
+    radix_lwe_true: &CudaVec<T>,
+    radix_lwe_false: &CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
@@ -2225,6 +2792,134 @@ pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_async<
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_cmux_integer_radix_kb_with_params_async(
+        streams,
+        radix_lwe_out,
+        radix_lwe_condition,
+        radix_lwe_true,
+        radix_lwe_false,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// In place version of [unchecked_cmux_integer_radix_kb_with_params_async], the false ciphertext is
+/// overwritten by the result (the backend copies the inputs before writing the output).
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_cmux_integer_radix_kb_assign_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_condition: &CudaVec<T>,
+    radix_lwe_true: &CudaVec<T>,
+    radix_lwe_false_and_out: &mut CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_condition.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_true.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_false_and_out.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+    scratch_cuda_integer_radix_cmux_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
+        true,
+    );
+    cuda_cmux_integer_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        radix_lwe_false_and_out.as_mut_c_ptr(0),
+        radix_lwe_condition.as_c_ptr(0),
+        radix_lwe_true.as_c_ptr(0),
+        radix_lwe_false_and_out.as_c_ptr(0),
+        mem_ptr,
+        bootstrapping_key.ptr.as_ptr(),
+        keyswitch_key.ptr.as_ptr(),
+        num_blocks,
+    );
+    cleanup_cuda_integer_radix_cmux(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    n: u32,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2244,38 +2939,28 @@ pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_scalar_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::LeftShift as u32,
         true,
     );
     cuda_integer_radix_scalar_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         n,
@@ -2286,23 +2971,21 @@ pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_scalar_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_rotate_left_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_rotate_left_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
@@ -2324,6 +3007,47 @@ pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_async<
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_rotate_left_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        n,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_left: &mut CudaVec<T>,
+    n: u32,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2343,38 +3067,28 @@ pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_scalar_rotate_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         ShiftRotateType::RightShift as u32,
         true,
     );
     cuda_integer_radix_scalar_rotate_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_left.as_mut_c_ptr(0),
         n,
@@ -2385,44 +3099,84 @@ pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_scalar_rotate(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_scalar_rotate_right_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async<
+pub unsafe fn unchecked_scalar_rotate_right_integer_radix_kb_assign_async<
     T: UnsignedInteger,
     B: Numeric,
 >(
     streams: &CudaStreams,
-    result: &mut CudaVec<T>,
-    radix_list: &mut CudaVec<T>,
+    radix_lwe_left: &mut CudaVec<T>,
+    n: u32,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
     message_modulus: MessageModulus,
     carry_modulus: CarryModulus,
     glwe_dimension: GlweDimension,
     polynomial_size: PolynomialSize,
-    lwe_dimension: LweDimension,
+    big_lwe_dimension: LweDimension,
+    small_lwe_dimension: LweDimension,
     ks_level: DecompositionLevelCount,
     ks_base_log: DecompositionBaseLog,
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    num_radixes: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_scalar_rotate_right_integer_radix_kb_assign_with_params_async(
+        streams,
+        radix_lwe_left,
+        n,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    result: &mut CudaVec<T>,
+    radix_list: &mut CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    num_radixes: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2447,37 +3201,27 @@ pub unsafe fn unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async<
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_radix_partial_sum_ciphertexts_vec_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
         num_radixes,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_integer_radix_partial_sum_ciphertexts_vec_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         result.as_mut_c_ptr(0),
         radix_list.as_mut_c_ptr(0),
@@ -2489,41 +3233,82 @@ pub unsafe fn unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async<
     );
     cleanup_cuda_integer_radix_partial_sum_ciphertexts_vec(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn apply_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
-    streams: &CudaStreams,
-    radix_lwe_output: &mut CudaSliceMut<T>,
-    radix_lwe_input: &CudaSlice<T>,
-    input_lut: &[T],
+pub unsafe fn unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    result: &mut CudaVec<T>,
+    radix_list: &mut CudaVec<T>,
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
-    lwe_dimension: LweDimension,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
     glwe_dimension: GlweDimension,
     polynomial_size: PolynomialSize,
+    lwe_dimension: LweDimension,
     ks_level: DecompositionLevelCount,
     ks_base_log: DecompositionBaseLog,
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
+    num_radixes: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async(
+        streams,
+        result,
+        radix_list,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        num_radixes,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn apply_univariate_lut_kb_with_params_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    radix_lwe_input: &CudaSlice<T>,
+    input_lut: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -2548,37 +3333,27 @@ pub unsafe fn apply_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_apply_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         input_lut.as_ptr().cast(),
-        lwe_dimension.0 as u32,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_apply_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_output.as_mut_c_ptr(0),
         radix_lwe_input.as_c_ptr(0),
@@ -2589,23 +3364,19 @@ pub unsafe fn apply_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     );
     cleanup_cuda_apply_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `apply_univariate_lut_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn apply_many_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn apply_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_output: &mut CudaSliceMut<T>,
     radix_lwe_input: &CudaSlice<T>,
@@ -2624,6 +3395,266 @@ pub unsafe fn apply_many_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>
     carry_modulus: CarryModulus,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    apply_univariate_lut_kb_with_params_async(
+        streams,
+        radix_lwe_output,
+        radix_lwe_input,
+        input_lut,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Allocates the buffers used to apply `input_lut` on `num_blocks` blocks and uploads the lookup
+/// table, so that it can be applied several times with
+/// [apply_univariate_lut_with_buffers_kb_async]
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+/// - `mem_ptr` __must__ be released with [cleanup_univariate_lut_kb_async]
+pub unsafe fn scratch_univariate_lut_kb_async<T: UnsignedInteger>(
+    streams: &CudaStreams,
+    mem_ptr: &mut *mut i8,
+    input_lut: &[T],
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    scratch_cuda_apply_univariate_lut_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(*mem_ptr),
+        input_lut.as_ptr().cast(),
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
+        true,
+    );
+}
+
+/// Applies the lookup table uploaded by [scratch_univariate_lut_kb_async] in `mem_ptr`
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+/// - `mem_ptr` __must__ have been allocated on `streams` for at least `num_blocks` blocks
+pub unsafe fn apply_univariate_lut_with_buffers_kb_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    radix_lwe_input: &CudaSlice<T>,
+    mem_ptr: *mut i8,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_input.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_output.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    cuda_apply_univariate_lut_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        radix_lwe_output.as_mut_c_ptr(0),
+        radix_lwe_input.as_c_ptr(0),
+        mem_ptr,
+        keyswitch_key.ptr.as_ptr(),
+        bootstrapping_key.ptr.as_ptr(),
+        num_blocks,
+    );
+}
+
+/// Releases the buffers allocated by [scratch_univariate_lut_kb_async]
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn cleanup_univariate_lut_kb_async(streams: &CudaStreams, mem_ptr: &mut *mut i8) {
+    cleanup_cuda_apply_univariate_lut_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(*mem_ptr),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Allocates the buffers used to compare two radix ciphertexts of `num_blocks` blocks with `op`,
+/// so that they can be reused by several calls to [comparison_integer_radix_with_buffers_kb_async]
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+/// - `mem_ptr` __must__ be released with [cleanup_comparison_integer_radix_kb_async]
+pub unsafe fn scratch_comparison_integer_radix_kb_async(
+    streams: &CudaStreams,
+    mem_ptr: &mut *mut i8,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+    op: ComparisonType,
+    is_signed: bool,
+) {
+    scratch_cuda_integer_radix_comparison_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(*mem_ptr),
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
+        op as u32,
+        is_signed,
+        true,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Compares `radix_lwe_left` and `radix_lwe_right` using the buffers allocated by
+/// [scratch_comparison_integer_radix_kb_async] in `mem_ptr`
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+/// - `mem_ptr` __must__ have been allocated on `streams` for exactly `num_blocks` blocks
+pub unsafe fn comparison_integer_radix_with_buffers_kb_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_left: &CudaVec<T>,
+    radix_lwe_right: &CudaVec<T>,
+    mem_ptr: *mut i8,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_out.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_left.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_right.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    cuda_comparison_integer_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        radix_lwe_out.as_mut_c_ptr(0),
+        radix_lwe_left.as_c_ptr(0),
+        radix_lwe_right.as_c_ptr(0),
+        mem_ptr,
+        bootstrapping_key.ptr.as_ptr(),
+        keyswitch_key.ptr.as_ptr(),
+        num_blocks,
+    );
+}
+
+/// Releases the buffers allocated by [scratch_comparison_integer_radix_kb_async]
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn cleanup_comparison_integer_radix_kb_async(
+    streams: &CudaStreams,
+    mem_ptr: &mut *mut i8,
+) {
+    cleanup_cuda_integer_comparison(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(*mem_ptr),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn apply_many_univariate_lut_kb_with_params_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    radix_lwe_input: &CudaSlice<T>,
+    input_lut: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
     num_many_lut: u32,
     lut_stride: u32,
 ) {
@@ -2650,38 +3681,28 @@ pub unsafe fn apply_many_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_apply_many_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         input_lut.as_ptr().cast(),
-        lwe_dimension.0 as u32,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         num_many_lut,
         true,
     );
     cuda_apply_many_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_output.as_mut_c_ptr(0),
         radix_lwe_input.as_c_ptr(0),
@@ -2694,27 +3715,22 @@ pub unsafe fn apply_many_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>
     );
     cleanup_cuda_apply_univariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `apply_many_univariate_lut_kb_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn apply_bivariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn apply_many_univariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_output: &mut CudaSliceMut<T>,
-    radix_lwe_input_1: &CudaVec<T>,
-    radix_lwe_input_2: &CudaVec<T>,
+    radix_lwe_input: &CudaSlice<T>,
     input_lut: &[T],
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
@@ -2730,6 +3746,51 @@ pub unsafe fn apply_bivariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     carry_modulus: CarryModulus,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+    num_many_lut: u32,
+    lut_stride: u32,
+) {
+    apply_many_univariate_lut_kb_with_params_async(
+        streams,
+        radix_lwe_output,
+        radix_lwe_input,
+        input_lut,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        num_many_lut,
+        lut_stride,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn apply_bivariate_lut_kb_with_params_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    radix_lwe_input_1: &CudaVec<T>,
+    radix_lwe_input_2: &CudaVec<T>,
+    input_lut: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
     shift: u32,
 ) {
     assert_eq!(
@@ -2760,37 +3821,27 @@ pub unsafe fn apply_bivariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_apply_bivariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         input_lut.as_ptr().cast(),
-        lwe_dimension.0 as u32,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
     cuda_apply_bivariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_output.as_mut_c_ptr(0),
         radix_lwe_input_1.as_c_ptr(0),
@@ -2803,18 +3854,133 @@ pub unsafe fn apply_bivariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
     );
     cleanup_cuda_apply_bivariate_lut_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `apply_bivariate_lut_kb_with_params_async` instead")]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn apply_bivariate_lut_kb_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    radix_lwe_input_1: &CudaVec<T>,
+    radix_lwe_input_2: &CudaVec<T>,
+    input_lut: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ks_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    pbs_base_log: DecompositionBaseLog,
+    num_blocks: u32,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+    shift: u32,
+) {
+    apply_bivariate_lut_kb_with_params_async(
+        streams,
+        radix_lwe_output,
+        radix_lwe_input_1,
+        radix_lwe_input_2,
+        input_lut,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        shift,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_div_rem_integer_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    quotient: &mut CudaVec<T>,
+    remainder: &mut CudaVec<T>,
+    numerator: &CudaVec<T>,
+    divisor: &CudaVec<T>,
+    is_signed: bool,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+    scratch_cuda_integer_div_rem_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        is_signed,
+        std::ptr::addr_of_mut!(mem_ptr),
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
+        true,
+    );
+    cuda_integer_div_rem_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        quotient.as_mut_c_ptr(0),
+        remainder.as_mut_c_ptr(0),
+        numerator.as_c_ptr(0),
+        divisor.as_c_ptr(0),
+        is_signed,
+        mem_ptr,
+        bootstrapping_key.ptr.as_ptr(),
+        keyswitch_key.ptr.as_ptr(),
+        num_blocks,
+    );
+    cleanup_cuda_integer_div_rem(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_div_rem_integer_radix_kb_assign_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
@@ -2839,63 +4005,112 @@ pub unsafe fn unchecked_div_rem_integer_radix_kb_assign_async<T: UnsignedInteger
     pbs_level: DecompositionLevelCount,
     pbs_base_log: DecompositionBaseLog,
     num_blocks: u32,
-    pbs_type: PBSType,
-    grouping_factor: LweBskGroupingFactor,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_div_rem_integer_radix_kb_assign_with_params_async(
+        streams,
+        quotient,
+        remainder,
+        numerator,
+        divisor,
+        is_signed,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// Computes the division of each pair of `numerators` and `divisors`, the scratch memory is
+/// allocated once and shared by all the divisions.
+///
+/// The divisions are launched one after another on `streams`, as they all write to the same
+/// scratch memory.
+///
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_div_rem_integer_radix_kb_vec_assign_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    quotients: &mut [&mut CudaVec<T>],
+    remainders: &mut [&mut CudaVec<T>],
+    numerators: &[&CudaVec<T>],
+    divisors: &[&CudaVec<T>],
+    is_signed: bool,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
+    assert_eq!(quotients.len(), numerators.len());
+    assert_eq!(remainders.len(), numerators.len());
+    assert_eq!(divisors.len(), numerators.len());
+
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_div_rem_radix_ciphertext_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         is_signed,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
-    cuda_integer_div_rem_radix_ciphertext_kb_64(
-        streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
-        streams.len() as u32,
-        quotient.as_mut_c_ptr(0),
-        remainder.as_mut_c_ptr(0),
-        numerator.as_c_ptr(0),
-        divisor.as_c_ptr(0),
-        is_signed,
-        mem_ptr,
-        bootstrapping_key.ptr.as_ptr(),
-        keyswitch_key.ptr.as_ptr(),
-        num_blocks,
-    );
+    for (((quotient, remainder), numerator), divisor) in quotients
+        .iter_mut()
+        .zip(remainders.iter_mut())
+        .zip(numerators.iter())
+        .zip(divisors.iter())
+    {
+        cuda_integer_div_rem_radix_ciphertext_kb_64(
+            streams.ptr.as_ptr(),
+            streams.raw_gpu_indexes().as_ptr(),
+            streams.len() as u32,
+            quotient.as_mut_c_ptr(0),
+            remainder.as_mut_c_ptr(0),
+            numerator.as_c_ptr(0),
+            divisor.as_c_ptr(0),
+            is_signed,
+            mem_ptr,
+            bootstrapping_key.ptr.as_ptr(),
+            keyswitch_key.ptr.as_ptr(),
+            num_blocks,
+        );
+    }
     cleanup_cuda_integer_div_rem(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
@@ -2906,25 +4121,15 @@ pub unsafe fn unchecked_div_rem_integer_radix_kb_assign_async<T: UnsignedInteger
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
 ///   is required
-pub unsafe fn compute_prefix_sum_hillis_steele_async<T: UnsignedInteger, B: Numeric>(
+pub unsafe fn compute_prefix_sum_hillis_steele_with_params_async<T: UnsignedInteger, B: Numeric>(
     streams: &CudaStreams,
     radix_lwe_output: &mut CudaSliceMut<T>,
     generates_or_propagates: &mut CudaSliceMut<T>,
     input_lut: &[T],
     bootstrapping_key: &CudaVec<B>,
     keyswitch_key: &CudaVec<T>,
-    lwe_dimension: LweDimension,
-    glwe_dimension: GlweDimension,
-    polynomial_size: PolynomialSize,
-    ks_level: DecompositionLevelCount,
-    ks_base_log: DecompositionBaseLog,
-    pbs_level: DecompositionLevelCount,
-    pbs_base_log: DecompositionBaseLog,
+    params: &CudaPbsParams,
     num_blocks: u32,
-    message_modulus: MessageModulus,
-    carry_modulus: CarryModulus,
-    pbs_type: PBSType,
-    grouping_factor: LweBskGroupingFactor,
     shift: u32,
 ) {
     assert_eq!(
@@ -2950,38 +4155,28 @@ pub unsafe fn compute_prefix_sum_hillis_steele_async<T: UnsignedInteger, B: Nume
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
     scratch_cuda_integer_compute_prefix_sum_hillis_steele_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         input_lut.as_ptr().cast(),
-        lwe_dimension.0 as u32,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
 
     cuda_integer_compute_prefix_sum_hillis_steele_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_output.as_mut_c_ptr(0),
         generates_or_propagates.as_mut_c_ptr(0),
@@ -2994,17 +4189,65 @@ pub unsafe fn compute_prefix_sum_hillis_steele_async<T: UnsignedInteger, B: Nume
 
     cleanup_cuda_integer_compute_prefix_sum_hillis_steele_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
+#[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `compute_prefix_sum_hillis_steele_with_params_async` instead")]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn compute_prefix_sum_hillis_steele_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_output: &mut CudaSliceMut<T>,
+    generates_or_propagates: &mut CudaSliceMut<T>,
+    input_lut: &[T],
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ks_level: DecompositionLevelCount,
+    ks_base_log: DecompositionBaseLog,
+    pbs_level: DecompositionLevelCount,
+    pbs_base_log: DecompositionBaseLog,
+    num_blocks: u32,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_type: PBSType,
+    grouping_factor: LweBskGroupingFactor,
+    shift: u32,
+) {
+    compute_prefix_sum_hillis_steele_with_params_async(
+        streams,
+        radix_lwe_output,
+        generates_or_propagates,
+        input_lut,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+            small_lwe_dimension: lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+        shift,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 /// # Safety
 ///
@@ -3024,12 +4267,7 @@ pub unsafe fn reverse_blocks_inplace_async<T: UnsignedInteger>(
     if num_blocks > 1 {
         cuda_integer_reverse_blocks_64_inplace(
             streams.ptr.as_ptr(),
-            streams
-                .gpu_indexes
-                .iter()
-                .map(|i| i.0)
-                .collect::<Vec<u32>>()
-                .as_ptr(),
+            streams.raw_gpu_indexes().as_ptr(),
             streams.len() as u32,
             radix_lwe_output.as_mut_c_ptr(0),
             num_blocks,
@@ -3088,12 +4326,7 @@ pub(crate) unsafe fn unchecked_unsigned_overflowing_sub_integer_radix_kb_assign_
     let big_lwe_dimension: u32 = glwe_dimension.0 as u32 * polynomial_size.0 as u32;
     scratch_cuda_integer_overflowing_sub_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
         glwe_dimension.0 as u32,
@@ -3114,12 +4347,7 @@ pub(crate) unsafe fn unchecked_unsigned_overflowing_sub_integer_radix_kb_assign_
     );
     cuda_integer_overflowing_sub_kb_64_inplace(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_input.as_mut_c_ptr(0),
         radix_rhs_input.as_c_ptr(0),
@@ -3134,18 +4362,71 @@ pub(crate) unsafe fn unchecked_unsigned_overflowing_sub_integer_radix_kb_assign_
     );
     cleanup_cuda_integer_overflowing_sub(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_signed_abs_radix_kb_assign_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    ct: &mut CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+    scratch_cuda_integer_abs_inplace_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        std::ptr::addr_of_mut!(mem_ptr),
+        true,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
+        true,
+    );
+    cuda_integer_abs_inplace_radix_ciphertext_kb_64(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
+        streams.len() as u32,
+        ct.as_mut_c_ptr(0),
+        mem_ptr,
+        true,
+        bootstrapping_key.ptr.as_ptr(),
+        keyswitch_key.ptr.as_ptr(),
+        num_blocks,
+    );
+    cleanup_cuda_integer_abs_inplace(
+        streams.ptr.as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(note = "use `unchecked_signed_abs_radix_kb_assign_with_params_async` instead")]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
@@ -3169,63 +4450,112 @@ pub unsafe fn unchecked_signed_abs_radix_kb_assign_async<T: UnsignedInteger, B:
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
 ) {
+    unchecked_signed_abs_radix_kb_assign_with_params_async(
+        streams,
+        ct,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_in: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
+) {
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_out.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        radix_lwe_in.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        bootstrapping_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
+    assert_eq!(
+        streams.gpu_indexes[0],
+        keyswitch_key.gpu_index(0),
+        "GPU error: all data should reside on the same GPU."
+    );
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
-    scratch_cuda_integer_abs_inplace_radix_ciphertext_kb_64(
+    scratch_cuda_integer_is_at_least_one_comparisons_block_true_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        true,
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
-    cuda_integer_abs_inplace_radix_ciphertext_kb_64(
+
+    cuda_integer_is_at_least_one_comparisons_block_true_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
-        ct.as_mut_c_ptr(0),
+        radix_lwe_out.as_mut_c_ptr(0),
+        radix_lwe_in.as_c_ptr(0),
         mem_ptr,
-        true,
         bootstrapping_key.ptr.as_ptr(),
         keyswitch_key.ptr.as_ptr(),
         num_blocks,
     );
-    cleanup_cuda_integer_abs_inplace(
+
+    cleanup_cuda_integer_is_at_least_one_comparisons_block_true(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
@@ -3252,6 +4582,47 @@ pub unsafe fn unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async(
+        streams,
+        radix_lwe_out,
+        radix_lwe_in,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async<
+    T: UnsignedInteger,
+    B: Numeric,
+>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_in: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -3274,40 +4645,30 @@ pub unsafe fn unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_
         "GPU error: all data should reside on the same GPU."
     );
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
-    scratch_cuda_integer_is_at_least_one_comparisons_block_true_kb_64(
+    scratch_cuda_integer_are_all_comparisons_block_true_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
         num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
 
-    cuda_integer_is_at_least_one_comparisons_block_true_kb_64(
+    cuda_integer_are_all_comparisons_block_true_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_out.as_mut_c_ptr(0),
         radix_lwe_in.as_c_ptr(0),
@@ -3317,20 +4678,18 @@ pub unsafe fn unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_
         num_blocks,
     );
 
-    cleanup_cuda_integer_is_at_least_one_comparisons_block_true(
+    cleanup_cuda_integer_are_all_comparisons_block_true(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
 }
 
 #[allow(clippy::too_many_arguments)]
+#[deprecated(
+    note = "use `unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async` instead"
+)]
 /// # Safety
 ///
 /// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
@@ -3357,6 +4716,45 @@ pub unsafe fn unchecked_are_all_comparisons_block_true_integer_radix_kb_async<
     num_blocks: u32,
     pbs_type: PBSType,
     grouping_factor: LweBskGroupingFactor,
+) {
+    unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async(
+        streams,
+        radix_lwe_out,
+        radix_lwe_in,
+        bootstrapping_key,
+        keyswitch_key,
+        &CudaPbsParams {
+            message_modulus,
+            carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension,
+            small_lwe_dimension,
+            ks_level,
+            ks_base_log,
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
+        },
+        num_blocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+/// # Safety
+///
+/// - [CudaStreams::synchronize] __must__ be called after this function as soon as synchronization
+///   is required
+pub unsafe fn unchecked_count_ones_async<T: UnsignedInteger, B: Numeric>(
+    streams: &CudaStreams,
+    radix_lwe_out: &mut CudaVec<T>,
+    radix_lwe_in: &CudaVec<T>,
+    bootstrapping_key: &CudaVec<B>,
+    keyswitch_key: &CudaVec<T>,
+    params: &CudaPbsParams,
+    num_input_blocks: u32,
+    num_output_blocks: u32,
 ) {
     assert_eq!(
         streams.gpu_indexes[0],
@@ -3379,57 +4777,43 @@ pub unsafe fn unchecked_are_all_comparisons_block_true_integer_radix_kb_async<
         "GPU error: all data should reside on the same GPU."
     );
     let mut mem_ptr: *mut i8 = std::ptr::null_mut();
-    scratch_cuda_integer_are_all_comparisons_block_true_kb_64(
+    scratch_cuda_integer_count_ones_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
-        glwe_dimension.0 as u32,
-        polynomial_size.0 as u32,
-        big_lwe_dimension.0 as u32,
-        small_lwe_dimension.0 as u32,
-        ks_level.0 as u32,
-        ks_base_log.0 as u32,
-        pbs_level.0 as u32,
-        pbs_base_log.0 as u32,
-        grouping_factor.0 as u32,
-        num_blocks,
-        message_modulus.0 as u32,
-        carry_modulus.0 as u32,
-        pbs_type as u32,
+        params.glwe_dimension.0 as u32,
+        params.polynomial_size.0 as u32,
+        params.big_lwe_dimension.0 as u32,
+        params.small_lwe_dimension.0 as u32,
+        params.ks_level.0 as u32,
+        params.ks_base_log.0 as u32,
+        params.pbs_level.0 as u32,
+        params.pbs_base_log.0 as u32,
+        params.grouping_factor.0 as u32,
+        num_input_blocks,
+        num_output_blocks,
+        params.message_modulus.0 as u32,
+        params.carry_modulus.0 as u32,
+        params.pbs_type as u32,
         true,
     );
-
-    cuda_integer_are_all_comparisons_block_true_kb_64(
+    cuda_integer_count_ones_kb_64(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         radix_lwe_out.as_mut_c_ptr(0),
         radix_lwe_in.as_c_ptr(0),
         mem_ptr,
         bootstrapping_key.ptr.as_ptr(),
         keyswitch_key.ptr.as_ptr(),
-        num_blocks,
+        params.polynomial_size.0 as u32,
+        num_input_blocks,
+        num_output_blocks,
     );
-
-    cleanup_cuda_integer_are_all_comparisons_block_true(
+    cleanup_cuda_integer_count_ones(
         streams.ptr.as_ptr(),
-        streams
-            .gpu_indexes
-            .iter()
-            .map(|i| i.0)
-            .collect::<Vec<u32>>()
-            .as_ptr(),
+        streams.raw_gpu_indexes().as_ptr(),
         streams.len() as u32,
         std::ptr::addr_of_mut!(mem_ptr),
     );
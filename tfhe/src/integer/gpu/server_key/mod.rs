@@ -2,18 +2,17 @@ use crate::core_crypto::gpu::lwe_bootstrap_key::CudaLweBootstrapKey;
 use crate::core_crypto::gpu::lwe_keyswitch_key::CudaLweKeyswitchKey;
 use crate::core_crypto::gpu::lwe_multi_bit_bootstrap_key::CudaLweMultiBitBootstrapKey;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{
-    allocate_and_generate_new_lwe_keyswitch_key, par_allocate_and_generate_new_lwe_bootstrap_key,
-    par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key, LweBootstrapKeyOwned,
-    LweMultiBitBootstrapKeyOwned,
-};
-use crate::integer::gpu::UnsignedInteger;
+use crate::core_crypto::prelude::LweBskGroupingFactor;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::{CudaPbsParams, PBSType, UnsignedInteger};
 use crate::integer::ClientKey;
 use crate::shortint::ciphertext::{MaxDegree, MaxNoiseLevel};
-use crate::shortint::engine::ShortintEngine;
 use crate::shortint::{CarryModulus, CiphertextModulus, MessageModulus, PBSOrder};
+use std::ops::Range;
 mod radix;
 
+pub use radix::{CudaComparisonScratch, PreparedLut, ReduceOp};
+
 pub enum CudaBootstrappingKey {
     Classic(CudaLweBootstrapKey),
     MultiBit(CudaLweMultiBitBootstrapKey),
@@ -37,6 +36,15 @@ pub struct CudaServerKey {
     // Modulus use for computations on the ciphertext
     pub ciphertext_modulus: CiphertextModulus,
     pub pbs_order: PBSOrder,
+    // Maximum number of blocks processed by a single kernel launch in block-wise operations,
+    // no limit if None
+    pub(crate) max_concurrent_blocks: Option<usize>,
+    // Whether input ciphertexts residing on another GPU than the one of the streams are copied
+    // to it instead of raising an error
+    pub(crate) auto_migrate: bool,
+    // CPU key the device keys were uploaded from, kept to serialize the key as the device keys
+    // are stored in the layout of the backend
+    pub(crate) cpu_key: crate::integer::CompressedServerKey,
 }
 
 impl CudaServerKey {
@@ -78,80 +86,17 @@ impl CudaServerKey {
         max_degree: MaxDegree,
         streams: &CudaStreams,
     ) -> Self {
-        let mut engine = ShortintEngine::new();
-
-        // Generate a regular keyset and convert to the GPU
-        let pbs_params_base = &cks.parameters();
-        let d_bootstrapping_key = match pbs_params_base {
-            crate::shortint::PBSParameters::PBS(pbs_params) => {
-                let h_bootstrap_key: LweBootstrapKeyOwned<u64> =
-                    par_allocate_and_generate_new_lwe_bootstrap_key(
-                        &cks.key.small_lwe_secret_key(),
-                        &cks.key.glwe_secret_key,
-                        pbs_params.pbs_base_log,
-                        pbs_params.pbs_level,
-                        pbs_params.glwe_noise_distribution,
-                        pbs_params.ciphertext_modulus,
-                        &mut engine.encryption_generator,
-                    );
-
-                let d_bootstrap_key =
-                    CudaLweBootstrapKey::from_lwe_bootstrap_key(&h_bootstrap_key, streams);
-
-                CudaBootstrappingKey::Classic(d_bootstrap_key)
-            }
-            crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
-                let h_bootstrap_key: LweMultiBitBootstrapKeyOwned<u64> =
-                    par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
-                        &cks.key.small_lwe_secret_key(),
-                        &cks.key.glwe_secret_key,
-                        pbs_params.pbs_base_log,
-                        pbs_params.pbs_level,
-                        pbs_params.grouping_factor,
-                        pbs_params.glwe_noise_distribution,
-                        pbs_params.ciphertext_modulus,
-                        &mut engine.encryption_generator,
-                    );
-
-                let d_bootstrap_key = CudaLweMultiBitBootstrapKey::from_lwe_multi_bit_bootstrap_key(
-                    &h_bootstrap_key,
-                    streams,
-                );
-
-                CudaBootstrappingKey::MultiBit(d_bootstrap_key)
-            }
-        };
-
-        // Creation of the key switching key
-        let h_key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
-            &cks.key.large_lwe_secret_key(),
-            &cks.key.small_lwe_secret_key(),
-            cks.parameters().ks_base_log(),
-            cks.parameters().ks_level(),
-            cks.parameters().lwe_noise_distribution(),
-            cks.parameters().ciphertext_modulus(),
-            &mut engine.encryption_generator,
-        );
-
-        let d_key_switching_key =
-            CudaLweKeyswitchKey::from_lwe_keyswitch_key(&h_key_switching_key, streams);
-
         assert!(matches!(
             cks.parameters().encryption_key_choice().into(),
             PBSOrder::KeyswitchBootstrap
         ));
 
-        // Pack the keys in the server key set:
-        Self {
-            key_switching_key: d_key_switching_key,
-            bootstrapping_key: d_bootstrapping_key,
-            message_modulus: cks.parameters().message_modulus(),
-            carry_modulus: cks.parameters().carry_modulus(),
-            max_degree,
-            max_noise_level: cks.parameters().max_noise_level(),
-            ciphertext_modulus: cks.parameters().ciphertext_modulus(),
-            pbs_order: cks.parameters().encryption_key_choice().into(),
-        }
+        // Generate a compressed keyset, kept to serialize the key, and convert it to the GPU
+        let cpu_key = crate::integer::CompressedServerKey::from_raw_parts(
+            crate::shortint::CompressedServerKey::new_with_max_degree(&cks.key, max_degree),
+        );
+
+        Self::from_cpu_key(cpu_key, streams)
     }
 
     /// Decompress a CompressedServerKey to a CudaServerKey
@@ -194,6 +139,10 @@ impl CudaServerKey {
         cpu_key: &crate::integer::CompressedServerKey,
         streams: &CudaStreams,
     ) -> Self {
+        Self::from_cpu_key(cpu_key.clone(), streams)
+    }
+
+    fn from_cpu_key(cpu_key: crate::integer::CompressedServerKey, streams: &CudaStreams) -> Self {
         let crate::shortint::CompressedServerKey {
             key_switching_key,
             bootstrapping_key,
@@ -242,9 +191,337 @@ impl CudaServerKey {
             max_noise_level,
             ciphertext_modulus,
             pbs_order,
+            max_concurrent_blocks: None,
+            auto_migrate: false,
+            cpu_key,
+        }
+    }
+
+    /// Serializes the key with [safe_serialize](crate::safe_serialization::safe_serialize), going
+    /// through its CPU representation.
+    ///
+    /// The keys on the GPU are stored in the layout used by the backend, so the
+    /// [CompressedServerKey](crate::integer::CompressedServerKey) they were uploaded from is
+    /// serialized instead. The result can be loaded back with [Self::deserialize], or on the CPU
+    /// as a [CompressedServerKey](crate::integer::CompressedServerKey).
+    ///
+    /// As with [safe_serialize](crate::safe_serialization::safe_serialize), an error is returned
+    /// if the serialized key is larger than `serialized_size_limit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::CudaServerKey;
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    /// let size = 4;
+    /// let cks = ClientKey::new(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sks = CudaServerKey::new(&cks, &streams);
+    ///
+    /// let size_limit = 1 << 30;
+    /// let serialized_sks = sks.serialize(size_limit).unwrap();
+    /// let deserialized_sks =
+    ///     CudaServerKey::deserialize(&serialized_sks, size_limit, &streams).unwrap();
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(msg1, size), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(msg2, size), &streams);
+    ///
+    /// let d_ct_res = deserialized_sks.add(&d_ct1, &d_ct2, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, msg1 + msg2);
+    /// ```
+    pub fn serialize(&self, serialized_size_limit: u64) -> crate::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        crate::safe_serialization::safe_serialize(&self.cpu_key, &mut bytes, serialized_size_limit)
+            .map_err(|err| crate::Error::new(err.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Deserializes a key serialized with [Self::serialize], or a
+    /// [CompressedServerKey](crate::integer::CompressedServerKey) serialized on the CPU with
+    /// [safe_serialize](crate::safe_serialization::safe_serialize), and uploads it to the GPU.
+    ///
+    /// As with [safe_deserialize](crate::safe_serialization::safe_deserialize), an error is
+    /// returned if `bytes` is larger than `serialized_size_limit`, or does not hold a versioned
+    /// [CompressedServerKey](crate::integer::CompressedServerKey).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::CudaServerKey;
+    /// use tfhe::integer::{ClientKey, CompressedServerKey};
+    /// use tfhe::safe_serialization::safe_serialize;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    /// let size = 4;
+    /// let cks = ClientKey::new(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let compressed_sks = CompressedServerKey::new_radix_compressed_server_key(&cks);
+    ///
+    /// let size_limit = 1 << 30;
+    /// let mut serialized_sks = vec![];
+    /// safe_serialize(&compressed_sks, &mut serialized_sks, size_limit).unwrap();
+    /// let cuda_sks = CudaServerKey::deserialize(&serialized_sks, size_limit, &streams).unwrap();
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(msg1, size), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(msg2, size), &streams);
+    ///
+    /// let d_ct_res = cuda_sks.add(&d_ct1, &d_ct2, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, msg1 + msg2);
+    /// ```
+    pub fn deserialize(
+        bytes: &[u8],
+        serialized_size_limit: u64,
+        streams: &CudaStreams,
+    ) -> crate::Result<Self> {
+        let cpu_key: crate::integer::CompressedServerKey =
+            crate::safe_serialization::safe_deserialize(bytes, serialized_size_limit)?;
+        Ok(Self::from_cpu_key(cpu_key, streams))
+    }
+
+    /// Sets the maximum number of blocks processed concurrently by the operations that work
+    /// independently on each block, i.e. bitwise operations and scalar bitwise operations.
+    ///
+    /// When a ciphertext has more blocks than this limit, these operations are performed by
+    /// several kernel launches on at most `max_concurrent_blocks` blocks each, leaving room on
+    /// the GPU for other work. `None` removes the limit, which is the default.
+    ///
+    /// This only impacts performance, results are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_concurrent_blocks` is `Some(0)`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, mut sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// sks.set_max_concurrent_blocks(Some(2));
+    /// assert_eq!(sks.max_concurrent_blocks(), Some(2));
+    ///
+    /// let msg1 = 201u64;
+    /// let msg2 = 140u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let d_ct_res = sks.bitand(&d_ct1, &d_ct2, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1 & msg2);
+    /// ```
+    pub fn set_max_concurrent_blocks(&mut self, max_concurrent_blocks: Option<usize>) {
+        assert_ne!(
+            max_concurrent_blocks,
+            Some(0),
+            "The maximum number of concurrent blocks must be at least 1"
+        );
+        self.max_concurrent_blocks = max_concurrent_blocks;
+    }
+
+    /// Returns the maximum number of blocks processed concurrently by block-wise operations,
+    /// see [`Self::set_max_concurrent_blocks`]
+    pub fn max_concurrent_blocks(&self) -> Option<usize> {
+        self.max_concurrent_blocks
+    }
+
+    /// Sets whether the operations supporting it copy input ciphertexts residing on another GPU
+    /// than the one of the first stream of `streams` to that GPU before computing.
+    ///
+    /// When disabled, which is the default, such inputs make the operations panic, and make their
+    /// `try_` versions return an error. The copy is a peer-to-peer copy when the GPUs support it,
+    /// and goes through the host otherwise.
+    ///
+    /// This is currently supported by additions, comparisons and the fallible operations such as
+    /// [Self::try_add].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, mut sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// sks.set_auto_migrate(true);
+    /// assert!(sks.auto_migrate());
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let d_ct_res = sks.unchecked_add(&d_ct1, &d_ct2, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1 + msg2);
+    /// ```
+    pub fn set_auto_migrate(&mut self, auto_migrate: bool) {
+        self.auto_migrate = auto_migrate;
+    }
+
+    /// Returns whether input ciphertexts residing on another GPU are copied to the GPU of the
+    /// streams, see [`Self::set_auto_migrate`]
+    pub fn auto_migrate(&self) -> bool {
+        self.auto_migrate
+    }
+
+    /// Returns a copy of `ct` on the GPU of the first stream of `streams` if automatic migration
+    /// is enabled and `ct` resides on another GPU, `None` otherwise
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub(crate) unsafe fn migrated_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> Option<T> {
+        if self.auto_migrate && ct.as_ref().d_blocks.0.d_vec.gpu_index(0) != streams.gpu_indexes[0]
+        {
+            Some(ct.duplicate_async(streams))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the maximum noise level a block can reach before it has to be refreshed by a
+    /// bootstrap.
+    ///
+    /// A freshly encrypted or bootstrapped block has a nominal noise level, and unchecked
+    /// additions sum the noise levels of their operands, so at most `max_noise_level().get()`
+    /// such ciphertexts can be summed with unchecked operations before a refresh.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    /// let (_cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// assert_eq!(
+    ///     sks.max_noise_level(),
+    ///     PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64.max_noise_level
+    /// );
+    /// ```
+    pub fn max_noise_level(&self) -> MaxNoiseLevel {
+        self.max_noise_level
+    }
+
+    /// Returns the parameters of the keyswitch and bootstrap performed with this key, as expected
+    /// by the functions of [crate::integer::gpu]
+    pub fn pbs_params(&self) -> CudaPbsParams {
+        let (glwe_dimension, polynomial_size, pbs_level, pbs_base_log, pbs_type, grouping_factor) =
+            match &self.bootstrapping_key {
+                CudaBootstrappingKey::Classic(d_bsk) => (
+                    d_bsk.glwe_dimension,
+                    d_bsk.polynomial_size,
+                    d_bsk.decomp_level_count,
+                    d_bsk.decomp_base_log,
+                    PBSType::Classical,
+                    LweBskGroupingFactor(0),
+                ),
+                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => (
+                    d_multibit_bsk.glwe_dimension,
+                    d_multibit_bsk.polynomial_size,
+                    d_multibit_bsk.decomp_level_count,
+                    d_multibit_bsk.decomp_base_log,
+                    PBSType::MultiBit,
+                    d_multibit_bsk.grouping_factor,
+                ),
+            };
+
+        CudaPbsParams {
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            glwe_dimension,
+            polynomial_size,
+            big_lwe_dimension: self
+                .key_switching_key
+                .input_key_lwe_size()
+                .to_lwe_dimension(),
+            small_lwe_dimension: self
+                .key_switching_key
+                .output_key_lwe_size()
+                .to_lwe_dimension(),
+            ks_level: self.key_switching_key.decomposition_level_count(),
+            ks_base_log: self.key_switching_key.decomposition_base_log(),
+            pbs_level,
+            pbs_base_log,
+            pbs_type,
+            grouping_factor,
         }
     }
 
+    /// Splits `0..num_blocks` into the ranges of blocks processed by each kernel launch of a
+    /// block-wise operation
+    pub(crate) fn concurrent_block_ranges(&self, num_blocks: usize) -> Vec<Range<usize>> {
+        let chunk_size = self.max_concurrent_blocks.unwrap_or(num_blocks).max(1);
+
+        (0..num_blocks)
+            .step_by(chunk_size)
+            .map(|start| start..num_blocks.min(start + chunk_size))
+            .collect()
+    }
+
     #[allow(clippy::unused_self)]
     pub(crate) fn num_bits_to_represent_unsigned_value<Clear>(&self, clear: Clear) -> usize
     where
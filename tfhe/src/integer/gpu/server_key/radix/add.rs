@@ -1,6 +1,5 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::{
     CudaIntegerRadixCiphertext, CudaSignedRadixCiphertext, CudaUnsignedRadixCiphertext,
@@ -8,7 +7,7 @@ use crate::integer::gpu::ciphertext::{
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
     unchecked_add_integer_radix_assign_async,
-    unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async, PBSType,
+    unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async,
 };
 use crate::integer::server_key::radix_parallel::OutputFlag;
 use crate::shortint::ciphertext::NoiseLevel;
@@ -85,6 +84,8 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        Self::assert_has_blocks(ct_left, "add");
+        Self::assert_has_blocks(ct_right, "add");
         let mut tmp_rhs;
 
         let (lhs, rhs) = match (
@@ -185,6 +186,36 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        self.try_unchecked_add_assign_async(ct_left, ct_right, streams)
+            .unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    /// Fallible version of [Self::unchecked_add_assign_async].
+    ///
+    /// Returns an error instead of panicking if an input resides on another GPU than the one of
+    /// the first stream of `streams` and automatic migration is disabled, see
+    /// [Self::set_auto_migrate].
+    ///
+    /// # Safety
+    ///
+    /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until stream is synchronised
+    pub unsafe fn try_unchecked_add_assign_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &mut T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> crate::Result<()> {
+        Self::assert_has_blocks(ct_left, "unchecked_add");
+        Self::assert_has_blocks(ct_right, "unchecked_add");
+        if let Some(migrated_left) = self.migrated_async(ct_left, streams) {
+            // The copy is queued on streams, and dropping the source only synchronizes its own
+            // GPU: it must be done before the source is released
+            streams.synchronize();
+            *ct_left = migrated_left;
+        }
+        let migrated_right = self.migrated_async(ct_right, streams);
+        let ct_right = migrated_right.as_ref().unwrap_or(ct_right);
         let ciphertext_left = ct_left.as_mut();
         let ciphertext_right = ct_right.as_ref();
         assert_eq!(
@@ -212,9 +243,10 @@ impl CudaServerKey {
             &ciphertext_right.d_blocks.0.d_vec,
             lwe_dimension,
             lwe_ciphertext_count.0 as u32,
-        );
+        )?;
 
         ciphertext_left.info = ciphertext_left.info.after_add(&ciphertext_right.info);
+        Ok(())
     }
 
     pub fn unchecked_add_assign<T: CudaIntegerRadixCiphertext>(
@@ -229,6 +261,21 @@ impl CudaServerKey {
         streams.synchronize();
     }
 
+    /// Fallible version of [Self::unchecked_add_assign], see
+    /// [Self::try_unchecked_add_assign_async] for the errors that are reported
+    pub fn try_unchecked_add_assign<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &mut T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> crate::Result<()> {
+        unsafe {
+            self.try_unchecked_add_assign_async(ct_left, ct_right, streams)?;
+        }
+        streams.try_synchronize()?;
+        Ok(())
+    }
+
     /// # Safety
     ///
     /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -277,51 +324,27 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async(
+                unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &mut terms.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_blocks.0 as u32,
                     radix_count_in_vec as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_async(
+                unchecked_partial_sum_ciphertexts_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &mut terms.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_blocks.0 as u32,
                     radix_count_in_vec as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -377,6 +400,9 @@ impl CudaServerKey {
         if ciphertexts.is_empty() {
             return None;
         }
+        for ct in ciphertexts {
+            Self::assert_has_blocks(ct, "sum_ciphertexts");
+        }
 
         let mut result = unsafe { ciphertexts[0].duplicate_async(streams) };
 
@@ -390,6 +416,48 @@ impl CudaServerKey {
         Some(result)
     }
 
+    /// Computes homomorphically the sum of all the ciphertexts of `ciphertexts`.
+    ///
+    /// All the terms are reduced at once on the GPU, and carries are propagated a single time at
+    /// the end, which is much cheaper than chaining [Self::add] calls.
+    ///
+    /// Returns `None` if `ciphertexts` is empty, and the input (with clean carries) if it contains
+    /// a single ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msgs = [17u64, 42, 3, 100];
+    ///
+    /// // Copy to GPU
+    /// let d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let d_sum = sks.sum_ciphertexts(d_cts, &streams).unwrap();
+    ///
+    /// // Copy back to CPU and decrypt
+    /// let sum: u64 = cks.decrypt(&d_sum.to_radix_ciphertext(&streams));
+    /// assert_eq!(sum, msgs.iter().sum::<u64>() % 256);
+    /// ```
     pub fn sum_ciphertexts<T: CudaIntegerRadixCiphertext>(
         &self,
         ciphertexts: Vec<T>,
@@ -420,6 +488,10 @@ impl CudaServerKey {
                 self.full_propagate_assign_async(&mut *ct, streams);
             });
 
+        if ciphertexts.len() == 1 {
+            return ciphertexts.pop();
+        }
+
         Some(self.unchecked_sum_ciphertexts_async(&ciphertexts, streams))
     }
 
@@ -568,6 +640,192 @@ impl CudaServerKey {
         (ct_res, ct_overflowed)
     }
 
+    /// Propagates the carries of `ct` and returns the carry going out of its last block.
+    ///
+    /// This is meant for ciphertexts resulting from a single unchecked addition (i.e. each block
+    /// carry is at most 1), so that custom adders can get the overflow flag once the result is
+    /// cleaned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    /// let modulus = 1 << 8;
+    ///
+    /// let msg1 = 200;
+    /// let msg2 = 100;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let mut d_ct_res = sks.unchecked_add(&d_ct1, &d_ct2, &streams);
+    /// let d_carry = sks.propagate_carries(&mut d_ct_res, &streams);
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let carry = d_carry.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let dec_carry = cks.decrypt_bool(&carry);
+    /// assert_eq!(dec_result, (msg1 + msg2) % modulus);
+    /// assert!(dec_carry);
+    /// ```
+    pub fn propagate_carries(
+        &self,
+        ct: &mut CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let carry = unsafe { self.propagate_carries_async(ct, streams) };
+        streams.synchronize();
+        carry
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn propagate_carries_async(
+        &self,
+        ct: &mut CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        assert!(
+            ct.as_ref()
+                .info
+                .blocks
+                .iter()
+                .all(|b| b.degree.get() < 2 * b.message_modulus.0),
+            "Blocks must have a carry of at most 1 to be propagated with a single carry"
+        );
+
+        let is_trivial = ct.as_ref().info.blocks.last().unwrap().noise_level == NoiseLevel::ZERO;
+
+        let mut carry_out: CudaUnsignedRadixCiphertext =
+            self.propagate_single_carry_assign_async(ct, streams, None, OutputFlag::Carry);
+
+        let noise_level = if is_trivial {
+            NoiseLevel::ZERO
+        } else {
+            NoiseLevel::NOMINAL
+        };
+        carry_out.as_mut().info = carry_out.as_ref().info.boolean_info(noise_level);
+
+        CudaBooleanBlock::from_cuda_radix_ciphertext(carry_out.ciphertext)
+    }
+
+    /// Computes the carry propagation state of each block of `ct`.
+    ///
+    /// This is the first stage of the carry propagation done with the Hillis-Steele prefix sum,
+    /// it is exposed so that other carry networks can be built on top of it.
+    ///
+    /// The i-th block of the output encrypts the state of the i-th block of `ct`:
+    ///
+    /// - `0`: the block does not output a carry
+    /// - `1`: the block generates a carry (`block >= message_modulus`)
+    /// - `2`: the block propagates the carry it receives (`block == message_modulus - 1`)
+    ///
+    /// Each block of `ct` must have a carry of at most 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::integer::IntegerCiphertext;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// // Blocks are (lsb first) [3, 3, 0, 0] and [1, 0, 2, 0]
+    /// let msg1 = 15u64;
+    /// let msg2 = 33u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// // Blocks of the sum are [4, 3, 2, 0]
+    /// let d_sum = sks.unchecked_add(&d_ct1, &d_ct2, &streams);
+    /// let d_states = sks.compute_generate_propagate(&d_sum, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let states = d_states.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_states = states
+    ///     .blocks()
+    ///     .iter()
+    ///     .map(|block| cks.decrypt_one_block(block))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(dec_states, vec![1, 2, 0, 0]);
+    /// ```
+    pub fn compute_generate_propagate<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> T {
+        let result = unsafe { self.compute_generate_propagate_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn compute_generate_propagate_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> T {
+        assert!(
+            ct.as_ref()
+                .info
+                .blocks
+                .iter()
+                .all(|b| b.degree.get() < 2 * b.message_modulus.0),
+            "Blocks must have a carry of at most 1 to compute their propagation state"
+        );
+
+        let message_modulus = self.message_modulus.0;
+        let lut = self.generate_lookup_table(|block| {
+            if block >= message_modulus {
+                1
+            } else if block == message_modulus - 1 {
+                2
+            } else {
+                0
+            }
+        });
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let mut result = ct.duplicate_async(streams);
+        self.apply_lookup_table_async(result.as_mut(), ct.as_ref(), &lut, 0..num_blocks, streams);
+        result
+    }
+
     /// # Safety
     ///
     /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -718,4 +976,101 @@ impl CudaServerKey {
         stream.synchronize();
         (result, overflowed)
     }
+
+    /// Adds an encrypted boolean (0 or 1) to a ciphertext, i.e. increments it if the boolean is
+    /// `true`.
+    ///
+    /// The boolean is used as the input carry of the carry propagation, which is cheaper than
+    /// a full addition with a radix encrypting the boolean. The result wraps around on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 63u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    /// let d_true = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(true), &streams);
+    ///
+    /// let d_ct_res = sks.add_boolean(&d_ct, &d_true, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg + 1);
+    /// ```
+    pub fn add_boolean<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        boolean: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> T {
+        let result = unsafe { self.add_boolean_async(ct, boolean, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn add_boolean_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &T,
+        boolean: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) -> T {
+        let mut result = ct.duplicate_async(streams);
+        self.add_boolean_assign_async(&mut result, boolean, streams);
+        result
+    }
+
+    pub fn add_boolean_assign<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &mut T,
+        boolean: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        unsafe {
+            self.add_boolean_assign_async(ct, boolean, streams);
+        }
+        streams.synchronize();
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn add_boolean_assign_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct: &mut T,
+        boolean: &CudaBooleanBlock,
+        streams: &CudaStreams,
+    ) {
+        Self::assert_has_blocks(ct, "add_boolean");
+
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_assign_async(ct, streams);
+        }
+
+        let _carry =
+            self.propagate_single_carry_assign_async(ct, streams, Some(boolean), OutputFlag::None);
+    }
 }
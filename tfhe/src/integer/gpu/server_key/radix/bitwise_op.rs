@@ -4,11 +4,10 @@ use crate::core_crypto::gpu::algorithms::{
 };
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    unchecked_bitop_integer_radix_kb_assign_async, BitOpType, CudaServerKey, PBSType,
+    unchecked_bitop_integer_radix_kb_slice_assign_async, BitOpType, CudaServerKey,
 };
 
 impl CudaServerKey {
@@ -182,61 +181,50 @@ impl CudaServerKey {
         );
 
         let lwe_ciphertext_count = ct_left.as_ref().d_blocks.lwe_ciphertext_count();
-
-        match &self.bootstrapping_key {
-            CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_bitop_integer_radix_kb_assign_async(
-                    streams,
-                    &mut ct_left.as_mut().d_blocks.0.d_vec,
-                    &ct_right.as_ref().d_blocks.0.d_vec,
-                    &d_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
-                    op,
-                    lwe_ciphertext_count.0 as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
-                );
-            }
-            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_bitop_integer_radix_kb_assign_async(
-                    streams,
-                    &mut ct_left.as_mut().d_blocks.0.d_vec,
-                    &ct_right.as_ref().d_blocks.0.d_vec,
-                    &d_multibit_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
-                    op,
-                    lwe_ciphertext_count.0 as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
-                );
+        let lwe_size = ct_left.as_ref().d_blocks.lwe_dimension().to_lwe_size().0;
+
+        for block_range in self.concurrent_block_ranges(lwe_ciphertext_count.0) {
+            let lwe_range = lwe_size * block_range.start..lwe_size * block_range.end;
+            let mut left_slice = ct_left
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice(lwe_range.clone(), 0)
+                .unwrap();
+            let right_slice = ct_right
+                .as_ref()
+                .d_blocks
+                .0
+                .d_vec
+                .as_slice(lwe_range, 0)
+                .unwrap();
+
+            match &self.bootstrapping_key {
+                CudaBootstrappingKey::Classic(d_bsk) => {
+                    unchecked_bitop_integer_radix_kb_slice_assign_async(
+                        streams,
+                        &mut left_slice,
+                        &right_slice,
+                        &d_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        op,
+                        block_range.len() as u32,
+                    );
+                }
+                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                    unchecked_bitop_integer_radix_kb_slice_assign_async(
+                        streams,
+                        &mut left_slice,
+                        &right_slice,
+                        &d_multibit_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        op,
+                        block_range.len() as u32,
+                    );
+                }
             }
         }
     }
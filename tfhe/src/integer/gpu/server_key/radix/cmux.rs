@@ -1,9 +1,12 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
+use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
-use crate::integer::gpu::{unchecked_cmux_integer_radix_kb_async, CudaServerKey, PBSType};
+use crate::integer::gpu::{
+    unchecked_cmux_integer_radix_kb_assign_async,
+    unchecked_cmux_integer_radix_kb_with_params_async, CudaServerKey,
+};
 
 impl CudaServerKey {
     /// # Safety
@@ -23,7 +26,7 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_cmux_integer_radix_kb_async(
+                unchecked_cmux_integer_radix_kb_with_params_async(
                     stream,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &condition.as_ref().ciphertext.d_blocks.0.d_vec,
@@ -31,27 +34,12 @@ impl CudaServerKey {
                     &false_ct.as_ref().d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_cmux_integer_radix_kb_async(
+                unchecked_cmux_integer_radix_kb_with_params_async(
                     stream,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &condition.as_ref().ciphertext.d_blocks.0.d_vec,
@@ -59,23 +47,8 @@ impl CudaServerKey {
                     &false_ct.as_ref().d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -128,4 +101,342 @@ impl CudaServerKey {
         stream.synchronize();
         result
     }
+
+    /// # Safety
+    ///
+    /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until stream is synchronised
+    pub unsafe fn unchecked_cmux_assign_async<T: CudaIntegerRadixCiphertext>(
+        &self,
+        dst: &mut T,
+        condition: &CudaBooleanBlock,
+        src: &T,
+        stream: &CudaStreams,
+    ) {
+        let lwe_ciphertext_count = src.as_ref().d_blocks.lwe_ciphertext_count();
+        assert_eq!(
+            lwe_ciphertext_count,
+            dst.as_ref().d_blocks.lwe_ciphertext_count(),
+            "Both ciphertexts must have the same number of blocks"
+        );
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                unchecked_cmux_integer_radix_kb_assign_async(
+                    stream,
+                    &condition.as_ref().ciphertext.d_blocks.0.d_vec,
+                    &src.as_ref().d_blocks.0.d_vec,
+                    &mut dst.as_mut().d_blocks.0.d_vec,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                unchecked_cmux_integer_radix_kb_assign_async(
+                    stream,
+                    &condition.as_ref().ciphertext.d_blocks.0.d_vec,
+                    &src.as_ref().d_blocks.0.d_vec,
+                    &mut dst.as_mut().d_blocks.0.d_vec,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
+            }
+        }
+        dst.as_mut().info = src.as_ref().info.after_if_then_else();
+    }
+
+    /// Sets `dst` to `src` if `condition` is true, and leaves it unchanged otherwise.
+    ///
+    /// This is the in place version of [Self::if_then_else], `dst` is used as the false
+    /// ciphertext and receives the result without allocating a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg_dst = 14u64;
+    /// let msg_src = 97u64;
+    ///
+    /// // Copy to GPU
+    /// let mut d_dst = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg_dst), &streams);
+    /// let d_src = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg_src), &streams);
+    /// let d_cond = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(true), &streams);
+    ///
+    /// sks.cmux_assign(&mut d_dst, &d_cond, &d_src, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_dst.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg_src);
+    /// ```
+    pub fn cmux_assign<T: CudaIntegerRadixCiphertext>(
+        &self,
+        dst: &mut T,
+        condition: &CudaBooleanBlock,
+        src: &T,
+        stream: &CudaStreams,
+    ) {
+        let mut tmp_src;
+
+        unsafe {
+            if !dst.block_carries_are_empty() {
+                self.full_propagate_assign_async(dst, stream);
+            }
+
+            let src = if src.block_carries_are_empty() {
+                src
+            } else {
+                tmp_src = src.duplicate_async(stream);
+                self.full_propagate_assign_async(&mut tmp_src, stream);
+                &tmp_src
+            };
+
+            self.unchecked_cmux_assign_async(dst, condition, src, stream);
+        }
+        stream.synchronize();
+    }
+
+    /// Returns the value of the first case whose clear value is equal to `selector`, or
+    /// `default` if no case matches.
+    ///
+    /// This is the encrypted equivalent of a `match` over an integer with clear arms. Each case
+    /// is turned into an equality mask and the values are selected with a chain of cmuxes, so
+    /// when several cases are identical, the value of the first one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let encrypt = |msg: u64| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// let d_selector = encrypt(2);
+    /// let cases = [(1u64, encrypt(10)), (2u64, encrypt(20))];
+    /// let d_default = encrypt(0);
+    ///
+    /// let d_ct_res = sks.switch(&d_selector, &cases, &d_default, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 20);
+    /// ```
+    pub fn switch<S, T, Clear>(
+        &self,
+        selector: &S,
+        cases: &[(Clear, T)],
+        default: &T,
+        stream: &CudaStreams,
+    ) -> T
+    where
+        S: CudaIntegerRadixCiphertext,
+        T: CudaIntegerRadixCiphertext,
+        Clear: DecomposableInto<u64> + Copy,
+    {
+        let result = unsafe { self.switch_async(selector, cases, default, stream) };
+        stream.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until stream is synchronised
+    pub unsafe fn switch_async<S, T, Clear>(
+        &self,
+        selector: &S,
+        cases: &[(Clear, T)],
+        default: &T,
+        stream: &CudaStreams,
+    ) -> T
+    where
+        S: CudaIntegerRadixCiphertext,
+        T: CudaIntegerRadixCiphertext,
+        Clear: DecomposableInto<u64> + Copy,
+    {
+        let mut tmp_selector;
+        let selector = if selector.block_carries_are_empty() {
+            selector
+        } else {
+            tmp_selector = selector.duplicate_async(stream);
+            self.full_propagate_assign_async(&mut tmp_selector, stream);
+            &tmp_selector
+        };
+
+        let mut result = default.duplicate_async(stream);
+        if !result.block_carries_are_empty() {
+            self.full_propagate_assign_async(&mut result, stream);
+        }
+
+        // Cases are applied from last to first, so that the first matching case is the one
+        // selected last
+        for (case, value) in cases.iter().rev() {
+            let mut tmp_value;
+            let value = if value.block_carries_are_empty() {
+                value
+            } else {
+                tmp_value = value.duplicate_async(stream);
+                self.full_propagate_assign_async(&mut tmp_value, stream);
+                &tmp_value
+            };
+
+            let is_case = self.unchecked_scalar_eq_async(selector, *case, stream);
+            self.unchecked_cmux_assign_async(&mut result, &is_case, value, stream);
+        }
+
+        result
+    }
+
+    /// Returns `values[selector]`, or an encryption of zero if `selector` is not a valid index in
+    /// `values`.
+    ///
+    /// The values are selected with a balanced tree of cmuxes, each level of the tree being driven
+    /// by one bit of `selector`, so `values.len() - 1` cmuxes are computed for a power of two number
+    /// of values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let encrypt = |msg: u64| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// let d_selector = encrypt(2);
+    /// let values = [encrypt(10), encrypt(20), encrypt(30)];
+    ///
+    /// let d_ct_res = sks.mux(&d_selector, &values, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 30);
+    /// ```
+    pub fn mux<S, T>(&self, selector: &S, values: &[T], stream: &CudaStreams) -> T
+    where
+        S: CudaIntegerRadixCiphertext,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.mux_async(selector, values, stream) };
+        stream.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until stream is synchronised
+    pub unsafe fn mux_async<S, T>(&self, selector: &S, values: &[T], stream: &CudaStreams) -> T
+    where
+        S: CudaIntegerRadixCiphertext,
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert!(!values.is_empty(), "At least one value is required");
+
+        let num_blocks = values[0].as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            values
+                .iter()
+                .all(|value| value.as_ref().d_blocks.lwe_ciphertext_count().0 == num_blocks),
+            "All values must have the same number of blocks"
+        );
+
+        let mut tmp_selector;
+        let selector = if selector.block_carries_are_empty() {
+            selector
+        } else {
+            tmp_selector = selector.duplicate_async(stream);
+            self.full_propagate_assign_async(&mut tmp_selector, stream);
+            &tmp_selector
+        };
+
+        // Values at indices that do not fit in the selector can never be selected
+        let selector_bits = self.message_modulus.0.ilog2() as usize
+            * selector.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let num_levels = (values.len().next_power_of_two().ilog2() as usize).min(selector_bits);
+
+        let mut level: Vec<T> = values
+            .iter()
+            .take(1 << num_levels)
+            .map(|value| {
+                let mut value = value.duplicate_async(stream);
+                if !value.block_carries_are_empty() {
+                    self.full_propagate_assign_async(&mut value, stream);
+                }
+                value
+            })
+            .collect();
+        level.resize_with(1 << num_levels, || {
+            self.create_trivial_zero_radix_async(num_blocks, stream)
+        });
+
+        for bit in 0..num_levels {
+            let mut selector_bit = selector.duplicate_async(stream);
+            self.scalar_bitand_assign_async(&mut selector_bit, 1u64 << bit, stream);
+            let is_bit_set = self.unchecked_scalar_ne_async(&selector_bit, 0u64, stream);
+
+            level = level
+                .chunks_exact(2)
+                .map(|pair| {
+                    self.unchecked_if_then_else_async(&is_bit_set, &pair[1], &pair[0], stream)
+                })
+                .collect();
+        }
+
+        let result = level.pop().unwrap();
+        let is_in_range = self.unchecked_scalar_lt_async(selector, values.len() as u64, stream);
+        let zero: T = self.create_trivial_zero_radix_async(num_blocks, stream);
+        self.unchecked_if_then_else_async(&is_in_range, &result, &zero, stream)
+    }
 }
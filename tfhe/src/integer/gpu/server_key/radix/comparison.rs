@@ -1,14 +1,18 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{CiphertextModulus, LweBskGroupingFactor, LweCiphertextCount};
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextCount};
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::info::CudaRadixCiphertextInfo;
-use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaRadixCiphertext};
+use crate::integer::gpu::ciphertext::{
+    CudaIntegerRadixCiphertext, CudaRadixCiphertext, CudaSignedRadixCiphertext,
+    CudaUnsignedRadixCiphertext,
+};
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    unchecked_comparison_integer_radix_kb_async, ComparisonType, CudaServerKey, PBSType,
+    unchecked_comparison_integer_radix_kb_with_params_async, ComparisonType, CudaServerKey,
 };
-use crate::shortint::ciphertext::Degree;
+use crate::integer::U256;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 
 impl CudaServerKey {
     /// # Safety
@@ -25,6 +29,35 @@ impl CudaServerKey {
     where
         T: CudaIntegerRadixCiphertext,
     {
+        self.try_unchecked_comparison_async(ct_left, ct_right, op, streams)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Fallible version of [Self::unchecked_comparison_async].
+    ///
+    /// Returns an error instead of panicking if an input resides on another GPU than the one of
+    /// the first stream of `streams` and automatic migration is disabled, see
+    /// [Self::set_auto_migrate].
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn try_unchecked_comparison_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        op: ComparisonType,
+        streams: &CudaStreams,
+    ) -> crate::Result<CudaBooleanBlock>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let migrated_left = self.migrated_async(ct_left, streams);
+        let ct_left = migrated_left.as_ref().unwrap_or(ct_left);
+        let migrated_right = self.migrated_async(ct_right, streams);
+        let ct_right = migrated_right.as_ref().unwrap_or(ct_right);
+
         assert_eq!(
             ct_left.as_ref().d_blocks.lwe_dimension(),
             ct_right.as_ref().d_blocks.lwe_dimension()
@@ -52,66 +85,36 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
+                unchecked_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
                     &ct_left.as_ref().d_blocks.0.d_vec,
                     &ct_right.as_ref().d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     op,
                     T::IS_SIGNED,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
-                );
+                )?;
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
+                unchecked_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
                     &ct_left.as_ref().d_blocks.0.d_vec,
                     &ct_right.as_ref().d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     op,
                     T::IS_SIGNED,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
-                );
+                )?;
             }
         }
 
-        result
+        Ok(result)
     }
 
     /// # Safety
@@ -356,6 +359,74 @@ impl CudaServerKey {
         result
     }
 
+    /// Compares for equality a ciphertext and a trivially encrypted ciphertext.
+    ///
+    /// The clear value of `trivial_ct` is extracted and a scalar comparison is done, which is
+    /// cheaper than a full ciphertext-ciphertext comparison. If `trivial_ct` turns out not to be
+    /// trivial (or is too large for the scalar path), this falls back to [Self::eq].
+    ///
+    /// There is no asynchronous version of this function, as extracting the clear value copies
+    /// `trivial_ct` back to the CPU, which waits for the computations on `streams`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 14u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// let d_trivial: CudaUnsignedRadixCiphertext = sks.create_trivial_radix(msg, size, &streams);
+    ///
+    /// let d_ct_res = sks.eq_trivial(&d_ct, &d_trivial, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn eq_trivial(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        trivial_ct: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let info = &trivial_ct.as_ref().info;
+        let num_bits = self.message_modulus.0.ilog2() as usize * info.blocks.len();
+
+        if num_bits <= U256::BITS as usize
+            && info
+                .blocks
+                .iter()
+                .all(|block| block.noise_level == NoiseLevel::ZERO)
+        {
+            // The metadata can be wrong, the CPU copy checks the blocks are actually trivial
+            let h_trivial_ct = trivial_ct.to_radix_ciphertext(streams);
+            if let Ok(clear) = h_trivial_ct.decrypt_trivial::<U256>() {
+                return self.scalar_eq(ct, clear, streams);
+            }
+        }
+
+        self.eq(ct, trivial_ct, streams)
+    }
+
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -957,82 +1028,27 @@ impl CudaServerKey {
     where
         T: CudaIntegerRadixCiphertext,
     {
-        assert_eq!(
-            ct_left.as_ref().d_blocks.lwe_dimension(),
-            ct_right.as_ref().d_blocks.lwe_dimension()
-        );
-        assert_eq!(
-            ct_left.as_ref().d_blocks.lwe_ciphertext_count(),
-            ct_right.as_ref().d_blocks.lwe_ciphertext_count()
-        );
-
-        let mut result = ct_left.duplicate_async(streams);
-
-        let lwe_ciphertext_count = ct_left.as_ref().d_blocks.lwe_ciphertext_count();
-
-        match &self.bootstrapping_key {
-            CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
-                    streams,
-                    &mut result.as_mut().d_blocks.0.d_vec,
-                    &ct_left.as_ref().d_blocks.0.d_vec,
-                    &ct_right.as_ref().d_blocks.0.d_vec,
-                    &d_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
-                    lwe_ciphertext_count.0 as u32,
-                    ComparisonType::MAX,
-                    T::IS_SIGNED,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
-                );
-            }
-            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
-                    streams,
-                    &mut result.as_mut().d_blocks.0.d_vec,
-                    &ct_left.as_ref().d_blocks.0.d_vec,
-                    &ct_right.as_ref().d_blocks.0.d_vec,
-                    &d_multibit_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
-                    lwe_ciphertext_count.0 as u32,
-                    ComparisonType::MAX,
-                    T::IS_SIGNED,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
-                );
-            }
-        }
-        result.as_mut().info = ct_left.as_ref().info.after_min_max();
+        self.try_unchecked_max_async(ct_left, ct_right, streams)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        result
+    /// Fallible version of [Self::unchecked_max_async], see
+    /// [Self::try_unchecked_comparison_async] for the errors that are reported
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn try_unchecked_max_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> crate::Result<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        self.try_unchecked_min_max_async(ct_left, ct_right, ComparisonType::MAX, streams)
     }
 
     pub fn unchecked_max<T>(&self, ct_left: &T, ct_right: &T, streams: &CudaStreams) -> T
@@ -1057,6 +1073,50 @@ impl CudaServerKey {
     where
         T: CudaIntegerRadixCiphertext,
     {
+        self.try_unchecked_min_async(ct_left, ct_right, streams)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Fallible version of [Self::unchecked_min_async], see
+    /// [Self::try_unchecked_comparison_async] for the errors that are reported
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn try_unchecked_min_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> crate::Result<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        self.try_unchecked_min_max_async(ct_left, ct_right, ComparisonType::MIN, streams)
+    }
+
+    /// Computes the min or the max of the two ciphertexts depending on `op`
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    unsafe fn try_unchecked_min_max_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        op: ComparisonType,
+        streams: &CudaStreams,
+    ) -> crate::Result<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let migrated_left = self.migrated_async(ct_left, streams);
+        let ct_left = migrated_left.as_ref().unwrap_or(ct_left);
+        let migrated_right = self.migrated_async(ct_right, streams);
+        let ct_right = migrated_right.as_ref().unwrap_or(ct_right);
+
         assert_eq!(
             ct_left.as_ref().d_blocks.lwe_dimension(),
             ct_right.as_ref().d_blocks.lwe_dimension()
@@ -1072,66 +1132,37 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
+                unchecked_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &ct_left.as_ref().d_blocks.0.d_vec,
                     &ct_right.as_ref().d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    ComparisonType::MIN,
+                    op,
                     T::IS_SIGNED,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
-                );
+                )?;
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_comparison_integer_radix_kb_async(
+                unchecked_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &ct_left.as_ref().d_blocks.0.d_vec,
                     &ct_right.as_ref().d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    ComparisonType::MIN,
+                    op,
                     T::IS_SIGNED,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
-                );
+                )?;
             }
         }
         result.as_mut().info = ct_left.as_ref().info.after_min_max();
-        result
+
+        Ok(result)
     }
 
     pub fn unchecked_min<T>(&self, ct_left: &T, ct_right: &T, streams: &CudaStreams) -> T
@@ -1236,4 +1267,348 @@ impl CudaServerKey {
         streams.synchronize();
         result
     }
+
+    /// Returns both the minimum and the maximum of two ciphertexts, as `(min, max)`.
+    ///
+    /// A single comparison is computed and shared by the two cmuxes selecting the results, which
+    /// is cheaper than calling [Self::min] and [Self::max], as each of them computes its own
+    /// comparison. This is the building block of sorting networks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg1 = 97u64;
+    /// let msg2 = 14u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// let (d_min, d_max) = sks.min_max(&d_ct1, &d_ct2, &streams);
+    ///
+    /// // Copy the results back to CPU and decrypt
+    /// let min: u64 = cks.decrypt(&d_min.to_radix_ciphertext(&streams));
+    /// let max: u64 = cks.decrypt(&d_max.to_radix_ciphertext(&streams));
+    /// assert_eq!(min, msg2);
+    /// assert_eq!(max, msg1);
+    /// ```
+    pub fn min_max<T>(&self, ct_left: &T, ct_right: &T, streams: &CudaStreams) -> (T, T)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.min_max_async(ct_left, ct_right, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn min_max_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> (T, T)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ct_left.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ct_left, ct_right),
+            (true, false) => {
+                tmp_rhs = ct_right.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (ct_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                (&tmp_lhs, ct_right)
+            }
+            (false, false) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                tmp_rhs = ct_right.duplicate_async(streams);
+
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        let is_gt = self.unchecked_gt_async(lhs, rhs, streams);
+        let min = self.unchecked_if_then_else_async(&is_gt, rhs, lhs, streams);
+        let max = self.unchecked_if_then_else_async(&is_gt, lhs, rhs, streams);
+        (min, max)
+    }
+
+    /// Compares two ciphertexts, like [Ord::cmp].
+    ///
+    /// Returns a signed ciphertext with the same number of blocks as the inputs, encrypting
+    /// - `-1` if `ct_left < ct_right`
+    /// - `0` if `ct_left == ct_right`
+    /// - `1` if `ct_left > ct_right`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// let d_ct_res = sks.cmp(&d_ct1, &d_ct2, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_signed_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, -1);
+    /// ```
+    pub fn cmp<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> CudaSignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.cmp_async(ct_left, ct_right, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn cmp_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> CudaSignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ct_left.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ct_left, ct_right),
+            (true, false) => {
+                tmp_rhs = ct_right.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (ct_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                (&tmp_lhs, ct_right)
+            }
+            (false, false) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                tmp_rhs = ct_right.duplicate_async(streams);
+
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        let num_blocks = lhs.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        // cmp = (lhs > rhs) - (lhs < rhs)
+        let [is_gt, is_lt] = [
+            self.unchecked_gt_async(lhs, rhs, streams),
+            self.unchecked_lt_async(lhs, rhs, streams),
+        ]
+        .map(|is_true| CudaSignedRadixCiphertext {
+            ciphertext: self
+                .extend_radix_with_trivial_zero_blocks_msb_async(
+                    &is_true.0,
+                    num_blocks - 1,
+                    streams,
+                )
+                .ciphertext,
+        });
+
+        let mut result = is_gt;
+        self.sub_assign_async(&mut result, &is_lt, streams);
+        result
+    }
+
+    /// Returns the minimum of all the ciphertexts of `cts`, or `None` if `cts` is empty.
+    ///
+    /// The minimums are computed pairwise in a balanced tree, so the depth of the computation is
+    /// logarithmic in the number of ciphertexts instead of linear.
+    ///
+    /// Expects the ciphertexts to have clean carries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks or do not all reside
+    /// on the first GPU of `streams`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msgs = [14u64, 97, 3, 42, 7];
+    ///
+    /// // Copy to GPU
+    /// let d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let d_min = sks.unchecked_min_many(&d_cts, &streams).unwrap();
+    ///
+    /// // Copy the result back to CPU and decrypt
+    /// let min: u64 = cks.decrypt(&d_min.to_radix_ciphertext(&streams));
+    /// assert_eq!(min, 3);
+    /// ```
+    pub fn unchecked_min_many<T>(&self, cts: &[T], streams: &CudaStreams) -> Option<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.unchecked_min_many_async(cts, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_min_many_async<T>(&self, cts: &[T], streams: &CudaStreams) -> Option<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        self.unchecked_min_or_max_many_async(cts, ComparisonType::MIN, streams)
+    }
+
+    /// Returns the maximum of all the ciphertexts of `cts`, or `None` if `cts` is empty.
+    ///
+    /// See [Self::unchecked_min_many] for details.
+    pub fn unchecked_max_many<T>(&self, cts: &[T], streams: &CudaStreams) -> Option<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.unchecked_max_many_async(cts, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_max_many_async<T>(&self, cts: &[T], streams: &CudaStreams) -> Option<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        self.unchecked_min_or_max_many_async(cts, ComparisonType::MAX, streams)
+    }
+
+    unsafe fn unchecked_min_or_max_many_async<T>(
+        &self,
+        cts: &[T],
+        op: ComparisonType,
+        streams: &CudaStreams,
+    ) -> Option<T>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let num_blocks = cts.first()?.as_ref().d_blocks.lwe_ciphertext_count();
+        for ct in cts {
+            assert_eq!(
+                ct.as_ref().d_blocks.lwe_ciphertext_count(),
+                num_blocks,
+                "All ciphertexts must have the same number of blocks"
+            );
+            assert_eq!(
+                ct.as_ref().d_blocks.0.d_vec.gpu_index(0),
+                streams.gpu_indexes[0],
+                "GPU error: all data should reside on the same GPU."
+            );
+        }
+
+        // Reduces pairs of ciphertexts, an odd one out is carried to the next level
+        let reduce_level = |level: &[T]| -> Vec<T> {
+            level
+                .chunks(2)
+                .map(|pair| match (pair, op) {
+                    ([lhs, rhs], ComparisonType::MIN) => {
+                        self.unchecked_min_async(lhs, rhs, streams)
+                    }
+                    ([lhs, rhs], ComparisonType::MAX) => {
+                        self.unchecked_max_async(lhs, rhs, streams)
+                    }
+                    ([single], _) => single.duplicate_async(streams),
+                    _ => unreachable!("only MIN and MAX can reduce ciphertexts"),
+                })
+                .collect()
+        };
+
+        let mut level = reduce_level(cts);
+        while level.len() > 1 {
+            level = reduce_level(&level);
+        }
+        level.pop()
+    }
 }
@@ -1,8 +1,15 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
-use crate::integer::gpu::{unchecked_div_rem_integer_radix_kb_assign_async, PBSType};
+use crate::integer::gpu::{
+    unchecked_div_rem_integer_radix_kb_assign_with_params_async,
+    unchecked_div_rem_integer_radix_kb_vec_assign_async,
+};
+use rayon::prelude::*;
+
+// Each group of div_rem_vec has its own scratch memory, which bounds the memory used by the
+// concurrent divisions
+const DIV_REM_VEC_MAX_CONCURRENT_GROUPS: usize = 4;
 
 impl CudaServerKey {
     /// # Safety
@@ -23,7 +30,7 @@ impl CudaServerKey {
         let num_blocks = divisor.as_ref().d_blocks.lwe_ciphertext_count().0 as u32;
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_div_rem_integer_radix_kb_assign_async(
+                unchecked_div_rem_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut quotient.as_mut().d_blocks.0.d_vec,
                     &mut remainder.as_mut().d_blocks.0.d_vec,
@@ -32,27 +39,12 @@ impl CudaServerKey {
                     T::IS_SIGNED,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_blocks,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_div_rem_integer_radix_kb_assign_async(
+                unchecked_div_rem_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut quotient.as_mut().d_blocks.0.d_vec,
                     &mut remainder.as_mut().d_blocks.0.d_vec,
@@ -61,23 +53,8 @@ impl CudaServerKey {
                     T::IS_SIGNED,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_blocks,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         };
@@ -245,4 +222,236 @@ impl CudaServerKey {
             streams,
         );
     }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_div_rem_vec_async<T>(
+        &self,
+        numerators: &[T],
+        divisors: &[T],
+        streams: &CudaStreams,
+    ) -> (Vec<T>, Vec<T>)
+    where
+        T: CudaIntegerRadixCiphertext + Send + Sync,
+    {
+        assert_eq!(
+            numerators.len(),
+            divisors.len(),
+            "numerators and divisors must have the same length"
+        );
+        let Some(first) = numerators.first() else {
+            return (vec![], vec![]);
+        };
+        let num_blocks = first.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            numerators.iter().chain(divisors.iter()).all(|ct| ct
+                .as_ref()
+                .d_blocks
+                .lwe_ciphertext_count()
+                .0
+                == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        // The inputs may still be written by work queued on streams
+        streams.synchronize();
+
+        // Each group of pairs is divided on its own stream, with its own scratch memory
+        let num_groups = numerators.len().min(DIV_REM_VEC_MAX_CONCURRENT_GROUPS);
+        let group_size = numerators.len().div_ceil(num_groups);
+        let mut streams_vector = Vec::<CudaStreams>::with_capacity(num_groups);
+        for _ in 0..num_groups {
+            streams_vector.push(CudaStreams::new_single_gpu(streams.gpu_indexes[0]));
+        }
+
+        let results = numerators
+            .par_chunks(group_size)
+            .zip(divisors.par_chunks(group_size))
+            .zip(streams_vector.par_iter())
+            .map(|((numerators, divisors), group_streams)| {
+                let result = self.unchecked_div_rem_group_async(
+                    numerators,
+                    divisors,
+                    num_blocks,
+                    group_streams,
+                );
+                group_streams.synchronize();
+                result
+            })
+            .collect::<Vec<_>>();
+
+        let mut quotients = Vec::with_capacity(numerators.len());
+        let mut remainders = Vec::with_capacity(numerators.len());
+        for (group_quotients, group_remainders) in results {
+            quotients.extend(group_quotients);
+            remainders.extend(group_remainders);
+        }
+
+        (quotients, remainders)
+    }
+
+    // Divides each pair of numerators and divisors one after another on streams, sharing the
+    // same scratch memory
+    unsafe fn unchecked_div_rem_group_async<T>(
+        &self,
+        numerators: &[T],
+        divisors: &[T],
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> (Vec<T>, Vec<T>)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut quotients: Vec<T> = numerators
+            .iter()
+            .map(|ct| ct.duplicate_async(streams))
+            .collect();
+        let mut remainders: Vec<T> = numerators
+            .iter()
+            .map(|ct| ct.duplicate_async(streams))
+            .collect();
+
+        {
+            let mut d_quotients = quotients
+                .iter_mut()
+                .map(|ct| &mut ct.as_mut().d_blocks.0.d_vec)
+                .collect::<Vec<_>>();
+            let mut d_remainders = remainders
+                .iter_mut()
+                .map(|ct| &mut ct.as_mut().d_blocks.0.d_vec)
+                .collect::<Vec<_>>();
+            let d_numerators = numerators
+                .iter()
+                .map(|ct| &ct.as_ref().d_blocks.0.d_vec)
+                .collect::<Vec<_>>();
+            let d_divisors = divisors
+                .iter()
+                .map(|ct| &ct.as_ref().d_blocks.0.d_vec)
+                .collect::<Vec<_>>();
+
+            match &self.bootstrapping_key {
+                CudaBootstrappingKey::Classic(d_bsk) => {
+                    unchecked_div_rem_integer_radix_kb_vec_assign_async(
+                        streams,
+                        &mut d_quotients,
+                        &mut d_remainders,
+                        &d_numerators,
+                        &d_divisors,
+                        T::IS_SIGNED,
+                        &d_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        num_blocks as u32,
+                    );
+                }
+                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                    unchecked_div_rem_integer_radix_kb_vec_assign_async(
+                        streams,
+                        &mut d_quotients,
+                        &mut d_remainders,
+                        &d_numerators,
+                        &d_divisors,
+                        T::IS_SIGNED,
+                        &d_multibit_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        num_blocks as u32,
+                    );
+                }
+            }
+        }
+
+        for ct in quotients.iter_mut().chain(remainders.iter_mut()) {
+            ct.as_mut().info = ct.as_ref().info.after_div_rem();
+        }
+
+        (quotients, remainders)
+    }
+
+    /// Computes homomorphically the quotient and remainder of each pair of `numerators` and
+    /// `divisors`.
+    ///
+    /// The pairs are split in up to 4 groups, computed concurrently, each of them on its own
+    /// stream of the first GPU of `streams`. The divisions of a group are computed one after
+    /// another and share the same scratch memory, which amortizes its allocation when many pairs
+    /// have to be divided. All the ciphertexts must have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let numerators = [97u64, 200];
+    /// let divisors = [8u64, 13];
+    ///
+    /// // Copy to GPU
+    /// let d_numerators = numerators
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    /// let d_divisors = divisors
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (d_quotients, d_remainders) = sks.div_rem_vec(&d_numerators, &d_divisors, &streams);
+    ///
+    /// for i in 0..numerators.len() {
+    ///     // Copy the results back to CPU and decrypt
+    ///     let q: u64 = cks.decrypt(&d_quotients[i].to_radix_ciphertext(&streams));
+    ///     let r: u64 = cks.decrypt(&d_remainders[i].to_radix_ciphertext(&streams));
+    ///     assert_eq!(q, numerators[i] / divisors[i]);
+    ///     assert_eq!(r, numerators[i] % divisors[i]);
+    /// }
+    /// ```
+    pub fn div_rem_vec<T>(
+        &self,
+        numerators: &[T],
+        divisors: &[T],
+        streams: &CudaStreams,
+    ) -> (Vec<T>, Vec<T>)
+    where
+        T: CudaIntegerRadixCiphertext + Send + Sync,
+    {
+        let result = unsafe {
+            if numerators
+                .iter()
+                .chain(divisors.iter())
+                .all(|ct| ct.block_carries_are_empty())
+            {
+                self.unchecked_div_rem_vec_async(numerators, divisors, streams)
+            } else {
+                let propagate = |cts: &[T]| -> Vec<T> {
+                    cts.iter()
+                        .map(|ct| {
+                            let mut tmp = ct.duplicate_async(streams);
+                            if !tmp.block_carries_are_empty() {
+                                self.full_propagate_assign_async(&mut tmp, streams);
+                            }
+                            tmp
+                        })
+                        .collect()
+                };
+                let numerators = propagate(numerators);
+                let divisors = propagate(divisors);
+                self.unchecked_div_rem_vec_async(&numerators, &divisors, streams)
+            }
+        };
+        streams.synchronize();
+        result
+    }
 }
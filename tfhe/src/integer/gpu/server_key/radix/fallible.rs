@@ -0,0 +1,298 @@
+use crate::core_crypto::gpu::{CudaError, CudaStreams};
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::server_key::CudaServerKey;
+
+impl CudaServerKey {
+    /// Returns whether the two ciphertexts reside on the same GPU.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(14u64), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(97u64), &streams);
+    ///
+    /// assert!(CudaServerKey::same_gpu(&d_ct1, &d_ct2));
+    /// CudaServerKey::require_same_gpu(&d_ct1, &d_ct2).unwrap();
+    /// ```
+    pub fn same_gpu<T: CudaIntegerRadixCiphertext>(ct_left: &T, ct_right: &T) -> bool {
+        Self::require_same_gpu(ct_left, ct_right).is_ok()
+    }
+
+    /// Returns an error if the two ciphertexts do not reside on the same GPU, see
+    /// [Self::same_gpu]
+    pub fn require_same_gpu<T: CudaIntegerRadixCiphertext>(
+        ct_left: &T,
+        ct_right: &T,
+    ) -> Result<(), CudaError> {
+        let expected = ct_left.as_ref().d_blocks.0.d_vec.gpu_index(0);
+        let got = ct_right.as_ref().d_blocks.0.d_vec.gpu_index(0);
+        if expected != got {
+            return Err(CudaError::GpuMismatch { expected, got });
+        }
+        Ok(())
+    }
+
+    /// Checks that the inputs reside on the GPU of the first stream of `streams`, and that this
+    /// GPU has enough free memory to process `num_buffer_blocks` blocks.
+    ///
+    /// The memory needed for a block is estimated as the size of a GLWE accumulator, which is the
+    /// largest per-block buffer used by a programmable bootstrap. This is a heuristic: the backend
+    /// may need more memory, or the memory may be used concurrently, in which case the backend
+    /// fails to allocate and aborts the process.
+    fn check_can_launch<T>(
+        &self,
+        inputs: &[&T],
+        num_buffer_blocks: usize,
+        streams: &CudaStreams,
+    ) -> Result<(), CudaError>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let gpu_index = streams.gpu_indexes[0];
+        for input in inputs {
+            let input_gpu_index = input.as_ref().d_blocks.0.d_vec.gpu_index(0);
+            if input_gpu_index != gpu_index {
+                return Err(CudaError::GpuMismatch {
+                    expected: gpu_index,
+                    got: input_gpu_index,
+                });
+            }
+        }
+
+        let params = self.pbs_params();
+        let block_size = (params.glwe_dimension.to_glwe_size().0 * params.polynomial_size.0)
+            .max(params.big_lwe_dimension.to_lwe_size().0)
+            * std::mem::size_of::<u64>();
+        let required = (num_buffer_blocks * block_size) as u64;
+
+        let (available, _) = CudaStreams::available_memory(gpu_index)
+            .map_err(|_| CudaError::MemoryQuery { gpu_index })?;
+        if required > available {
+            return Err(CudaError::OutOfMemory {
+                gpu_index,
+                required,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    /// Copies the inputs to the GPU of `streams` if automatic migration is enabled, checks that
+    /// they can be processed with [Self::check_can_launch], then runs `op` on them and
+    /// synchronizes `streams`.
+    fn try_binary_op<T, R>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        num_buffer_blocks: usize,
+        streams: &CudaStreams,
+        op: impl FnOnce(&T, &T) -> R,
+    ) -> Result<R, CudaError>
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let migrated_left = unsafe { self.migrated_async(ct_left, streams) };
+        let ct_left = migrated_left.as_ref().unwrap_or(ct_left);
+        let migrated_right = unsafe { self.migrated_async(ct_right, streams) };
+        let ct_right = migrated_right.as_ref().unwrap_or(ct_right);
+        self.check_can_launch(&[ct_left, ct_right], num_buffer_blocks, streams)?;
+
+        let result = op(ct_left, ct_right);
+        streams.try_synchronize()?;
+        Ok(result)
+    }
+
+    /// Fallible version of [Self::add].
+    ///
+    /// Returns an error instead of panicking or aborting if the inputs do not reside on the GPU of
+    /// `streams` and automatic migration is disabled (see [Self::set_auto_migrate]), if the GPU is
+    /// estimated not to have enough free memory, or if the backend reports an error when
+    /// synchronizing.
+    ///
+    /// The free memory check is only an estimation done before launching the operation, an
+    /// allocation failing inside the backend still aborts the process.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let d_ct_res = sks.try_add(&d_ct1, &d_ct2, &streams).unwrap();
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1 + msg2);
+    /// ```
+    pub fn try_add<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<T, CudaError> {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        self.try_binary_op(
+            ct_left,
+            ct_right,
+            4 * num_blocks,
+            streams,
+            |ct_left, ct_right| unsafe {
+                let mut result = ct_left.duplicate_async(streams);
+                self.add_assign_async(&mut result, ct_right, streams);
+                result
+            },
+        )
+    }
+
+    /// Fallible version of [Self::sub], see [Self::try_add] for the errors that are reported
+    pub fn try_sub<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<T, CudaError> {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        self.try_binary_op(
+            ct_left,
+            ct_right,
+            4 * num_blocks,
+            streams,
+            |ct_left, ct_right| unsafe { self.sub_async(ct_left, ct_right, streams) },
+        )
+    }
+
+    /// Fallible version of [Self::mul], see [Self::try_add] for the errors that are reported
+    pub fn try_mul<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<T, CudaError> {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        // The partial products need num_blocks blocks for each block of the right operand
+        let num_buffer_blocks = 4 * num_blocks * num_blocks;
+        self.try_binary_op(
+            ct_left,
+            ct_right,
+            num_buffer_blocks,
+            streams,
+            |ct_left, ct_right| unsafe {
+                let mut result = ct_left.duplicate_async(streams);
+                self.mul_assign_async(&mut result, ct_right, streams);
+                result
+            },
+        )
+    }
+
+    fn try_comparison<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+        comparison: unsafe fn(&Self, &T, &T, &CudaStreams) -> CudaBooleanBlock,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        self.try_binary_op(
+            ct_left,
+            ct_right,
+            4 * num_blocks,
+            streams,
+            |ct_left, ct_right| unsafe { comparison(self, ct_left, ct_right, streams) },
+        )
+    }
+
+    /// Fallible version of [Self::eq], see [Self::try_add] for the errors that are reported
+    pub fn try_eq<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::eq_async)
+    }
+
+    /// Fallible version of [Self::ne], see [Self::try_add] for the errors that are reported
+    pub fn try_ne<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::ne_async)
+    }
+
+    /// Fallible version of [Self::gt], see [Self::try_add] for the errors that are reported
+    pub fn try_gt<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::gt_async)
+    }
+
+    /// Fallible version of [Self::ge], see [Self::try_add] for the errors that are reported
+    pub fn try_ge<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::ge_async)
+    }
+
+    /// Fallible version of [Self::lt], see [Self::try_add] for the errors that are reported
+    pub fn try_lt<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::lt_async)
+    }
+
+    /// Fallible version of [Self::le], see [Self::try_add] for the errors that are reported
+    pub fn try_le<T: CudaIntegerRadixCiphertext>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        streams: &CudaStreams,
+    ) -> Result<CudaBooleanBlock, CudaError> {
+        self.try_comparison(ct_left, ct_right, streams, Self::le_async)
+    }
+}
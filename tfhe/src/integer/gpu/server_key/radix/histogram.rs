@@ -0,0 +1,104 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::CudaServerKey;
+
+impl CudaServerKey {
+    /// Computes the histogram of the encrypted `values`.
+    ///
+    /// Returns `num_bins` ciphertexts, the i-th one encrypting the number of values equal to `i`.
+    /// Values greater or equal to `num_bins` are not counted in any bin.
+    ///
+    /// The counts have the same number of blocks as the values, so they wrap around if more
+    /// than `2^num_bits` values fall in the same bin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty, or if the values do not all have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msgs = [1u64, 3, 1, 200, 0];
+    ///
+    /// // Copy to GPU
+    /// let d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let d_bins = sks.histogram(&d_cts, 4, &streams);
+    ///
+    /// // Copy the result back to CPU and decrypt:
+    /// let bins = d_bins
+    ///     .iter()
+    ///     .map(|d_bin| cks.decrypt::<u64>(&d_bin.to_radix_ciphertext(&streams)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(bins, vec![1, 2, 0, 1]);
+    /// ```
+    pub fn histogram(
+        &self,
+        values: &[CudaUnsignedRadixCiphertext],
+        num_bins: u32,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        let result = unsafe { self.histogram_async(values, num_bins, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn histogram_async(
+        &self,
+        values: &[CudaUnsignedRadixCiphertext],
+        num_bins: u32,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        assert!(
+            !values.is_empty(),
+            "Cannot compute the histogram of an empty list"
+        );
+        let num_blocks = values[0].as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            values
+                .iter()
+                .all(|ct| ct.as_ref().d_blocks.lwe_ciphertext_count().0 == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        (0..num_bins)
+            .map(|bin| {
+                // Each value contributes 1 to the bin it is equal to
+                let masks = values
+                    .iter()
+                    .map(|value| {
+                        let is_in_bin = self.scalar_eq_async(value, bin, streams);
+                        self.extend_radix_with_trivial_zero_blocks_msb_async(
+                            &is_in_bin.0,
+                            num_blocks - 1,
+                            streams,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                self.sum_ciphertexts_async(masks, streams).unwrap()
+            })
+            .collect()
+    }
+}
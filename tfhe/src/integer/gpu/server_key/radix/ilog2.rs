@@ -1,15 +1,15 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{LweBskGroupingFactor, LweCiphertextCount};
+use crate::core_crypto::prelude::LweCiphertextCount;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::{
     CudaIntegerRadixCiphertext, CudaSignedRadixCiphertext, CudaUnsignedRadixCiphertext,
 };
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
-    apply_univariate_lut_kb_async, compute_prefix_sum_hillis_steele_async,
-    reverse_blocks_inplace_async, PBSType,
+    apply_univariate_lut_kb_with_params_async, compute_prefix_sum_hillis_steele_with_params_async,
+    reverse_blocks_inplace_async, unchecked_count_ones_async,
 };
 use crate::integer::server_key::radix_parallel::ilog2::{BitValue, Direction};
 
@@ -86,51 +86,27 @@ impl CudaServerKey {
         // in the message space
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -175,7 +151,7 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                compute_prefix_sum_hillis_steele_async(
+                compute_prefix_sum_hillis_steele_with_params_async(
                     streams,
                     &mut output_cts
                         .0
@@ -186,25 +162,13 @@ impl CudaServerKey {
                     sum_lut.acc.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                     0u32,
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                compute_prefix_sum_hillis_steele_async(
+                compute_prefix_sum_hillis_steele_with_params_async(
                     streams,
                     &mut output_cts
                         .0
@@ -215,20 +179,8 @@ impl CudaServerKey {
                     sum_lut.acc.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                     0u32,
                 );
             }
@@ -241,6 +193,9 @@ impl CudaServerKey {
     /// The returned Ciphertexts has a variable size
     /// i.e. It contains just the minimum number of block
     /// needed to represent the maximum possible number of bits.
+    ///
+    /// The backend has no dedicated kernel for this: the count is built from the univariate LUT,
+    /// prefix sum and partial sum kernels of [crate::integer::gpu].
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -477,7 +432,8 @@ impl CudaServerKey {
         // We add `1` to this number as we are going to use signed numbers later
         //
         // The ilog2 of a number that is on n bits, is in range 1..=n-1
-        let counter_num_blocks = ((num_bits_in_ciphertext - 1).ilog2() + 1 + 1)
+        // (`max(1)` handles single bit ciphertexts, where the ilog2 is always 0)
+        let counter_num_blocks = ((num_bits_in_ciphertext - 1).max(1).ilog2() + 1 + 1)
             .div_ceil(self.message_modulus.0.ilog2()) as usize;
 
         // 11111000
@@ -583,51 +539,27 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut message_blocks_slice,
                     &result_slice,
                     lut_a.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     counter_num_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut message_blocks_slice,
                     &result_slice,
                     lut_a.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     counter_num_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -677,51 +609,27 @@ impl CudaServerKey {
         unsafe {
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut carry_blocks_slice,
                         &result_slice,
                         lut_b.acc.as_ref(),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         counter_num_blocks as u32 - 1,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut carry_blocks_slice,
                         &result_slice,
                         lut_b.acc.as_ref(),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         counter_num_blocks as u32 - 1,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                     );
                 }
             }
@@ -786,6 +694,9 @@ impl CudaServerKey {
     /// i.e. It contains just the minimum number of block
     /// needed to represent the maximum possible number of bits.
     ///
+    /// Only the raw bits of `ct` are counted, so signed and unsigned ciphertexts behave the same.
+    /// If `ct` encrypts 0, the result is the number of bits of `ct`.
+    ///
     /// This is a default function, it will internally clone the ciphertext if it has
     /// non propagated carries, and it will output a ciphertext without any carries.
     ///
@@ -932,6 +843,9 @@ impl CudaServerKey {
     /// i.e. It contains just the minimum number of block
     /// needed to represent the maximum possible number of bits.
     ///
+    /// Only the raw bits of `ct` are counted, so signed and unsigned ciphertexts behave the same.
+    /// If `ct` encrypts 0, the result is the number of bits of `ct`.
+    ///
     /// This is a default function, it will internally clone the ciphertext if it has
     /// non propagated carries, and it will output a ciphertext without any carries.
     ///
@@ -1216,4 +1130,437 @@ impl CudaServerKey {
             self.scalar_gt_async(ct, 0, streams),
         )
     }
+
+    /// Returns the logarithm of the number in the given clear `base`, rounded down.
+    ///
+    /// The result is computed by comparing `ct` to each power of `base` that fits in the
+    /// ciphertext, so the number of comparisons is bounded by the number of bits of `ct`.
+    ///
+    /// Unlike the clear `ilog`, this does not panic when `ct` is 0 (or negative for signed
+    /// ciphertexts): the result is 0 in that case, use [Self::scalar_gt] to check the validity
+    /// of the input.
+    ///
+    /// The returned Ciphertexts has a variable size
+    /// i.e. It contains just the minimum number of block
+    /// needed to represent the maximum possible result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than 2 or if `ct` encrypts more than 128 bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 1000u16;
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// // Compute homomorphically a log in base 10
+    /// let d_ct_res = sks.ilog(&d_ct, 10, &streams);
+    ///
+    /// // Decrypt
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.ilog(10));
+    /// ```
+    pub fn ilog<T>(&self, ct: &T, base: u32, streams: &CudaStreams) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let res = unsafe { self.ilog_async(ct, base, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn ilog_async<T>(
+        &self,
+        ct: &T,
+        base: u32,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert!(base >= 2, "The base of the logarithm must be at least 2");
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        if num_blocks == 0 {
+            return self.create_trivial_zero_radix_async(0, streams);
+        }
+
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp, streams);
+            &tmp
+        };
+
+        let num_bits_in_message = self.message_modulus.0.ilog2();
+        let num_bits_in_ciphertext = num_bits_in_message
+            .checked_mul(num_blocks as u32)
+            .expect("Number of bits encrypted exceeds u32::MAX");
+        assert!(
+            num_bits_in_ciphertext <= u128::BITS,
+            "ilog only supports ciphertexts of up to 128 bits"
+        );
+
+        let num_value_bits = if T::IS_SIGNED {
+            num_bits_in_ciphertext - 1
+        } else {
+            num_bits_in_ciphertext
+        };
+        let max_value = if num_value_bits == 0 {
+            0
+        } else {
+            u128::MAX >> (u128::BITS - num_value_bits)
+        };
+
+        // ilog(x) is the number of powers base^k, k >= 1, that are <= x
+        let mut powers = Vec::new();
+        let mut power = u128::from(base);
+        while power <= max_value {
+            powers.push(power);
+            match power.checked_mul(u128::from(base)) {
+                Some(next_power) => power = next_power,
+                None => break,
+            }
+        }
+
+        if powers.is_empty() {
+            return self.create_trivial_zero_radix_async(1, streams);
+        }
+
+        let counter_num_blocks =
+            ((powers.len() as u32).ilog2() + 1).div_ceil(num_bits_in_message) as usize;
+
+        let cts = powers
+            .into_iter()
+            .map(|power| {
+                let is_greater_or_equal = self.unchecked_scalar_ge_async(ct, power, streams);
+                self.cast_to_unsigned_async(is_greater_or_equal.0, counter_num_blocks, streams)
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_ciphertexts_async(&cts, streams)
+    }
+
+    /// Returns a [CudaBooleanBlock] encrypting `true` if at least `k` bits of `ct` are set to one,
+    /// and `false` otherwise.
+    ///
+    /// This is cheaper than counting the ones and comparing the count to `k`: the number of ones
+    /// of each block is summed with `-k` and only the sign of the result is extracted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // 0b1011_0001 has 4 bits set
+    /// let msg = 0b1011_0001u8;
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// let d_res_4 = sks.has_at_least_k_bits_set(&d_ct, 4, &streams);
+    /// let d_res_5 = sks.has_at_least_k_bits_set(&d_ct, 5, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let res_4 = d_res_4.to_boolean_block(&streams);
+    /// let res_5 = d_res_5.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// assert!(cks.decrypt_bool(&res_4));
+    /// assert!(!cks.decrypt_bool(&res_5));
+    /// ```
+    pub fn has_at_least_k_bits_set<T>(
+        &self,
+        ct: &T,
+        k: u32,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let res = unsafe { self.has_at_least_k_bits_set_async(ct, k, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn has_at_least_k_bits_set_async<T>(
+        &self,
+        ct: &T,
+        k: u32,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp, streams);
+            &tmp
+        };
+
+        let num_bits_in_message = self.message_modulus.0.ilog2();
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        let num_bits_in_ciphertext = num_bits_in_message
+            .checked_mul(num_blocks as u32)
+            .expect("Number of bits encrypted exceeds u32::MAX");
+
+        if k == 0 || k > num_bits_in_ciphertext {
+            let trivial: CudaUnsignedRadixCiphertext =
+                self.create_trivial_radix_async(u64::from(k == 0), 1, streams);
+            return CudaBooleanBlock::from_cuda_radix_ciphertext(trivial.ciphertext);
+        }
+
+        // count - k is in range -num_bits_in_ciphertext..=num_bits_in_ciphertext,
+        // so one more bit than needed to represent num_bits_in_ciphertext is used for the sign
+        let counter_num_blocks =
+            (num_bits_in_ciphertext.ilog2() + 1 + 1).div_ceil(num_bits_in_message) as usize;
+
+        let ones_lut =
+            self.generate_lookup_table(|x| u64::from((x % self.message_modulus.0).count_ones()));
+        let mut ones_per_block = ct.duplicate_async(streams);
+        self.apply_lookup_table_async(
+            ones_per_block.as_mut(),
+            ct.as_ref(),
+            &ones_lut,
+            0..num_blocks,
+            streams,
+        );
+
+        let lwe_size = ct.as_ref().d_blocks.lwe_dimension().to_lwe_size().0;
+        let mut cts = Vec::<CudaSignedRadixCiphertext>::with_capacity(num_blocks + 1);
+        for i in 0..num_blocks {
+            let mut counter: CudaSignedRadixCiphertext =
+                self.create_trivial_zero_radix_async(counter_num_blocks, streams);
+
+            let mut dest_slice = counter
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice(0..lwe_size, 0)
+                .unwrap();
+            let src_slice = ones_per_block
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice((i * lwe_size)..((i + 1) * lwe_size), 0)
+                .unwrap();
+            dest_slice.copy_from_gpu_async(&src_slice, streams, 0);
+            counter.as_mut().info.blocks[0] = ones_per_block.as_ref().info.blocks[i];
+
+            cts.push(counter);
+        }
+
+        cts.push(self.create_trivial_radix_async(-i64::from(k), counter_num_blocks, streams));
+
+        let difference = self.unchecked_sum_ciphertexts_async(&cts, streams);
+
+        // count >= k when the sign bit of count - k is not set
+        let sign_bit_pos = num_bits_in_message - 1;
+        let sign_lut = self.generate_lookup_table(|x| u64::from((x >> sign_bit_pos) & 1 == 0));
+        let mut result: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(1, streams);
+        self.apply_lookup_table_async(
+            result.as_mut(),
+            difference.as_ref(),
+            &sign_lut,
+            (counter_num_blocks - 1)..counter_num_blocks,
+            streams,
+        );
+
+        CudaBooleanBlock::from_cuda_radix_ciphertext(result.ciphertext)
+    }
+
+    /// See [Self::count_ones]
+    ///
+    /// Expects ct to have clean carries
+    pub fn unchecked_count_ones<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let res = unsafe { self.unchecked_count_ones_async(ct, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_count_ones_async<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        if num_blocks == 0 {
+            return self.create_trivial_zero_radix_async(0, streams);
+        }
+
+        let num_bits_in_message = self.message_modulus.0.ilog2();
+        let num_bits_in_ciphertext = num_bits_in_message
+            .checked_mul(num_blocks as u32)
+            .expect("Number of bits encrypted exceeds u32::MAX");
+
+        // `num_bits_in_ciphertext` is the max value we want to represent
+        // its ilog2 + 1 gives use how many bits we need to be able to represent it.
+        let counter_num_blocks =
+            (num_bits_in_ciphertext.ilog2() + 1).div_ceil(num_bits_in_message) as usize;
+
+        let mut result: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(counter_num_blocks, streams);
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                unchecked_count_ones_async(
+                    streams,
+                    &mut result.as_mut().d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    num_blocks as u32,
+                    counter_num_blocks as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                unchecked_count_ones_async(
+                    streams,
+                    &mut result.as_mut().d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    num_blocks as u32,
+                    counter_num_blocks as u32,
+                );
+            }
+        }
+
+        result.as_mut().info = result.as_ref().info.after_ilog2();
+        result
+    }
+
+    /// Returns the number of bits set to one in the binary representation of `ct`
+    ///
+    /// The returned Ciphertexts has a variable size
+    /// i.e. It contains just the minimum number of block
+    /// needed to represent the maximum possible number of bits.
+    ///
+    /// This is a default function, it will internally clone the ciphertext if it has
+    /// non propagated carries, and it will output a ciphertext without any carries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 0b1011_0001u8;
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// // Compute homomorphically the number of bits set
+    /// let d_ct_res = sks.count_ones(&d_ct, &streams);
+    ///
+    /// // Decrypt
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.count_ones());
+    /// ```
+    pub fn count_ones<T>(&self, ct: &T, streams: &CudaStreams) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let res = unsafe { self.count_ones_async(ct, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn count_ones_async<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp, streams);
+            &tmp
+        };
+        self.unchecked_count_ones_async(ct, streams)
+    }
 }
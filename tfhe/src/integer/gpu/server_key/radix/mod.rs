@@ -14,9 +14,10 @@ use crate::integer::gpu::ciphertext::{
 };
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    add_and_propagate_single_carry_assign_async, apply_many_univariate_lut_kb_async,
-    apply_univariate_lut_kb_async, full_propagate_assign_async,
-    propagate_single_carry_assign_async, CudaServerKey, PBSType,
+    add_and_propagate_single_carry_assign_async, apply_bivariate_lut_kb_with_params_async,
+    apply_many_univariate_lut_kb_with_params_async, apply_univariate_lut_kb_with_params_async,
+    full_propagate_assign_with_params_async, propagate_single_carry_assign_async, CudaServerKey,
+    PBSType,
 };
 use crate::integer::server_key::radix_parallel::OutputFlag;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
@@ -35,10 +36,14 @@ mod cmux;
 mod comparison;
 mod div_mod;
 mod even_odd;
+mod fallible;
+mod histogram;
 mod ilog2;
 mod mul;
 mod neg;
 mod oprf;
+mod prepared_lut;
+mod reduce;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -48,11 +53,17 @@ mod scalar_mul;
 mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
+mod scratch;
 mod shift;
+mod sort;
 mod sub;
 mod vector_comparisons;
 mod vector_find;
 
+pub use prepared_lut::PreparedLut;
+pub use reduce::ReduceOp;
+pub use scratch::CudaComparisonScratch;
+
 #[cfg(test)]
 mod tests_long_run;
 #[cfg(test)]
@@ -154,6 +165,16 @@ impl CudaServerKey {
         res
     }
 
+    /// Panics with a message naming `op_name` if `ct` has no blocks, as operations on empty
+    /// radix ciphertexts are not supported by the backend
+    pub(crate) fn assert_has_blocks<T: CudaIntegerRadixCiphertext>(ct: &T, op_name: &str) {
+        assert!(
+            ct.as_ref().d_blocks.lwe_ciphertext_count().0 > 0,
+            "{op_name} requires at least one block, got a ciphertext with 0 blocks \
+            (was it created with num_blocks = 0?)"
+        );
+    }
+
     pub(crate) fn encoding(&self) -> ShortintEncoding {
         ShortintEncoding {
             ciphertext_modulus: self.ciphertext_modulus,
@@ -213,6 +234,187 @@ impl CudaServerKey {
         })
     }
 
+    /// Create a trivial ciphertext of `num_blocks` blocks on the GPU for each scalar of `scalars`
+    ///
+    /// The blocks of all the ciphertexts are uploaded to the GPU with a single transfer, which is
+    /// faster than calling [Self::create_trivial_radix] for each scalar.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let scalars = [212u64, 0, 17];
+    /// let d_ctxts = sks.create_trivial_radix_vec(&scalars, num_blocks, &streams);
+    ///
+    /// // Decrypt:
+    /// for (d_ctxt, scalar) in d_ctxts.iter().zip(scalars) {
+    ///     let dec: u64 = cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams));
+    ///     assert_eq!(dec, scalar);
+    /// }
+    /// ```
+    pub fn create_trivial_radix_vec<Scalar>(
+        &self,
+        scalars: &[Scalar],
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext>
+    where
+        Scalar: DecomposableInto<u64> + Copy,
+    {
+        let res = unsafe { self.create_trivial_radix_vec_async(scalars, num_blocks, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub unsafe fn create_trivial_radix_vec_async<Scalar>(
+        &self,
+        scalars: &[Scalar],
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext>
+    where
+        Scalar: DecomposableInto<u64> + Copy,
+    {
+        if num_blocks == 0 {
+            return scalars
+                .iter()
+                .map(|scalar| self.create_trivial_radix_async(*scalar, 0, streams))
+                .collect();
+        }
+
+        let lwe_size = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.key_switching_key.input_key_lwe_size(),
+            PBSOrder::BootstrapKeyswitch => self.key_switching_key.output_key_lwe_size(),
+        };
+
+        // The blocks of all the ciphertexts are uploaded at once, then split
+        let decomposer = scalars.iter().flat_map(|scalar| {
+            BlockDecomposer::new(*scalar, self.message_modulus.0.ilog2())
+                .iter_as::<u64>()
+                .chain(std::iter::repeat(0))
+                .take(num_blocks)
+        });
+        let mut cpu_lwe_list = LweCiphertextList::new(
+            0,
+            lwe_size,
+            LweCiphertextCount(num_blocks * scalars.len()),
+            self.ciphertext_modulus,
+        );
+        let mut info = Vec::with_capacity(num_blocks * scalars.len());
+        for (block_value, mut lwe) in decomposer.zip(cpu_lwe_list.iter_mut()) {
+            *lwe.get_mut_body().data = self.encoding().encode(Cleartext(block_value)).0;
+            info.push(CudaBlockInfo {
+                degree: Degree::new(block_value),
+                message_modulus: self.message_modulus,
+                carry_modulus: self.carry_modulus,
+                pbs_order: self.pbs_order,
+                noise_level: NoiseLevel::ZERO,
+            });
+        }
+
+        let d_packed_blocks =
+            CudaLweCiphertextList::from_lwe_ciphertext_list_async(&cpu_lwe_list, streams);
+        let lwe_size = lwe_size.0;
+
+        info.chunks(num_blocks)
+            .enumerate()
+            .map(|(i, blocks_info)| {
+                let mut d_vec = CudaVec::new_async(num_blocks * lwe_size, streams, 0);
+                d_vec.copy_src_range_gpu_to_gpu_async(
+                    i * num_blocks * lwe_size..(i + 1) * num_blocks * lwe_size,
+                    &d_packed_blocks.0.d_vec,
+                    streams,
+                    0,
+                );
+                let d_blocks = CudaLweCiphertextList::from_cuda_vec(
+                    d_vec,
+                    LweCiphertextCount(num_blocks),
+                    self.ciphertext_modulus,
+                );
+                CudaIntegerRadixCiphertext::from(CudaRadixCiphertext {
+                    d_blocks,
+                    info: CudaRadixCiphertextInfo {
+                        blocks: blocks_info.to_vec(),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Reorders the ciphertexts so that the ciphertext at index `i` moves to the index whose
+    /// binary representation is the one of `i` reversed.
+    ///
+    /// This is a pure permutation of the list, no computation is done on the GPU.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `ctxts` is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let mut d_ctxts = sks.create_trivial_radix_vec(&[0u64, 1, 2, 3], num_blocks, &streams);
+    /// CudaServerKey::bit_reverse_permute(&mut d_ctxts);
+    ///
+    /// // Decrypt:
+    /// let dec: Vec<u64> = d_ctxts
+    ///     .iter()
+    ///     .map(|d_ctxt| cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams)))
+    ///     .collect();
+    /// assert_eq!(dec, [0, 2, 1, 3]);
+    /// ```
+    pub fn bit_reverse_permute<T>(ctxts: &mut [T])
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let len = ctxts.len();
+        assert!(
+            len.is_power_of_two(),
+            "The number of ciphertexts ({len}) must be a power of two"
+        );
+        let num_bits = len.ilog2();
+        if num_bits == 0 {
+            return;
+        }
+
+        for i in 0..len {
+            let j = i.reverse_bits() >> (usize::BITS - num_bits);
+            // Each pair is swapped once
+            if i < j {
+                ctxts.swap(i, j);
+            }
+        }
+    }
+
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -399,43 +601,23 @@ impl CudaServerKey {
         let num_blocks = ciphertext.d_blocks.lwe_ciphertext_count().0 as u32;
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                full_propagate_assign_async(
+                full_propagate_assign_with_params_async(
                     streams,
                     &mut ciphertext.d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    d_bsk.input_lwe_dimension(),
-                    d_bsk.glwe_dimension(),
-                    d_bsk.polynomial_size(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count(),
-                    d_bsk.decomp_base_log(),
+                    &self.pbs_params(),
                     num_blocks,
-                    ciphertext.info.blocks.first().unwrap().message_modulus,
-                    ciphertext.info.blocks.first().unwrap().carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                full_propagate_assign_async(
+                full_propagate_assign_with_params_async(
                     streams,
                     &mut ciphertext.d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    d_multibit_bsk.input_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension(),
-                    d_multibit_bsk.polynomial_size(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count(),
-                    d_multibit_bsk.decomp_base_log(),
+                    &self.pbs_params(),
                     num_blocks,
-                    ciphertext.info.blocks.first().unwrap().message_modulus,
-                    ciphertext.info.blocks.first().unwrap().carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         };
@@ -449,6 +631,48 @@ impl CudaServerKey {
         });
     }
 
+    /// Sets the degree of each block of `ct` to the largest value a block can hold, without
+    /// modifying the ciphertext data.
+    ///
+    /// This is meant for ciphertexts whose metadata cannot be trusted (e.g. deserialized
+    /// inputs): the carries are then considered as potentially full, so the next operations
+    /// clean them before doing any computation.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg = 14u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// sks.reset_degrees(&mut d_ct);
+    ///
+    /// let d_ct_res = sks.scalar_add(&d_ct, 1u64, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg + 1);
+    /// ```
+    pub fn reset_degrees<T: CudaIntegerRadixCiphertext>(&self, ct: &mut T) {
+        ct.as_mut().info = ct.as_ref().info.after_reset_degrees();
+    }
+
     /// Prepend trivial zero LSB blocks to an existing [`CudaUnsignedRadixCiphertext`] or
     /// [`CudaSignedRadixCiphertext`](`crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext`)
     /// and returns the result as a new ciphertext on GPU. This can be useful for casting
@@ -604,24 +828,10 @@ impl CudaServerKey {
         if num_blocks == 0 {
             return ct.duplicate_async(streams);
         }
-        let new_num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0 + num_blocks;
-        let ciphertext_modulus = ct.as_ref().d_blocks.ciphertext_modulus();
-        let lwe_size = ct.as_ref().d_blocks.lwe_dimension().to_lwe_size();
-
-        let mut extended_ct_vec = CudaVec::new_async(new_num_blocks * lwe_size.0, streams, 0);
-        extended_ct_vec.memset_async(0u64, streams, 0);
-        extended_ct_vec.copy_from_gpu_async(&ct.as_ref().d_blocks.0.d_vec, streams, 0);
-        let extended_ct_list = CudaLweCiphertextList::from_cuda_vec(
-            extended_ct_vec,
-            LweCiphertextCount(new_num_blocks),
-            ciphertext_modulus,
-        );
-
-        let extended_ct_info = ct
-            .as_ref()
-            .info
-            .after_extend_radix_with_trivial_zero_blocks_msb(num_blocks);
-        T::from(CudaRadixCiphertext::new(extended_ct_list, extended_ct_info))
+        T::from(
+            ct.as_ref()
+                .extended_with_trivial_zero_blocks_msb_async(num_blocks, streams),
+        )
     }
 
     /// Remove LSB blocks from an existing [`CudaUnsignedRadixCiphertext`] or
@@ -788,7 +998,8 @@ impl CudaServerKey {
         T::from(CudaRadixCiphertext::new(trimmed_ct_list, trimmed_ct_info))
     }
 
-    pub(crate) fn generate_lookup_table<F>(&self, f: F) -> LookupTableOwned
+    /// Generates the lookup table of the univariate function `f` applied on each block
+    pub fn generate_lookup_table<F>(&self, f: F) -> LookupTableOwned
     where
         F: Fn(u64) -> u64,
     {
@@ -934,51 +1145,27 @@ impl CudaServerKey {
         let num_ct_blocks = block_range.len() as u32;
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         };
@@ -1098,53 +1285,29 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                apply_many_univariate_lut_kb_async(
+                apply_many_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                     function_count as u32,
                     lut.sample_extraction_stride as u32,
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                apply_many_univariate_lut_kb_async(
+                apply_many_univariate_lut_kb_with_params_async(
                     streams,
                     &mut output_slice,
                     &input_slice,
                     lut.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     num_ct_blocks as u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                     function_count as u32,
                     lut.sample_extraction_stride as u32,
                 );
@@ -1176,6 +1339,171 @@ impl CudaServerKey {
         ciphertexts
     }
 
+    /// Applies the bivariate function `f` block-wise on `ct_left` and `ct_right`, the i-th block of
+    /// the result being `f(ct_left[i], ct_right[i])`.
+    ///
+    /// The inputs of `f` are the messages of the blocks, in `0..message_modulus`. Its output
+    /// can use the carry space, i.e. be in `0..message_modulus * carry_modulus`, the degree of
+    /// the result blocks being set accordingly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // Blocks (from least significant): [1, 2, 3, 0] and [3, 0, 2, 1]
+    /// let msg1 = 0b00_11_10_01u64;
+    /// let msg2 = 0b01_10_00_11u64;
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// let d_ct_res = sks.apply_bivariate_lookup_table(&d_ct1, &d_ct2, |a, b| a.min(b), &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0b00_10_00_01);
+    /// ```
+    pub fn apply_bivariate_lookup_table<T, F>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        f: F,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+        F: Fn(u64, u64) -> u64,
+    {
+        let result =
+            unsafe { self.apply_bivariate_lookup_table_async(ct_left, ct_right, f, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn apply_bivariate_lookup_table_async<T, F>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        f: F,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+        F: Fn(u64, u64) -> u64,
+    {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert_eq!(
+            num_blocks,
+            ct_right.as_ref().d_blocks.lwe_ciphertext_count().0,
+            "Both ciphertexts must have the same number of blocks"
+        );
+
+        // The messages are packed as lhs * message_modulus + rhs, so carries must be cleaned first
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+        let (lhs, rhs) = match (
+            ct_left.block_carries_are_empty(),
+            ct_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ct_left, ct_right),
+            (true, false) => {
+                tmp_rhs = ct_right.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (ct_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                (&tmp_lhs, ct_right)
+            }
+            (false, false) => {
+                tmp_lhs = ct_left.duplicate_async(streams);
+                tmp_rhs = ct_right.duplicate_async(streams);
+                self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                self.full_propagate_assign_async(&mut tmp_rhs, streams);
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        let mut result = lhs.duplicate_async(streams);
+        if num_blocks == 0 {
+            return result;
+        }
+
+        let lut = self.generate_lookup_table_bivariate(f);
+
+        let mut output_slice = result
+            .as_mut()
+            .d_blocks
+            .0
+            .d_vec
+            .as_mut_slice(.., 0)
+            .unwrap();
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                apply_bivariate_lut_kb_with_params_async(
+                    streams,
+                    &mut output_slice,
+                    &lhs.as_ref().d_blocks.0.d_vec,
+                    &rhs.as_ref().d_blocks.0.d_vec,
+                    lut.acc.acc.as_ref(),
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    num_blocks as u32,
+                    lut.ct_right_modulus.0 as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                apply_bivariate_lut_kb_with_params_async(
+                    streams,
+                    &mut output_slice,
+                    &lhs.as_ref().d_blocks.0.d_vec,
+                    &rhs.as_ref().d_blocks.0.d_vec,
+                    lut.acc.acc.as_ref(),
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    num_blocks as u32,
+                    lut.ct_right_modulus.0 as u32,
+                );
+            }
+        }
+
+        for info in result.as_mut().info.blocks.iter_mut() {
+            info.degree = lut.acc.degree;
+            info.noise_level = NoiseLevel::NOMINAL;
+        }
+
+        result
+    }
+
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -1224,51 +1552,27 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut padding_block,
                     &last_block,
                     padding_block_creator_lut.acc.as_ref(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     1u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                apply_univariate_lut_kb_async(
+                apply_univariate_lut_kb_with_params_async(
                     streams,
                     &mut padding_block,
                     &last_block,
                     padding_block_creator_lut.acc.as_ref(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     1u32,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -1504,4 +1808,151 @@ impl CudaServerKey {
             }
         }
     }
+
+    /// Cast a [`CudaUnsignedRadixCiphertext`] to `target_num_blocks` blocks, also returning
+    /// whether the value was truncated.
+    ///
+    /// The returned boolean is true if any of the blocks removed when narrowing was not zero,
+    /// i.e. if the result does not hold the same value as `source`. It is always false when
+    /// widening.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let num_blocks = 8;
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg = 300u16;
+    ///
+    /// let ct1 = cks.encrypt(msg);
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    ///
+    /// let (d_ct_res, d_truncated) = sks.checked_cast_to(&d_ct1, 4, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let truncated = d_truncated.to_boolean_block(&streams);
+    ///
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg as u8, res);
+    /// assert!(cks.decrypt_bool(&truncated));
+    /// ```
+    pub fn checked_cast_to(
+        &self,
+        source: &CudaUnsignedRadixCiphertext,
+        target_num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
+        let res = unsafe { self.checked_cast_to_async(source, target_num_blocks, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub unsafe fn checked_cast_to_async(
+        &self,
+        source: &CudaUnsignedRadixCiphertext,
+        target_num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
+        let mut tmp_source;
+        let source = if source.block_carries_are_empty() {
+            source
+        } else {
+            tmp_source = source.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_source, streams);
+            &tmp_source
+        };
+
+        let current_num_blocks = source.as_ref().info.blocks.len();
+        if target_num_blocks >= current_num_blocks {
+            let result = self.extend_radix_with_trivial_zero_blocks_msb_async(
+                source,
+                target_num_blocks - current_num_blocks,
+                streams,
+            );
+            let d_false: CudaUnsignedRadixCiphertext = self.create_trivial_radix(0, 1, streams);
+            return (
+                result,
+                CudaBooleanBlock::from_cuda_radix_ciphertext(d_false.ciphertext),
+            );
+        }
+
+        let num_blocks_to_remove = current_num_blocks - target_num_blocks;
+        let result = self.trim_radix_blocks_msb_async(source, num_blocks_to_remove, streams);
+        let removed_blocks = self.trim_radix_blocks_lsb_async(source, target_num_blocks, streams);
+        let truncated = self.unchecked_scalar_ne_async(&removed_blocks, 0u64, streams);
+
+        (result, truncated)
+    }
+
+    /// Runs a bootstrap on trivial blocks to initialize the GPU(s) behind `streams`
+    ///
+    /// The first operation issued on a GPU pays for the loading of the CUDA modules and the
+    /// initialization of the context. Calling this function beforehand, e.g. before timing
+    /// operations in benchmarks, ensures this cost is not accounted for in the first operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// sks.warmup(&streams);
+    ///
+    /// let msg = 7u64;
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.add(&d_ct, &d_ct, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg + msg);
+    /// ```
+    pub fn warmup(&self, streams: &CudaStreams) {
+        // One block per GPU so that every GPU of `streams` runs a bootstrap
+        let num_blocks = streams.len();
+
+        let ct: CudaUnsignedRadixCiphertext = self.create_trivial_zero_radix(num_blocks, streams);
+        let mut result: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix(num_blocks, streams);
+
+        let identity_lut = self.generate_lookup_table(|x| x);
+
+        unsafe {
+            self.apply_lookup_table_async(
+                result.as_mut(),
+                ct.as_ref(),
+                &identity_lut,
+                0..num_blocks,
+                streams,
+            );
+        }
+        streams.synchronize();
+    }
 }
@@ -1,8 +1,11 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
-use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
-use crate::integer::gpu::{unchecked_mul_integer_radix_kb_assign_async, PBSType};
+use crate::integer::gpu::unchecked_mul_integer_radix_kb_assign_with_params_async;
+use crate::integer::server_key::radix_parallel::OutputFlag;
+use crate::shortint::ciphertext::{Degree, MaxDegree, MaxNoiseLevel, NoiseLevel};
+use rayon::prelude::*;
 
 impl CudaServerKey {
     /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -71,13 +74,15 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        Self::assert_has_blocks(ct_left, "unchecked_mul");
+        Self::assert_has_blocks(ct_right, "unchecked_mul");
         let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0 as u32;
 
         let is_boolean_left = ct_left.holds_boolean_value();
         let is_boolean_right = ct_right.holds_boolean_value();
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_mul_integer_radix_kb_assign_async(
+                unchecked_mul_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct_left.as_mut().d_blocks.0.d_vec,
                     is_boolean_left,
@@ -85,22 +90,12 @@ impl CudaServerKey {
                     is_boolean_right,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension(),
-                    d_bsk.input_lwe_dimension(),
-                    d_bsk.polynomial_size(),
-                    d_bsk.decomp_base_log(),
-                    d_bsk.decomp_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    self.key_switching_key.decomposition_level_count(),
+                    &self.pbs_params(),
                     num_blocks,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_mul_integer_radix_kb_assign_async(
+                unchecked_mul_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct_left.as_mut().d_blocks.0.d_vec,
                     is_boolean_left,
@@ -108,18 +103,8 @@ impl CudaServerKey {
                     is_boolean_right,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension(),
-                    d_multibit_bsk.input_lwe_dimension(),
-                    d_multibit_bsk.polynomial_size(),
-                    d_multibit_bsk.decomp_base_log(),
-                    d_multibit_bsk.decomp_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    self.key_switching_key.decomposition_level_count(),
+                    &self.pbs_params(),
                     num_blocks,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         };
@@ -205,6 +190,8 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        Self::assert_has_blocks(ct_left, "mul");
+        Self::assert_has_blocks(ct_right, "mul");
         let mut tmp_rhs;
 
         let (lhs, rhs) = match (
@@ -245,4 +232,571 @@ impl CudaServerKey {
         }
         streams.synchronize();
     }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting unsigned
+    /// integer values, and returns a boolean indicating whether the result overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg1 = 20u64;
+    /// let msg2 = 13u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let (d_ct_res, d_overflowed) = sks.unsigned_overflowing_mul(&d_ct1, &d_ct2, &streams);
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let overflowed = d_overflowed.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, (msg1 * msg2) % 256);
+    /// assert!(cks.decrypt_bool(&overflowed));
+    /// ```
+    pub fn unsigned_overflowing_mul(
+        &self,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
+        let result = unsafe { self.unsigned_overflowing_mul_async(ct_left, ct_right, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unsigned_overflowing_mul_async(
+        &self,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        // The product is computed on twice as many blocks so that it cannot wrap around,
+        // it overflowed if any of its upper blocks is not zero
+        let mut lhs =
+            self.extend_radix_with_trivial_zero_blocks_msb_async(ct_left, num_blocks, streams);
+        let rhs =
+            self.extend_radix_with_trivial_zero_blocks_msb_async(ct_right, num_blocks, streams);
+
+        self.mul_assign_async(&mut lhs, &rhs, streams);
+
+        let high_part = self.trim_radix_blocks_lsb_async(&lhs, num_blocks, streams);
+        let result = self.trim_radix_blocks_msb_async(&lhs, num_blocks, streams);
+
+        let overflowed = self.any_block_nonzero_async(&high_part, streams);
+
+        (result, overflowed)
+    }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting unsigned
+    /// integer values, the result being clamped to the maximum value of the ciphertext when the
+    /// multiplication overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 8;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg1 = 300u16;
+    /// let msg2 = 400u16;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Copy to GPU
+    /// let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    /// let d_ct_res = sks.saturating_mul(&d_ct1, &d_ct2, &streams);
+    ///
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn saturating_mul(
+        &self,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext {
+        let result = unsafe { self.saturating_mul_async(ct_left, ct_right, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn saturating_mul_async(
+        &self,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext {
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        let (result, overflowed) = self.unsigned_overflowing_mul_async(ct_left, ct_right, streams);
+
+        let mut max_value: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(num_blocks, streams);
+        self.unchecked_bitnot_assign_async(&mut max_value, streams);
+
+        self.unchecked_if_then_else_async(&overflowed, &max_value, &result, streams)
+    }
+
+    /// Computes homomorphically `acc += ct_left * ct_right`.
+    ///
+    /// The product is not computed on its own: the block products of `ct_left` and `ct_right`
+    /// and the blocks of `acc` are all reduced in a single partial sum, followed by a single
+    /// carry propagation. The product is computed on as many blocks as `acc`, so `ct_left` and
+    /// `ct_right` may have fewer blocks than `acc` (e.g. to accumulate 8 bits products in a 16
+    /// bits sum).
+    ///
+    /// If `propagate_carries` is false, the final carry propagation is skipped and `acc` is left
+    /// with carries. A later call to `mac` absorbs them with a single block-wise bootstrap of
+    /// `acc`, so that several products can be accumulated with a single carry propagation, done
+    /// by the last call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ct_left` or `ct_right` has more blocks than `acc`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, &streams);
+    ///
+    /// let (clear_acc, clear_a, clear_b, clear_c) = (1000u64, 200u64, 100u64, 50u64);
+    ///
+    /// // 16 bits accumulator, 8 bits operands
+    /// let mut d_acc =
+    ///     CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(clear_acc, 8), &streams);
+    /// let d_a = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(clear_a, 4), &streams);
+    /// let d_b = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(clear_b, 4), &streams);
+    /// let d_c = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(clear_c, 4), &streams);
+    ///
+    /// // The carries of the first product are propagated with the second one
+    /// sks.mac(&mut d_acc, &d_a, &d_b, false, &streams);
+    /// sks.mac(&mut d_acc, &d_a, &d_c, true, &streams);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt_radix(&d_acc.to_radix_ciphertext(&streams));
+    /// assert_eq!(res, clear_acc + clear_a * clear_b + clear_a * clear_c);
+    /// ```
+    pub fn mac(
+        &self,
+        acc: &mut CudaUnsignedRadixCiphertext,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        propagate_carries: bool,
+        streams: &CudaStreams,
+    ) {
+        unsafe {
+            self.mac_async(acc, ct_left, ct_right, propagate_carries, streams);
+        }
+        streams.synchronize();
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn mac_async(
+        &self,
+        acc: &mut CudaUnsignedRadixCiphertext,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        propagate_carries: bool,
+        streams: &CudaStreams,
+    ) {
+        self.accumulate_products_async(
+            acc,
+            std::iter::once((ct_left, ct_right)),
+            propagate_carries,
+            streams,
+        );
+    }
+
+    /// Computes homomorphically the product of an encrypted matrix by an encrypted vector.
+    ///
+    /// All the ciphertexts must have the same number of blocks. Each coefficient of the result
+    /// is widened so that the dot product of a row with the vector cannot overflow: for
+    /// `n` bits inputs and `k` columns, the outputs hold `2 * n + ceil(log2(k))` bits (rounded
+    /// up to a whole number of blocks).
+    ///
+    /// Each dot product is computed like [Self::mac]: the block products of the whole row are
+    /// reduced in a single partial sum, followed by a single carry propagation. The rows are
+    /// computed concurrently, each of them on its own stream of the first GPU of `streams`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vector` is empty, if a row does not have as many columns as `vector` has
+    /// elements, or if the ciphertexts do not all have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 2;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let encrypt = |clear: u64| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+    ///
+    /// let matrix = [[1u64, 2], [15, 15]].map(|row| row.map(encrypt));
+    /// let vector = [3u64, 15].map(encrypt);
+    ///
+    /// let d_result = sks.matvec(&matrix, &vector, &streams);
+    ///
+    /// // Decrypt
+    /// let result = d_result
+    ///     .iter()
+    ///     .map(|d_ct| cks.decrypt::<u64>(&d_ct.to_radix_ciphertext(&streams)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(result, vec![33, 270]);
+    /// ```
+    pub fn matvec<R>(
+        &self,
+        matrix: &[R],
+        vector: &[CudaUnsignedRadixCiphertext],
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext>
+    where
+        R: AsRef<[CudaUnsignedRadixCiphertext]> + Sync,
+    {
+        let result = unsafe { self.matvec_async(matrix, vector, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn matvec_async<R>(
+        &self,
+        matrix: &[R],
+        vector: &[CudaUnsignedRadixCiphertext],
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext>
+    where
+        R: AsRef<[CudaUnsignedRadixCiphertext]> + Sync,
+    {
+        assert!(!vector.is_empty(), "matvec requires a non-empty vector");
+        let num_columns = vector.len();
+        let num_blocks = vector[0].as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(
+                row.as_ref().len(),
+                num_columns,
+                "Row {i} has {} columns, expected {num_columns} to match the vector",
+                row.as_ref().len()
+            );
+        }
+        assert!(
+            matrix
+                .iter()
+                .flat_map(|row| row.as_ref().iter())
+                .chain(vector.iter())
+                .all(|ct| ct.as_ref().d_blocks.lwe_ciphertext_count().0 == num_blocks),
+            "All the ciphertexts of matvec must have the same number of blocks"
+        );
+
+        let num_bits_in_message = self.message_modulus.0.ilog2();
+        let num_bits_in_product = 2 * num_bits_in_message * num_blocks as u32;
+        // The sum of k products needs ceil(log2(k)) more bits
+        let num_bits_for_sum = if num_columns > 1 {
+            (num_columns as u32 - 1).ilog2() + 1
+        } else {
+            0
+        };
+        let output_num_blocks =
+            (num_bits_in_product + num_bits_for_sum).div_ceil(num_bits_in_message) as usize;
+
+        // The inputs may still be written by work queued on streams
+        streams.synchronize();
+
+        let mut streams_vector = Vec::<CudaStreams>::with_capacity(matrix.len());
+        for _ in 0..matrix.len() {
+            streams_vector.push(CudaStreams::new_single_gpu(streams.gpu_indexes[0]));
+        }
+
+        matrix
+            .par_iter()
+            .zip(streams_vector.par_iter())
+            .map(|(row, row_streams)| {
+                let mut acc: CudaUnsignedRadixCiphertext =
+                    self.create_trivial_zero_radix_async(output_num_blocks, row_streams);
+                self.accumulate_products_async(
+                    &mut acc,
+                    row.as_ref().iter().zip(vector.iter()),
+                    true,
+                    row_streams,
+                );
+                row_streams.synchronize();
+                acc
+            })
+            .collect()
+    }
+
+    // Computes acc += sum(ct_left * ct_right) with a single partial sum of the block products,
+    // the carries being propagated once at the end if propagate_carries is true
+    unsafe fn accumulate_products_async<'a, I>(
+        &self,
+        acc: &mut CudaUnsignedRadixCiphertext,
+        products: I,
+        propagate_carries: bool,
+        streams: &CudaStreams,
+    ) where
+        I: Iterator<
+            Item = (
+                &'a CudaUnsignedRadixCiphertext,
+                &'a CudaUnsignedRadixCiphertext,
+            ),
+        >,
+    {
+        Self::assert_has_blocks(acc, "mac");
+        let num_blocks = acc.as_ref().d_blocks.lwe_ciphertext_count().0;
+
+        // The partial sum expects terms with empty carries and a nominal noise level
+        let mut terms = self.accumulator_terms_async(acc, streams);
+        for (ct_left, ct_right) in products {
+            terms.extend(self.block_products_async(ct_left, ct_right, num_blocks, streams));
+        }
+
+        if terms.len() > 2 {
+            self.unchecked_partial_sum_ciphertexts_assign_async(acc, &terms, streams);
+            // The partial sum reduces the terms down to two before adding them
+            let message_max = self.message_modulus.0 - 1;
+            for block in acc.as_mut().info.blocks.iter_mut() {
+                block.degree = Degree::new(2 * message_max);
+                block.noise_level = NoiseLevel::NOMINAL * 2;
+            }
+        } else {
+            let mut terms = terms.into_iter();
+            *acc = terms.next().unwrap();
+            for term in terms {
+                self.unchecked_add_assign_async(acc, &term, streams);
+            }
+        }
+
+        // The carries can only be left if a later bootstrap of acc is still correct
+        let carries_fit = acc.as_ref().info.blocks.iter().all(|block| {
+            MaxDegree::from_msg_carry_modulus(block.message_modulus, block.carry_modulus)
+                .validate(block.degree)
+                .is_ok()
+                && MaxNoiseLevel::from_msg_carry_modulus(block.message_modulus, block.carry_modulus)
+                    .validate(block.noise_level)
+                    .is_ok()
+        });
+        if propagate_carries || !carries_fit {
+            self.propagate_single_carry_assign_async(acc, streams, None, OutputFlag::None);
+        }
+    }
+
+    // Returns acc as terms of a partial sum: acc itself if it has empty carries and a nominal
+    // noise level, otherwise its message and carry parts, the carries being moved to the next
+    // block
+    unsafe fn accumulator_terms_async(
+        &self,
+        acc: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        let is_clean = acc.block_carries_are_empty()
+            && acc
+                .as_ref()
+                .info
+                .blocks
+                .iter()
+                .all(|block| block.noise_level <= NoiseLevel::NOMINAL);
+        if is_clean {
+            return vec![acc.duplicate_async(streams)];
+        }
+
+        let num_blocks = acc.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let message_modulus = self.message_modulus.0;
+        let message_lut = self.generate_lookup_table(|x| x % message_modulus);
+        let carry_lut = self.generate_lookup_table(|x| x / message_modulus);
+
+        // Carries that do not fit in a term are propagated instead
+        if carry_lut.degree.get() >= message_modulus {
+            let mut acc = acc.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut acc, streams);
+            return vec![acc];
+        }
+
+        let mut message = acc.duplicate_async(streams);
+        self.apply_lookup_table_async(
+            message.as_mut(),
+            acc.as_ref(),
+            &message_lut,
+            0..num_blocks,
+            streams,
+        );
+        if num_blocks == 1 {
+            return vec![message];
+        }
+
+        let mut carries = acc.duplicate_async(streams);
+        self.apply_lookup_table_async(
+            carries.as_mut(),
+            acc.as_ref(),
+            &carry_lut,
+            0..num_blocks,
+            streams,
+        );
+        // The carry of the last block is dropped, as the sum wraps around
+        let mut shifted_carries: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(num_blocks, streams);
+        shifted_carries.as_mut().copy_blocks_from_async(
+            1,
+            carries.as_ref(),
+            0..num_blocks - 1,
+            streams,
+        );
+
+        vec![message, shifted_carries]
+    }
+
+    // Returns the products of the blocks of ct_left and ct_right as terms of num_blocks blocks:
+    // for each block of ct_right, one term holds the least significant parts of its products
+    // with the blocks of ct_left, and another one the most significant parts, shifted by one
+    // block. Blocks beyond num_blocks are dropped.
+    unsafe fn block_products_async(
+        &self,
+        ct_left: &CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        Self::assert_has_blocks(ct_left, "mac");
+        Self::assert_has_blocks(ct_right, "mac");
+        let num_blocks_left = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let num_blocks_right = ct_right.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            num_blocks_left <= num_blocks && num_blocks_right <= num_blocks,
+            "mac operands cannot have more blocks than the accumulator, \
+            got {num_blocks_left} and {num_blocks_right} for an accumulator of {num_blocks}"
+        );
+
+        // Every block of ct_left is paired with every block of ct_right: the j-th group of
+        // num_blocks_left blocks holds ct_left on the left, and the j-th block of ct_right
+        // repeated on the right
+        let num_pairs = num_blocks_left * num_blocks_right;
+        let mut lhs: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(num_pairs, streams);
+        let mut rhs: CudaUnsignedRadixCiphertext =
+            self.create_trivial_zero_radix_async(num_pairs, streams);
+        for j in 0..num_blocks_right {
+            let group_start = j * num_blocks_left;
+            lhs.as_mut().copy_blocks_from_async(
+                group_start,
+                ct_left.as_ref(),
+                0..num_blocks_left,
+                streams,
+            );
+            for i in 0..num_blocks_left {
+                rhs.as_mut().copy_blocks_from_async(
+                    group_start + i,
+                    ct_right.as_ref(),
+                    j..j + 1,
+                    streams,
+                );
+            }
+        }
+
+        let message_modulus = self.message_modulus.0;
+        let lsb_products = self.apply_bivariate_lookup_table_async(
+            &lhs,
+            &rhs,
+            |x, y| (x * y) % message_modulus,
+            streams,
+        );
+        let msb_products = self.apply_bivariate_lookup_table_async(
+            &lhs,
+            &rhs,
+            |x, y| (x * y) / message_modulus,
+            streams,
+        );
+
+        let mut terms = Vec::with_capacity(2 * num_blocks_right);
+        for j in 0..num_blocks_right {
+            let group_start = j * num_blocks_left;
+            for (products, shift) in [(&lsb_products, j), (&msb_products, j + 1)] {
+                if shift >= num_blocks {
+                    continue;
+                }
+                let count = num_blocks_left.min(num_blocks - shift);
+                let mut term: CudaUnsignedRadixCiphertext =
+                    self.create_trivial_zero_radix_async(num_blocks, streams);
+                term.as_mut().copy_blocks_from_async(
+                    shift,
+                    products.as_ref(),
+                    group_start..group_start + count,
+                    streams,
+                );
+                terms.push(term);
+            }
+        }
+
+        terms
+    }
 }
@@ -62,6 +62,7 @@ impl CudaServerKey {
         ctxt: &T,
         streams: &CudaStreams,
     ) -> T {
+        Self::assert_has_blocks(ctxt, "unchecked_neg");
         let mut ciphertext_out = ctxt.duplicate_async(streams);
         let lwe_dimension = ctxt.as_ref().d_blocks.lwe_dimension();
         let lwe_ciphertext_count = ctxt.as_ref().d_blocks.lwe_ciphertext_count();
@@ -136,6 +137,7 @@ impl CudaServerKey {
         ctxt: &T,
         streams: &CudaStreams,
     ) -> T {
+        Self::assert_has_blocks(ctxt, "neg");
         let mut tmp_ctxt;
 
         let ct = if ctxt.block_carries_are_empty() {
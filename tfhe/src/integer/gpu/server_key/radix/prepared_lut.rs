@@ -0,0 +1,206 @@
+use crate::core_crypto::gpu::vec::GpuIndex;
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::server_key::CudaBootstrappingKey;
+use crate::integer::gpu::{
+    apply_univariate_lut_with_buffers_kb_async, cleanup_univariate_lut_kb_async,
+    scratch_univariate_lut_kb_async, CudaServerKey,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::server_key::LookupTableOwned;
+use tfhe_cuda_backend::cuda_bind::cuda_create_stream;
+
+/// A lookup table already uploaded to the GPUs, see [CudaServerKey::prepare_lut].
+///
+/// The device buffers are released when the `PreparedLut` is dropped.
+pub struct PreparedLut {
+    mem_ptr: *mut i8,
+    gpu_indexes: Vec<GpuIndex>,
+    num_blocks: usize,
+    degree: Degree,
+}
+
+impl PreparedLut {
+    /// Returns the maximum number of blocks the lookup table can be applied on at once
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+}
+
+impl Drop for PreparedLut {
+    fn drop(&mut self) {
+        // The streams used to prepare the lut may be gone, the release is done on
+        // new streams on the same GPUs
+        let streams = CudaStreams {
+            ptr: self
+                .gpu_indexes
+                .iter()
+                .map(|gpu_index| unsafe { cuda_create_stream(gpu_index.0) })
+                .collect(),
+            gpu_indexes: self.gpu_indexes.clone(),
+            owned: true,
+        };
+        unsafe {
+            cleanup_univariate_lut_kb_async(&streams, &mut self.mem_ptr);
+        }
+        streams.synchronize();
+    }
+}
+
+impl CudaServerKey {
+    /// Uploads a lookup table to the GPUs of `streams` so that it can be applied several times
+    /// on ciphertexts of at most `num_blocks` blocks without being uploaded again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // Squares each block
+    /// let lut = sks.generate_lookup_table(|x| (x * x) % 4);
+    /// let prepared_lut = sks.prepare_lut(&lut, size, &streams);
+    ///
+    /// for msg in [0b01_10_11_00u64, 0b11_11_01_10u64] {
+    ///     let ct = cks.encrypt(msg);
+    ///
+    ///     // Copy to GPU
+    ///     let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    ///     let d_ct_res = sks.apply_prepared_lut(&d_ct, &prepared_lut, &streams);
+    ///
+    ///     // Copy the result back to CPU
+    ///     let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    ///     // Decrypt:
+    ///     let dec_result: u64 = cks.decrypt(&ct_res);
+    ///     let expected = (0..size).fold(0, |acc, i| {
+    ///         let block = (msg >> (2 * i)) & 3;
+    ///         acc | (((block * block) % 4) << (2 * i))
+    ///     });
+    ///     assert_eq!(dec_result, expected);
+    /// }
+    /// ```
+    pub fn prepare_lut(
+        &self,
+        lut: &LookupTableOwned,
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> PreparedLut {
+        let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+
+        unsafe {
+            scratch_univariate_lut_kb_async(
+                streams,
+                &mut mem_ptr,
+                lut.acc.as_ref(),
+                &self.pbs_params(),
+                num_blocks as u32,
+            );
+        }
+        streams.synchronize();
+
+        PreparedLut {
+            mem_ptr,
+            gpu_indexes: streams.gpu_indexes.clone(),
+            num_blocks,
+            degree: lut.degree,
+        }
+    }
+
+    /// Applies a lookup table prepared with [CudaServerKey::prepare_lut] on every block of `ct`.
+    ///
+    /// `ct` must not have more blocks than the prepared lookup table was prepared for, and
+    /// `streams` must be on the same GPUs as the ones used to prepare it.
+    ///
+    /// See [CudaServerKey::prepare_lut] for an example.
+    pub fn apply_prepared_lut<T>(&self, ct: &T, lut: &PreparedLut, streams: &CudaStreams) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.apply_prepared_lut_async(ct, lut, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn apply_prepared_lut_async<T>(
+        &self,
+        ct: &T,
+        lut: &PreparedLut,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            num_blocks <= lut.num_blocks,
+            "The lookup table was prepared for at most {} blocks, got {num_blocks}",
+            lut.num_blocks
+        );
+        assert_eq!(
+            streams.gpu_indexes, lut.gpu_indexes,
+            "The lookup table was prepared on different GPUs"
+        );
+
+        let mut result = ct.duplicate_async(streams);
+        if num_blocks == 0 {
+            return result;
+        }
+
+        let input_slice = ct.as_ref().d_blocks.0.d_vec.as_slice(.., 0).unwrap();
+        let mut output_slice = result
+            .as_mut()
+            .d_blocks
+            .0
+            .d_vec
+            .as_mut_slice(.., 0)
+            .unwrap();
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                apply_univariate_lut_with_buffers_kb_async(
+                    streams,
+                    &mut output_slice,
+                    &input_slice,
+                    lut.mem_ptr,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    num_blocks as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                apply_univariate_lut_with_buffers_kb_async(
+                    streams,
+                    &mut output_slice,
+                    &input_slice,
+                    lut.mem_ptr,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    num_blocks as u32,
+                );
+            }
+        }
+
+        for info in result.as_mut().info.blocks.iter_mut() {
+            info.degree = lut.degree;
+            info.noise_level = NoiseLevel::NOMINAL;
+        }
+        result
+    }
+}
@@ -0,0 +1,130 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::CudaServerKey;
+
+/// The operations that can be used to combine ciphertexts in [CudaServerKey::reduce]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReduceOp {
+    Add,
+    Mul,
+    Max,
+    Min,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+impl CudaServerKey {
+    /// Combines `init` and all the ciphertexts of `cts` with the operation `op`.
+    ///
+    /// The ciphertexts are combined pairwise in a tree, so the operation is expected to be
+    /// associative and commutative, which is the case of all the [ReduceOp].
+    ///
+    /// If `cts` is empty, a copy of `init` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::integer::gpu::server_key::ReduceOp;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msgs = [14u64, 97, 3, 42];
+    ///
+    /// // Copy to GPU
+    /// let d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    /// let d_init = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u64), &streams);
+    ///
+    /// let d_ct_res = sks.reduce(&d_cts, &d_init, ReduceOp::Max, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 97);
+    /// ```
+    pub fn reduce<T>(&self, cts: &[T], init: &T, op: ReduceOp, streams: &CudaStreams) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.reduce_async(cts, init, op, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn reduce_async<T>(
+        &self,
+        cts: &[T],
+        init: &T,
+        op: ReduceOp,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let num_blocks = init.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert!(
+            cts.iter()
+                .all(|ct| ct.as_ref().d_blocks.lwe_ciphertext_count().0 == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        let mut values: Vec<T> = std::iter::once(init)
+            .chain(cts.iter())
+            .map(|ct| ct.duplicate_async(streams))
+            .collect();
+
+        while values.len() > 1 {
+            let mut next_values = Vec::with_capacity(values.len().div_ceil(2));
+            let mut iter = values.into_iter();
+            while let Some(mut lhs) = iter.next() {
+                if let Some(rhs) = iter.next() {
+                    self.reduce_op_assign_async(&mut lhs, &rhs, op, streams);
+                }
+                next_values.push(lhs);
+            }
+            values = next_values;
+        }
+
+        values.pop().unwrap()
+    }
+
+    unsafe fn reduce_op_assign_async<T>(
+        &self,
+        lhs: &mut T,
+        rhs: &T,
+        op: ReduceOp,
+        streams: &CudaStreams,
+    ) where
+        T: CudaIntegerRadixCiphertext,
+    {
+        match op {
+            ReduceOp::Add => self.add_assign_async(lhs, rhs, streams),
+            ReduceOp::Mul => self.mul_assign_async(lhs, rhs, streams),
+            ReduceOp::Max => *lhs = self.max_async(lhs, rhs, streams),
+            ReduceOp::Min => *lhs = self.min_async(lhs, rhs, streams),
+            ReduceOp::BitAnd => self.bitand_assign_async(lhs, rhs, streams),
+            ReduceOp::BitOr => self.bitor_assign_async(lhs, rhs, streams),
+            ReduceOp::BitXor => self.bitxor_assign_async(lhs, rhs, streams),
+        }
+    }
+}
@@ -1,10 +1,9 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    unchecked_rotate_left_integer_radix_kb_assign_async,
-    unchecked_rotate_right_integer_radix_kb_assign_async, CudaServerKey, PBSType,
+    unchecked_rotate_left_integer_radix_kb_assign_with_params_async,
+    unchecked_rotate_right_integer_radix_kb_assign_with_params_async, CudaServerKey,
 };
 
 impl CudaServerKey {
@@ -25,57 +24,27 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_rotate_right_integer_radix_kb_assign_async(
+                unchecked_rotate_right_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     &rotate.as_ref().d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     is_signed,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_rotate_right_integer_radix_kb_assign_async(
+                unchecked_rotate_right_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     &rotate.as_ref().d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     is_signed,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -145,57 +114,27 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_rotate_left_integer_radix_kb_assign_async(
+                unchecked_rotate_left_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     &rotate.as_ref().d_blocks.0.d_vec,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     is_signed,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_rotate_left_integer_radix_kb_assign_async(
+                unchecked_rotate_left_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     &rotate.as_ref().d_blocks.0.d_vec,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     is_signed,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -554,4 +493,80 @@ impl CudaServerKey {
         unsafe { self.rotate_left_async(ct, rotate, streams) };
         streams.synchronize();
     }
+
+    /// Computes homomorphically a left rotate by an encrypted amount, the amount being first
+    /// reduced modulo the bit width of `ct`
+    ///
+    /// The reduction is done with [CudaServerKey::scalar_rem] which, compared to
+    /// [CudaServerKey::rotate_left], adds the cost of a scalar division to the rotation.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 128u8;
+    /// let rotate = 10u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let rotate_ct = cks.encrypt(rotate);
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    /// let d_rotate_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&rotate_ct, &streams);
+    ///
+    /// let d_ct_res = sks.rotate_left_secret(&d_ct, &d_rotate_ct, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.rotate_left(rotate as u32) as u64);
+    /// ```
+    pub fn rotate_left_secret<T>(
+        &self,
+        ct: &T,
+        rotate: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.rotate_left_secret_async(ct, rotate, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn rotate_left_secret_async<T>(
+        &self,
+        ct: &T,
+        rotate: &CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0 as u64;
+        let num_bits = u64::from(self.message_modulus.0.ilog2()) * num_blocks;
+
+        let reduced_rotate = self.scalar_rem_async(rotate, num_bits, streams);
+        self.rotate_left_async(ct, &reduced_rotate, streams)
+    }
 }
@@ -9,8 +9,9 @@ use crate::integer::gpu::ciphertext::{
 use crate::integer::gpu::scalar_addition_integer_radix_assign_async;
 use crate::integer::gpu::server_key::CudaServerKey;
 use crate::integer::server_key::radix_parallel::OutputFlag;
+use crate::integer::server_key::CheckError;
 use crate::prelude::CastInto;
-use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::ciphertext::{Degree, MaxDegree, NoiseLevel};
 
 impl CudaServerKey {
     /// Computes homomorphically an addition between a scalar and a ciphertext.
@@ -78,6 +79,7 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8> + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
+        Self::assert_has_blocks(ct, "unchecked_scalar_add");
         if scalar != Scalar::ZERO {
             let bits_in_message = self.message_modulus.0.ilog2();
             let mut d_decomposed_scalar = CudaVec::<u64>::new_async(
@@ -106,6 +108,15 @@ impl CudaServerKey {
             );
 
             ct.as_mut().info = ct.as_ref().info.after_scalar_add(scalar);
+
+            debug_assert!(
+                ct.as_ref().info.blocks.iter().all(|block| {
+                    MaxDegree::from_msg_carry_modulus(block.message_modulus, block.carry_modulus)
+                        .validate(block.degree)
+                        .is_ok()
+                }),
+                "The scalar addition exceeded the carry capacity of the ciphertext"
+            );
         }
     }
 
@@ -124,6 +135,113 @@ impl CudaServerKey {
         streams.synchronize();
     }
 
+    /// Verifies if a scalar can be added to a ciphertext without exceeding its carry capacity,
+    /// taking into account the carries that would go from one block to the next.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 4u64;
+    /// let scalar = 40u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Check if we can perform an addition
+    /// sks.is_scalar_add_possible(&d_ct, scalar).unwrap();
+    /// ```
+    pub fn is_scalar_add_possible<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+    ) -> Result<(), CheckError>
+    where
+        Scalar: DecomposableInto<u8>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let bits_in_message = self.message_modulus.0.ilog2();
+        let decomposer =
+            BlockDecomposer::with_early_stop_at_zero(scalar, bits_in_message).iter_as::<u8>();
+
+        // Assumes message_modulus and carry_modulus matches between pairs of block
+        let mut preceding_block_carry = Degree::new(0);
+        for (block, scalar_block_value) in ct.as_ref().info.blocks.iter().zip(decomposer) {
+            let degree_after_add = block.degree + Degree::new(u64::from(scalar_block_value));
+
+            let max_degree =
+                MaxDegree::from_msg_carry_modulus(block.message_modulus, block.carry_modulus);
+
+            max_degree.validate(degree_after_add + preceding_block_carry)?;
+
+            preceding_block_carry = Degree::new(degree_after_add.get() / block.message_modulus.0);
+        }
+        Ok(())
+    }
+
+    /// Computes homomorphically an addition between a scalar and a ciphertext.
+    ///
+    /// If the operation can be performed, the result is assigned to the ciphertext given as
+    /// parameter, and the block degrees are updated. Otherwise a [CheckError] is returned and
+    /// the ciphertext is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 4u64;
+    /// let scalar = 40u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Compute homomorphically an addition:
+    /// sks.checked_scalar_add_assign(&mut d_ct, scalar, &streams).unwrap();
+    /// let ct_res = d_ct.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg + scalar, dec);
+    /// ```
+    pub fn checked_scalar_add_assign<Scalar, T>(
+        &self,
+        ct: &mut T,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> Result<(), CheckError>
+    where
+        Scalar: DecomposableInto<u8> + CastInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        self.is_scalar_add_possible(ct, scalar)?;
+        self.unchecked_scalar_add_assign(ct, scalar, streams);
+        Ok(())
+    }
+
     /// Computes homomorphically an addition between a scalar and a ciphertext.
     ///
     /// This function computes the operation without checking if it exceeds the capacity of the
@@ -184,6 +302,7 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8> + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
+        Self::assert_has_blocks(ct, "scalar_add");
         if !ct.block_carries_are_empty() {
             self.full_propagate_assign_async(ct, streams);
         };
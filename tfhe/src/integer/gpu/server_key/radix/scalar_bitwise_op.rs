@@ -1,12 +1,12 @@
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
 use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    unchecked_scalar_bitop_integer_radix_kb_assign_async, BitOpType, CudaServerKey, PBSType,
+    unchecked_scalar_bitop_integer_radix_kb_slice_assign_async, BitOpType, CudaServerKey,
 };
+use crate::shortint::ciphertext::Degree;
 
 impl CudaServerKey {
     /// # Safety
@@ -23,7 +23,6 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8>,
         T: CudaIntegerRadixCiphertext,
     {
-        let lwe_ciphertext_count = ct.as_ref().d_blocks.lwe_ciphertext_count();
         let message_modulus = self.message_modulus.0;
 
         let h_clear_blocks = BlockDecomposer::with_early_stop_at_zero(rhs, message_modulus.ilog2())
@@ -31,62 +30,76 @@ impl CudaServerKey {
             .map(|x| x as u64)
             .collect::<Vec<_>>();
 
-        let clear_blocks = CudaVec::from_cpu_async(&h_clear_blocks, streams, 0);
+        self.unchecked_scalar_bitop_blocks_assign_async(ct, h_clear_blocks, op, streams);
+    }
 
-        match &self.bootstrapping_key {
-            CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_bitop_integer_radix_kb_assign_async(
-                    streams,
-                    &mut ct.as_mut().d_blocks.0.d_vec,
-                    &clear_blocks,
-                    &d_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
-                    op,
-                    lwe_ciphertext_count.0 as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
-                );
-            }
-            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_bitop_integer_radix_kb_assign_async(
-                    streams,
-                    &mut ct.as_mut().d_blocks.0.d_vec,
-                    &clear_blocks,
-                    &d_multibit_bsk.d_vec,
-                    &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
-                    op,
-                    lwe_ciphertext_count.0 as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
-                );
+    /// Same as [Self::unchecked_scalar_bitop_assign_async] but the scalar is given already
+    /// decomposed into blocks, least significant first. Missing blocks are considered zero.
+    ///
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub(crate) unsafe fn unchecked_scalar_bitop_blocks_assign_async<T>(
+        &self,
+        ct: &mut T,
+        mut h_clear_blocks: Vec<u64>,
+        op: BitOpType,
+        streams: &CudaStreams,
+    ) where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let lwe_ciphertext_count = ct.as_ref().d_blocks.lwe_ciphertext_count();
+        let lwe_size = ct.as_ref().d_blocks.lwe_dimension().to_lwe_size().0;
+
+        let block_ranges = self.concurrent_block_ranges(lwe_ciphertext_count.0);
+        if block_ranges.len() > 1 {
+            // Each launch gets the clear blocks of its range, so the blocks that were stripped by
+            // the early stop at zero are added back
+            h_clear_blocks.resize(h_clear_blocks.len().max(lwe_ciphertext_count.0), 0);
+        }
+
+        for block_range in block_ranges {
+            let h_range_clear_blocks = if block_range.len() == lwe_ciphertext_count.0 {
+                h_clear_blocks.as_slice()
+            } else {
+                &h_clear_blocks[block_range.clone()]
+            };
+            let clear_blocks = CudaVec::from_cpu_async(h_range_clear_blocks, streams, 0);
+
+            let mut ct_slice = ct
+                .as_mut()
+                .d_blocks
+                .0
+                .d_vec
+                .as_mut_slice(lwe_size * block_range.start..lwe_size * block_range.end, 0)
+                .unwrap();
+
+            match &self.bootstrapping_key {
+                CudaBootstrappingKey::Classic(d_bsk) => {
+                    unchecked_scalar_bitop_integer_radix_kb_slice_assign_async(
+                        streams,
+                        &mut ct_slice,
+                        &clear_blocks,
+                        &d_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        op,
+                        block_range.len() as u32,
+                    );
+                }
+                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                    unchecked_scalar_bitop_integer_radix_kb_slice_assign_async(
+                        streams,
+                        &mut ct_slice,
+                        &clear_blocks,
+                        &d_multibit_bsk.d_vec,
+                        &self.key_switching_key.d_vec,
+                        &self.pbs_params(),
+                        op,
+                        block_range.len() as u32,
+                    );
+                }
             }
         }
     }
@@ -301,4 +314,90 @@ impl CudaServerKey {
         self.scalar_bitxor_assign(&mut result, rhs, streams);
         result
     }
+
+    /// Reduces the value encrypted in `ct` modulo `2^bits`, i.e. the bits above the `bits`
+    /// lowest ones are set to zero. The result has the same number of blocks as `ct`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 8 * 2 = 16 bits of message
+    /// let size = 8;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 0xABCDu16;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.reduce_to_bits(&d_ct, 5, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// let clear: u16 = cks.decrypt(&ct_res);
+    /// assert_eq!(clear, msg % (1 << 5));
+    /// ```
+    pub fn reduce_to_bits<T>(&self, ct: &T, bits: u32, streams: &CudaStreams) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut result = unsafe { ct.duplicate_async(streams) };
+        unsafe {
+            self.reduce_to_bits_assign_async(&mut result, bits, streams);
+        }
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn reduce_to_bits_assign_async<T>(
+        &self,
+        ct: &mut T,
+        bits: u32,
+        streams: &CudaStreams,
+    ) where
+        T: CudaIntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_assign_async(ct, streams);
+        }
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let bits_in_message = self.message_modulus.0.ilog2();
+        if bits as usize >= num_blocks * bits_in_message as usize {
+            return;
+        }
+
+        // The mask has all the bits of the blocks below `bits` set, the block containing the
+        // `bits`-th bit is partially set and the ones above are left out, which zeroes them
+        let num_full_blocks = (bits / bits_in_message) as usize;
+        let remaining_bits = bits % bits_in_message;
+        let mut h_mask_blocks = vec![self.message_modulus.0 - 1; num_full_blocks];
+        if remaining_bits != 0 {
+            h_mask_blocks.push((1 << remaining_bits) - 1);
+        }
+
+        self.unchecked_scalar_bitop_blocks_assign_async(
+            ct,
+            h_mask_blocks.clone(),
+            BitOpType::ScalarAnd,
+            streams,
+        );
+
+        let mask_blocks = h_mask_blocks.into_iter().chain(std::iter::repeat(0));
+        for (info, mask_block) in ct.as_mut().info.blocks.iter_mut().zip(mask_blocks) {
+            info.degree = info.degree.after_bitand(Degree::new(mask_block));
+        }
+    }
 }
@@ -1,16 +1,18 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::vec::CudaVec;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{CiphertextModulus, LweBskGroupingFactor, LweCiphertextCount};
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextCount};
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::info::CudaRadixCiphertextInfo;
-use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaRadixCiphertext};
+use crate::integer::gpu::ciphertext::{
+    CudaIntegerRadixCiphertext, CudaRadixCiphertext, CudaUnsignedRadixCiphertext,
+};
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
-    unchecked_are_all_comparisons_block_true_integer_radix_kb_async,
-    unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_async,
-    unchecked_scalar_comparison_integer_radix_kb_async, ComparisonType, PBSType,
+    unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async,
+    unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async,
+    unchecked_scalar_comparison_integer_radix_kb_with_params_async, BitOpType, ComparisonType,
 };
 use crate::shortint::ciphertext::Degree;
 
@@ -175,63 +177,33 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_comparison_integer_radix_kb_async(
+                unchecked_scalar_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
                     &ct.as_ref().d_blocks.0.d_vec,
                     &d_scalar_blocks,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     scalar_blocks.len() as u32,
                     op,
                     signed_with_positive_scalar,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_comparison_integer_radix_kb_async(
+                unchecked_scalar_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
                     &ct.as_ref().d_blocks.0.d_vec,
                     &d_scalar_blocks,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     scalar_blocks.len() as u32,
                     op,
                     signed_with_positive_scalar,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -337,63 +309,33 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_comparison_integer_radix_kb_async(
+                unchecked_scalar_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &ct.as_ref().d_blocks.0.d_vec,
                     &d_scalar_blocks,
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     scalar_blocks.len() as u32,
                     op,
                     T::IS_SIGNED,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_comparison_integer_radix_kb_async(
+                unchecked_scalar_comparison_integer_radix_kb_with_params_async(
                     streams,
                     &mut result.as_mut().d_blocks.0.d_vec,
                     &ct.as_ref().d_blocks.0.d_vec,
                     &d_scalar_blocks,
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
                     scalar_blocks.len() as u32,
                     op,
                     T::IS_SIGNED,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -406,67 +348,52 @@ impl CudaServerKey {
         ct: &T,
         streams: &CudaStreams,
     ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.unchecked_are_all_comparisons_block_true_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_are_all_comparisons_block_true_async<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
     where
         T: CudaIntegerRadixCiphertext,
     {
         let lwe_ciphertext_count = ct.as_ref().d_blocks.lwe_ciphertext_count();
 
-        let ct_res: T = self.create_trivial_radix(0, 1, streams);
+        let ct_res: T = self.create_trivial_radix_async(0, 1, streams);
         let mut boolean_res = CudaBooleanBlock::from_cuda_radix_ciphertext(ct_res.into_inner());
-        unsafe {
-            match &self.bootstrapping_key {
-                CudaBootstrappingKey::Classic(d_bsk) => {
-                    unchecked_are_all_comparisons_block_true_integer_radix_kb_async(
-                        streams,
-                        &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
-                        &ct.as_ref().d_blocks.0.d_vec,
-                        &d_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
-                    );
-                }
-                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    unchecked_are_all_comparisons_block_true_integer_radix_kb_async(
-                        streams,
-                        &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
-                        &ct.as_ref().d_blocks.0.d_vec,
-                        &d_multibit_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
-                    );
-                }
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async(
+                    streams,
+                    &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                unchecked_are_all_comparisons_block_true_integer_radix_kb_with_params_async(
+                    streams,
+                    &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
             }
         }
         boolean_res.as_mut().ciphertext.info = boolean_res
@@ -474,7 +401,6 @@ impl CudaServerKey {
             .ciphertext
             .info
             .after_block_comparisons();
-        streams.synchronize();
         boolean_res
     }
 
@@ -483,67 +409,53 @@ impl CudaServerKey {
         ct: &T,
         streams: &CudaStreams,
     ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result =
+            unsafe { self.unchecked_is_at_least_one_comparisons_block_true_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_is_at_least_one_comparisons_block_true_async<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
     where
         T: CudaIntegerRadixCiphertext,
     {
         let lwe_ciphertext_count = ct.as_ref().d_blocks.lwe_ciphertext_count();
 
-        let ct_res: T = self.create_trivial_radix(0, 1, streams);
+        let ct_res: T = self.create_trivial_radix_async(0, 1, streams);
         let mut boolean_res = CudaBooleanBlock::from_cuda_radix_ciphertext(ct_res.into_inner());
-        unsafe {
-            match &self.bootstrapping_key {
-                CudaBootstrappingKey::Classic(d_bsk) => {
-                    unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_async(
-                        streams,
-                        &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
-                        &ct.as_ref().d_blocks.0.d_vec,
-                        &d_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
-                    );
-                }
-                CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_async(
-                        streams,
-                        &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
-                        &ct.as_ref().d_blocks.0.d_vec,
-                        &d_multibit_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
-                    );
-                }
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async(
+                    streams,
+                    &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                unchecked_is_at_least_one_comparisons_block_true_integer_radix_kb_with_params_async(
+                    streams,
+                    &mut boolean_res.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct.as_ref().d_blocks.0.d_vec,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    &self.pbs_params(),
+                    lwe_ciphertext_count.0 as u32,
+                );
             }
         }
         boolean_res.as_mut().ciphertext.info = boolean_res
@@ -551,10 +463,275 @@ impl CudaServerKey {
             .ciphertext
             .info
             .after_block_comparisons();
-        streams.synchronize();
         boolean_res
     }
 
+    /// Returns a [`CudaBooleanBlock`] encrypting `true` if at least one block of `ct` is not
+    /// zero, that is, if `ct` encrypts a non-zero value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // Only the most significant block is not zero
+    /// let msg = 64u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.any_block_nonzero(&d_ct, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn any_block_nonzero<T>(&self, ct: &T, streams: &CudaStreams) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.any_block_nonzero_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn any_block_nonzero_async<T>(
+        &self,
+        ct: &T,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_ct, streams);
+            &tmp_ct
+        };
+
+        // Blocks are summed by chunks, each sum being non-zero iff one of its blocks is
+        self.unchecked_is_at_least_one_comparisons_block_true_async(ct, streams)
+    }
+
+    /// Returns a [`CudaBooleanBlock`] encrypting `true` if every block of `ct` holds its maximum
+    /// value (`message_modulus - 1`), that is, if all the bits of `ct` are set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 8;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = u16::MAX;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.all_blocks_max(&d_ct, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn all_blocks_max<T>(&self, ct: &T, streams: &CudaStreams) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.all_blocks_max_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn all_blocks_max_async<T>(&self, ct: &T, streams: &CudaStreams) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_ct, streams);
+            &tmp_ct
+        };
+
+        // The block comparison expects blocks encrypting 0 or 1,
+        // so each block is first mapped to whether it is at its maximum
+        let max_value = self.message_modulus.0 - 1;
+        let is_max_lut =
+            self.generate_lookup_table(|x| u64::from(x % self.message_modulus.0 == max_value));
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let mut is_max_blocks = ct.duplicate_async(streams);
+        self.apply_lookup_table_async(
+            is_max_blocks.as_mut(),
+            ct.as_ref(),
+            &is_max_lut,
+            0..num_blocks,
+            streams,
+        );
+
+        self.unchecked_are_all_comparisons_block_true_async(&is_max_blocks, streams)
+    }
+
+    /// Returns a [`CudaBooleanBlock`] encrypting `true` if `ct` encrypts zero.
+    ///
+    /// This is cheaper than `scalar_eq(ct, 0)`: blocks are summed together as long as the sum of
+    /// their degrees fits in a block, and a single bootstrap per sum tells whether it is zero.
+    /// Ciphertexts small enough to fit in one sum thus only need one bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let ct = cks.encrypt(0u64);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_ct_res = sks.is_all_zero(&d_ct, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cks.decrypt_bool(&ct_res);
+    /// assert!(dec_result);
+    /// ```
+    pub fn is_all_zero<T>(&self, ct: &T, streams: &CudaStreams) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.is_all_zero_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn is_all_zero_async<T>(&self, ct: &T, streams: &CudaStreams) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_ct, streams);
+            &tmp_ct
+        };
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let max_block_degree = ct
+            .as_ref()
+            .info
+            .blocks
+            .iter()
+            .map(|block| block.degree.get())
+            .max()
+            .unwrap_or(0);
+
+        // All blocks are known to be zero (e.g. trivial zeros)
+        if max_block_degree == 0 {
+            let d_ct: CudaUnsignedRadixCiphertext = self.create_trivial_radix_async(1, 1, streams);
+            return CudaBooleanBlock::from_cuda_radix_ciphertext(d_ct.ciphertext);
+        }
+
+        // Block i is added to block i % num_sums, so each sum holds at most blocks_per_sum
+        // blocks and cannot exceed the max degree: a sum is zero iff all its blocks are
+        let blocks_per_sum = (self.max_degree.get() / max_block_degree) as usize;
+        let num_sums = num_blocks.div_ceil(blocks_per_sum);
+
+        let mut sums = self.trim_radix_blocks_msb_async(ct, num_blocks - num_sums, streams);
+        for start in (num_sums..num_blocks).step_by(num_sums) {
+            let end = (start + num_sums).min(num_blocks);
+            let chunk = self.trim_radix_blocks_lsb_async(ct, start, streams);
+            let chunk = self.trim_radix_blocks_msb_async(&chunk, num_blocks - end, streams);
+            let chunk = self.extend_radix_with_trivial_zero_blocks_msb_async(
+                &chunk,
+                num_sums - (end - start),
+                streams,
+            );
+            self.unchecked_add_assign_async(&mut sums, &chunk, streams);
+        }
+
+        let is_zero_lut = self.generate_lookup_table(|x| u64::from(x == 0));
+        let mut are_sums_zero = sums.duplicate_async(streams);
+        self.apply_lookup_table_async(
+            are_sums_zero.as_mut(),
+            sums.as_ref(),
+            &is_zero_lut,
+            0..num_sums,
+            streams,
+        );
+
+        if num_sums == 1 {
+            CudaBooleanBlock::from_cuda_radix_ciphertext(are_sums_zero.into_inner())
+        } else {
+            self.unchecked_are_all_comparisons_block_true_async(&are_sums_zero, streams)
+        }
+    }
+
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -992,6 +1169,75 @@ impl CudaServerKey {
         result
     }
 
+    /// Compares for greater or equal a ciphertext and a clear value
+    ///
+    /// Unlike [Self::scalar_ge], the result is returned as a one-block radix ciphertext encrypting
+    /// 0 or 1, which can directly be used in arithmetic operations (e.g. multiplied with another
+    /// ciphertext once extended to the right number of blocks).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 97u64;
+    ///
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// let d_ct_res = sks.scalar_ge_radix(&d_ct, 42u64, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 1);
+    /// ```
+    pub fn scalar_ge_radix<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.scalar_ge_radix_async(ct, scalar, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn scalar_ge_radix_async<Scalar, T>(
+        &self,
+        ct: &T,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        // The boolean block already is a one-block radix, no conversion is needed
+        self.scalar_ge_async(ct, scalar, streams).0
+    }
+
     /// # Safety
     ///
     /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
@@ -1208,4 +1454,222 @@ impl CudaServerKey {
         streams.synchronize();
         result
     }
+
+    /// Compares each ciphertext of `cts` to the clear `scalar` and packs the results in a single
+    /// radix ciphertext, the i-th bit being set if the comparison holds for the i-th ciphertext.
+    ///
+    /// The result has as many blocks as needed to hold one bit per ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// - if `cts` is empty
+    /// - if `op` is [ComparisonType::MAX] or [ComparisonType::MIN]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::{gen_keys_radix_gpu, ComparisonType};
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msgs = [14u64, 97, 3, 42, 7];
+    ///
+    /// // Copy to GPU
+    /// let d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let d_mask = sks.scalar_cmp_mask(&d_cts, ComparisonType::GT, 10u64, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let mask = d_mask.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec: u64 = cks.decrypt(&mask);
+    /// assert_eq!(dec, 0b01011);
+    /// ```
+    pub fn scalar_cmp_mask<Scalar, T>(
+        &self,
+        cts: &[T],
+        op: ComparisonType,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.scalar_cmp_mask_async(cts, op, scalar, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn scalar_cmp_mask_async<Scalar, T>(
+        &self,
+        cts: &[T],
+        op: ComparisonType,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert!(
+            !cts.is_empty(),
+            "scalar_cmp_mask requires at least one ciphertext"
+        );
+        assert!(
+            !matches!(op, ComparisonType::MAX | ComparisonType::MIN),
+            "scalar_cmp_mask only supports comparison operators, not MAX or MIN"
+        );
+
+        let comparisons = cts
+            .iter()
+            .map(|ct| {
+                let mut tmp_lhs;
+                let lhs = if ct.block_carries_are_empty() {
+                    ct
+                } else {
+                    tmp_lhs = ct.duplicate_async(streams);
+                    self.full_propagate_assign_async(&mut tmp_lhs, streams);
+                    &tmp_lhs
+                };
+
+                self.unchecked_scalar_comparison_async(lhs, scalar, op, streams)
+            })
+            .collect::<Vec<_>>();
+
+        // Each block of the result holds as many booleans as there are bits in the message,
+        // the boolean of index j in a block being moved to the j-th bit
+        let num_bits_in_message = self.message_modulus.0.ilog2() as usize;
+        // The j-th LUT moves a boolean to the bit j + 1, the bit 0 needs no LUT
+        let shift_luts = (1..num_bits_in_message)
+            .map(|j| self.generate_lookup_table(|x| (x & 1) << j))
+            .collect::<Vec<_>>();
+        let packed_blocks = comparisons
+            .chunks(num_bits_in_message)
+            .map(|booleans| {
+                let mut packed = booleans[0].0.duplicate_async(streams);
+                for (boolean, shift_lut) in booleans[1..].iter().zip(shift_luts.iter()) {
+                    let mut shifted: CudaUnsignedRadixCiphertext =
+                        self.create_trivial_zero_radix_async(1, streams);
+                    self.apply_lookup_table_async(
+                        shifted.as_mut(),
+                        boolean.0.as_ref(),
+                        shift_lut,
+                        0..1,
+                        streams,
+                    );
+                    self.unchecked_add_assign_async(&mut packed, &shifted, streams);
+                }
+                packed
+            })
+            .collect::<Vec<_>>();
+
+        self.convert_integer_radixes_vec_to_single_integer_radix_ciphertext(&packed_blocks, streams)
+    }
+
+    /// Returns a [`CudaBooleanBlock`] encrypting `true` if `low <= ct <= high`.
+    ///
+    /// If `low > high` the range is empty and the result is always `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 42u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// let d_in_range = sks.is_in_range(&d_ct, 10u64, 50u64, &streams);
+    /// let d_not_in_range = sks.is_in_range(&d_ct, 43u64, 50u64, &streams);
+    ///
+    /// // Copy the result back to CPU and decrypt
+    /// assert!(cks.decrypt_bool(&d_in_range.to_boolean_block(&streams)));
+    /// assert!(!cks.decrypt_bool(&d_not_in_range.to_boolean_block(&streams)));
+    /// ```
+    pub fn is_in_range<Scalar, T>(
+        &self,
+        ct: &T,
+        low: Scalar,
+        high: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.is_in_range_async(ct, low, high, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn is_in_range_async<Scalar, T>(
+        &self,
+        ct: &T,
+        low: Scalar,
+        high: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        Scalar: DecomposableInto<u64>,
+        T: CudaIntegerRadixCiphertext,
+    {
+        if low > high {
+            let trivial: CudaUnsignedRadixCiphertext =
+                self.create_trivial_zero_radix_async(1, streams);
+            return CudaBooleanBlock::from_cuda_radix_ciphertext(trivial.ciphertext);
+        }
+
+        let mut tmp_lhs;
+        let lhs = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_lhs = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp_lhs, streams);
+            &tmp_lhs
+        };
+
+        let mut result = self.unchecked_scalar_ge_async(lhs, low, streams);
+        let is_le_high = self.unchecked_scalar_le_async(lhs, high, streams);
+        self.unchecked_bitop_assign_async(&mut result.0, &is_le_high.0, BitOpType::And, streams);
+
+        result
+    }
 }
@@ -147,12 +147,17 @@ impl CudaServerKey {
         );
 
         if MiniUnsignedInteger::is_power_of_two(divisor) {
+            let shift = MiniUnsignedInteger::ilog2(divisor);
+            // Scalar shifts are done modulo the number of bits, so a divisor that is
+            // >= 2^numerator_bits must not be turned into a shift
+            if shift >= numerator_bits {
+                return self.create_trivial_zero_radix_async(
+                    numerator.as_ref().d_blocks.lwe_ciphertext_count().0,
+                    streams,
+                );
+            }
             // Even in FHE, shifting is faster than multiplying / dividing
-            return self.unchecked_scalar_right_shift_async(
-                numerator,
-                MiniUnsignedInteger::ilog2(divisor) as u64,
-                streams,
-            );
+            return self.unchecked_scalar_right_shift_async(numerator, shift as u64, streams);
         }
 
         let log2_divisor = MiniUnsignedInteger::ceil_ilog2(divisor);
@@ -303,6 +308,72 @@ impl CudaServerKey {
         self.unchecked_scalar_div_async(numerator, divisor, streams)
     }
 
+    /// Computes homomorphically the floor of the division between a ciphertext and a scalar.
+    ///
+    /// Only the quotient is computed, the remainder is never reconstructed, which makes this
+    /// cheaper than [Self::scalar_div_rem] when the remainder is not needed (e.g. averaging or
+    /// scaling).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 200u64;
+    /// let scalar = 7u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Compute homomorphically the floor division:
+    /// let d_ct_res = sks.scalar_div_floor(&d_ct, scalar, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(msg / scalar, clear);
+    /// ```
+    pub fn scalar_div_floor<Scalar>(
+        &self,
+        numerator: &CudaUnsignedRadixCiphertext,
+        divisor: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: Reciprocable,
+    {
+        let res = unsafe { self.scalar_div_floor_async(numerator, divisor, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub unsafe fn scalar_div_floor_async<Scalar>(
+        &self,
+        numerator: &CudaUnsignedRadixCiphertext,
+        divisor: Scalar,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext
+    where
+        Scalar: Reciprocable,
+    {
+        // For unsigned integers the truncated quotient is the floor quotient
+        self.scalar_div_async(numerator, divisor, streams)
+    }
+
     pub fn unchecked_scalar_div_rem<Scalar>(
         &self,
         numerator: &CudaUnsignedRadixCiphertext,
@@ -543,6 +614,97 @@ impl CudaServerKey {
         self.unchecked_scalar_rem_async(numerator, divisor, streams)
     }
 
+    /// Extracts the base-`base` digits of `ct` using repeated scalar divisions.
+    ///
+    /// The digits are returned least significant first, each one encrypted on as many blocks as
+    /// `ct`. As many digits as needed to write the maximum value `ct` can encrypt are returned, so
+    /// the most significant ones may be zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is smaller than 2.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 203u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // 255 has 3 digits in base 10
+    /// let d_digits = sks.to_radix_digits(&d_ct, 10, &streams);
+    /// let digits: Vec<u64> = d_digits
+    ///     .iter()
+    ///     .map(|d_digit| cks.decrypt(&d_digit.to_radix_ciphertext(&streams)))
+    ///     .collect();
+    /// assert_eq!(digits, vec![3, 0, 2]);
+    /// ```
+    pub fn to_radix_digits(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        base: u32,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        let res = unsafe { self.to_radix_digits_async(ct, base, streams) };
+        streams.synchronize();
+        res
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronized
+    pub unsafe fn to_radix_digits_async(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        base: u32,
+        streams: &CudaStreams,
+    ) -> Vec<CudaUnsignedRadixCiphertext> {
+        assert!(base >= 2, "The base must be at least 2, got {base}");
+
+        let num_bits =
+            ct.as_ref().d_blocks.lwe_ciphertext_count().0 * self.message_modulus.0.ilog2() as usize;
+
+        // Little endian limbs of the maximum value ct can encrypt, divided by base along with
+        // the ciphertext, to know when all remaining digits are 0
+        let mut max_value = vec![u64::MAX; num_bits.div_ceil(64)];
+        if num_bits % 64 != 0 {
+            *max_value.last_mut().unwrap() = (1u64 << (num_bits % 64)) - 1;
+        }
+
+        let mut digits = Vec::new();
+        let mut quotient: Option<CudaUnsignedRadixCiphertext> = None;
+        while max_value.iter().any(|&limb| limb != 0) {
+            let numerator = quotient.as_ref().unwrap_or(ct);
+            let (next_quotient, digit) = self.scalar_div_rem_async(numerator, base, streams);
+            digits.push(digit);
+            quotient = Some(next_quotient);
+
+            let mut rem = 0u128;
+            for limb in max_value.iter_mut().rev() {
+                let current = (rem << 64) | u128::from(*limb);
+                *limb = (current / u128::from(base)) as u64;
+                rem = current % u128::from(base);
+            }
+        }
+
+        digits
+    }
+
     pub fn unchecked_signed_scalar_div<Scalar>(
         &self,
         numerator: &CudaSignedRadixCiphertext,
@@ -1,9 +1,9 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
-use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
-use crate::integer::gpu::{unchecked_scalar_mul_integer_radix_kb_async, PBSType};
+use crate::integer::gpu::unchecked_scalar_mul_integer_radix_kb_with_params_async;
 use crate::integer::server_key::ScalarMultiplier;
 use crate::prelude::CastInto;
 use itertools::Itertools;
@@ -74,6 +74,7 @@ impl CudaServerKey {
         Scalar: ScalarMultiplier + DecomposableInto<u8> + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
+        Self::assert_has_blocks(ct, "unchecked_scalar_mul");
         if scalar == Scalar::ZERO {
             ct.as_mut().d_blocks.0.d_vec.memset_async(0, streams, 0);
             return;
@@ -109,53 +110,29 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_mul_integer_radix_kb_async(
+                unchecked_scalar_mul_integer_radix_kb_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     decomposed_scalar.as_slice(),
                     has_at_least_one_set.as_slice(),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_bsk.decomp_base_log,
-                    d_bsk.decomp_level_count,
-                    self.key_switching_key.decomposition_base_log(),
-                    self.key_switching_key.decomposition_level_count(),
+                    &self.pbs_params(),
                     num_blocks as u32,
                     decomposed_scalar.len() as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_mul_integer_radix_kb_async(
+                unchecked_scalar_mul_integer_radix_kb_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     decomposed_scalar.as_slice(),
                     has_at_least_one_set.as_slice(),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    d_multibit_bsk.decomp_base_log,
-                    d_multibit_bsk.decomp_level_count,
-                    self.key_switching_key.decomposition_base_log(),
-                    self.key_switching_key.decomposition_level_count(),
+                    &self.pbs_params(),
                     num_blocks as u32,
                     decomposed_scalar.len() as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         };
@@ -238,6 +215,7 @@ impl CudaServerKey {
         Scalar: ScalarMultiplier + DecomposableInto<u8> + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
+        Self::assert_has_blocks(ct, "scalar_mul");
         if !ct.block_carries_are_empty() {
             self.full_propagate_assign_async(ct, streams);
         };
@@ -245,6 +223,40 @@ impl CudaServerKey {
         self.unchecked_scalar_mul_assign_async(ct, scalar, streams);
     }
 
+    /// Computes homomorphically a multiplication between a scalar and a ciphertext.
+    ///
+    /// The result is assigned to `ct`, which avoids allocating a new ciphertext compared to
+    /// [Self::scalar_mul].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 30;
+    /// let scalar = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Compute homomorphically a scalar multiplication:
+    /// sks.scalar_mul_assign(&mut d_ct, scalar, &streams);
+    /// let ct_res = d_ct.to_radix_ciphertext(&streams);
+    ///
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(scalar * msg, clear);
+    /// ```
     pub fn scalar_mul_assign<Scalar, T>(&self, ct: &mut T, scalar: Scalar, streams: &CudaStreams)
     where
         Scalar: ScalarMultiplier + DecomposableInto<u8> + CastInto<u64>,
@@ -255,4 +267,95 @@ impl CudaServerKey {
         }
         streams.synchronize();
     }
+
+    /// Computes homomorphically a multiplication between a scalar and a ciphertext encrypting an
+    /// unsigned integer value, and returns a boolean indicating whether the result overflowed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 100u8;
+    /// let scalar = 3u8;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Compute homomorphically an overflowing scalar multiplication:
+    /// let (d_ct_res, d_overflowed) = sks.unsigned_overflowing_scalar_mul(&d_ct, scalar, &streams);
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let overflowed = d_overflowed.to_boolean_block(&streams);
+    ///
+    /// let clear: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!((clear, cks.decrypt_bool(&overflowed)), msg.overflowing_mul(scalar));
+    /// ```
+    pub fn unsigned_overflowing_scalar_mul<Scalar>(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock)
+    where
+        Scalar: ScalarMultiplier + DecomposableInto<u8> + CastInto<u64>,
+    {
+        let result = unsafe { self.unsigned_overflowing_scalar_mul_async(ct, scalar, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unsigned_overflowing_scalar_mul_async<Scalar>(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        scalar: Scalar,
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock)
+    where
+        Scalar: ScalarMultiplier + DecomposableInto<u8> + CastInto<u64>,
+    {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let num_scalar_blocks =
+            BlockDecomposer::with_early_stop_at_zero(scalar, self.message_modulus.0.ilog2())
+                .count();
+
+        if num_scalar_blocks == 0 {
+            // Multiplying by zero never overflows
+            let mut result = ct.duplicate_async(streams);
+            self.scalar_mul_assign_async(&mut result, scalar, streams);
+            let trivial: CudaUnsignedRadixCiphertext =
+                self.create_trivial_radix_async(0, 1, streams);
+            return (
+                result,
+                CudaBooleanBlock::from_cuda_radix_ciphertext(trivial.ciphertext),
+            );
+        }
+
+        // The product of a n blocks value by a m blocks scalar fits in n + m blocks,
+        // it overflowed if any of the upper m blocks is not zero
+        let mut wide_result =
+            self.extend_radix_with_trivial_zero_blocks_msb_async(ct, num_scalar_blocks, streams);
+        self.scalar_mul_assign_async(&mut wide_result, scalar, streams);
+
+        let high_part = self.trim_radix_blocks_lsb_async(&wide_result, num_blocks, streams);
+        let result = self.trim_radix_blocks_msb_async(&wide_result, num_scalar_blocks, streams);
+
+        let overflowed = self.any_block_nonzero_async(&high_part, streams);
+
+        (result, overflowed)
+    }
 }
@@ -1,11 +1,12 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{CastFrom, LweBskGroupingFactor};
-use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::core_crypto::prelude::CastFrom;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::CudaBootstrappingKey;
 use crate::integer::gpu::{
-    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_async,
-    unchecked_scalar_left_shift_integer_radix_kb_assign_async,
-    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async, CudaServerKey, PBSType,
+    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async,
+    unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async,
+    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async, CudaServerKey,
 };
 
 impl CudaServerKey {
@@ -47,55 +48,25 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_left_shift_integer_radix_kb_assign_async(
+                unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     u32::cast_from(shift),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_left_shift_integer_radix_kb_assign_async(
+                unchecked_scalar_left_shift_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     u32::cast_from(shift),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
@@ -193,110 +164,34 @@ impl CudaServerKey {
         if T::IS_SIGNED {
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_async(
-                        streams,
-                        &mut ct.as_mut().d_blocks.0.d_vec,
-                        u32::cast_from(shift),
-                        &d_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
-                    );
+                    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async(streams, &mut ct.as_mut().d_blocks.0.d_vec, u32::cast_from(shift), &d_bsk.d_vec, &self.key_switching_key.d_vec, &self.pbs_params(), lwe_ciphertext_count.0 as u32);
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_async(
-                        streams,
-                        &mut ct.as_mut().d_blocks.0.d_vec,
-                        u32::cast_from(shift),
-                        &d_multibit_bsk.d_vec,
-                        &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
-                        lwe_ciphertext_count.0 as u32,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
-                    );
+                    unchecked_scalar_arithmetic_right_shift_integer_radix_kb_assign_with_params_async(streams, &mut ct.as_mut().d_blocks.0.d_vec, u32::cast_from(shift), &d_multibit_bsk.d_vec, &self.key_switching_key.d_vec, &self.pbs_params(), lwe_ciphertext_count.0 as u32);
                 }
             }
         } else {
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async(
+                    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async(
                         streams,
                         &mut ct.as_mut().d_blocks.0.d_vec,
                         u32::cast_from(shift),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         lwe_ciphertext_count.0 as u32,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async(
+                    unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async(
                         streams,
                         &mut ct.as_mut().d_blocks.0.d_vec,
                         u32::cast_from(shift),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key
-                            .input_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         lwe_ciphertext_count.0 as u32,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                     );
                 }
             }
@@ -590,57 +485,221 @@ impl CudaServerKey {
 
         match &self.bootstrapping_key {
             CudaBootstrappingKey::Classic(d_bsk) => {
-                unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async(
+                unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     u32::cast_from(shift),
                     &d_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_bsk.glwe_dimension,
-                    d_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_bsk.decomp_level_count,
-                    d_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::Classical,
-                    LweBskGroupingFactor(0),
                 );
             }
             CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                unchecked_scalar_logical_right_shift_integer_radix_kb_assign_async(
+                unchecked_scalar_logical_right_shift_integer_radix_kb_assign_with_params_async(
                     streams,
                     &mut ct.as_mut().d_blocks.0.d_vec,
                     u32::cast_from(shift),
                     &d_multibit_bsk.d_vec,
                     &self.key_switching_key.d_vec,
-                    self.message_modulus,
-                    self.carry_modulus,
-                    d_multibit_bsk.glwe_dimension,
-                    d_multibit_bsk.polynomial_size,
-                    self.key_switching_key
-                        .input_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key
-                        .output_key_lwe_size()
-                        .to_lwe_dimension(),
-                    self.key_switching_key.decomposition_level_count(),
-                    self.key_switching_key.decomposition_base_log(),
-                    d_multibit_bsk.decomp_level_count,
-                    d_multibit_bsk.decomp_base_log,
+                    &self.pbs_params(),
                     lwe_ciphertext_count.0 as u32,
-                    PBSType::MultiBit,
-                    d_multibit_bsk.grouping_factor,
                 );
             }
         }
     }
+
+    /// Changes the number of fractional bits of a fixed-point value from `from_frac` to
+    /// `to_frac`.
+    ///
+    /// - When increasing the precision, the value is shifted left, which is exact (as long as the
+    ///   integer part still fits in the ciphertext).
+    /// - When reducing the precision, the value is shifted right and rounded to the nearest value,
+    ///   ties being rounded up.
+    ///
+    /// The result is returned as a new ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // 16 bits, to hold a Q8.8 value
+    /// let size = 8;
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // 3.78125 in Q8.8
+    /// let msg = 0x3C8u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // Q8.8 -> Q8.4, 60.5 / 16 is rounded up to 61 / 16
+    /// let d_ct_res = sks.rescale(&d_ct, 8, 4, &streams);
+    ///
+    /// // Copy back to CPU
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 61);
+    /// ```
+    pub fn rescale<T>(&self, ct: &T, from_frac: u32, to_frac: u32, streams: &CudaStreams) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.rescale_async(ct, from_frac, to_frac, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn rescale_async<T>(
+        &self,
+        ct: &T,
+        from_frac: u32,
+        to_frac: u32,
+        streams: &CudaStreams,
+    ) -> T
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        if to_frac >= from_frac {
+            return self.scalar_left_shift_async(ct, to_frac - from_frac, streams);
+        }
+
+        let shift = from_frac - to_frac;
+
+        // round(x / 2^shift) = (x >> shift) + bit (shift - 1) of x
+        // this cannot overflow as (x >> shift) is at most max >> shift
+        let mut result = self.scalar_right_shift_async(ct, shift, streams);
+        let mut round_bit = self.scalar_right_shift_async(ct, shift - 1, streams);
+        self.scalar_bitand_assign_async(&mut round_bit, 1u8, streams);
+        self.add_assign_async(&mut result, &round_bit, streams);
+
+        result
+    }
+
+    /// Computes homomorphically a left shift by a clear amount, also returning whether some set
+    /// bits were shifted out.
+    ///
+    /// The returned [CudaBooleanBlock] encrypts `true` if the shifted value no longer fits in the
+    /// ciphertext, i.e. if `ct << n` computed on a wider integer would differ from the result.
+    /// Shifting by at least the number of bits of the ciphertext gives 0, and overflows if `ct`
+    /// is not 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 100u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// // Copy to GPU
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    ///
+    /// // 100 << 1 fits in 8 bits
+    /// let (d_ct_res, d_overflowed) = sks.overflowing_scalar_shl(&d_ct, 1, &streams);
+    /// let dec_result: u64 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+    /// assert_eq!(dec_result, 200);
+    /// assert!(!cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams)));
+    ///
+    /// // 100 << 2 does not
+    /// let (d_ct_res, d_overflowed) = sks.overflowing_scalar_shl(&d_ct, 2, &streams);
+    /// let dec_result: u64 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+    /// assert_eq!(dec_result, (msg << 2) % 256);
+    /// assert!(cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams)));
+    /// ```
+    pub fn overflowing_scalar_shl<T>(
+        &self,
+        ct: &T,
+        n: u32,
+        streams: &CudaStreams,
+    ) -> (T, CudaBooleanBlock)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe { self.overflowing_scalar_shl_async(ct, n, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn overflowing_scalar_shl_async<T>(
+        &self,
+        ct: &T,
+        n: u32,
+        streams: &CudaStreams,
+    ) -> (T, CudaBooleanBlock)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let mut tmp;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp = ct.duplicate_async(streams);
+            self.full_propagate_assign_async(&mut tmp, streams);
+            &tmp
+        };
+
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let num_bits = self.message_modulus.0.ilog2() * num_blocks as u32;
+
+        if n == 0 {
+            let overflowed: CudaUnsignedRadixCiphertext =
+                self.create_trivial_zero_radix_async(1, streams);
+            return (
+                ct.duplicate_async(streams),
+                CudaBooleanBlock::from_cuda_radix_ciphertext(overflowed.ciphertext),
+            );
+        }
+
+        if n >= num_bits {
+            let result = self.create_trivial_zero_radix_async(num_blocks, streams);
+            let overflowed = self.unchecked_scalar_ne_async(ct, 0u64, streams);
+            return (result, overflowed);
+        }
+
+        // The bits shifted out are the n most significant ones
+        let mut shifted_out = ct.duplicate_async(streams);
+        self.unchecked_scalar_right_shift_logical_assign_async(
+            &mut shifted_out,
+            num_bits - n,
+            streams,
+        );
+        let overflowed = self.unchecked_scalar_ne_async(&shifted_out, 0u64, streams);
+
+        let mut result = ct.duplicate_async(streams);
+        self.unchecked_scalar_left_shift_assign_async(&mut result, n, streams);
+
+        (result, overflowed)
+    }
 }
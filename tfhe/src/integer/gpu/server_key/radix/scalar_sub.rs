@@ -2,7 +2,10 @@ use crate::core_crypto::gpu::CudaStreams;
 use crate::core_crypto::prelude::{Numeric, SignedNumeric};
 use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
-use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaSignedRadixCiphertext};
+use crate::integer::gpu::ciphertext::{
+    CudaIntegerRadixCiphertext, CudaSignedRadixCiphertext, CudaUnsignedRadixCiphertext,
+};
+use crate::integer::gpu::scalar_subtraction_integer_radix_assign_async;
 use crate::integer::gpu::server_key::CudaServerKey;
 use crate::integer::server_key::radix_parallel::OutputFlag;
 use crate::integer::server_key::TwosComplementNegation;
@@ -74,8 +77,18 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8> + Numeric + TwosComplementNegation + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
-        let negated_scalar = scalar.twos_complement_negation();
-        self.unchecked_scalar_add_assign_async(ct, negated_scalar, streams);
+        Self::assert_has_blocks(ct, "unchecked_scalar_sub");
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let lwe_dimension = ct.as_ref().d_blocks.lwe_dimension();
+        scalar_subtraction_integer_radix_assign_async(
+            streams,
+            &mut ct.as_mut().d_blocks.0.d_vec,
+            scalar,
+            lwe_dimension,
+            num_blocks as u32,
+            self.message_modulus.0 as u32,
+            self.carry_modulus.0 as u32,
+        );
         ct.as_mut().info = ct.as_ref().info.after_scalar_sub(scalar);
     }
 
@@ -154,6 +167,7 @@ impl CudaServerKey {
         Scalar: DecomposableInto<u8> + Numeric + TwosComplementNegation + CastInto<u64>,
         T: CudaIntegerRadixCiphertext,
     {
+        Self::assert_has_blocks(ct, "scalar_sub");
         if !ct.block_carries_are_empty() {
             self.full_propagate_assign_async(ct, streams);
         };
@@ -173,6 +187,82 @@ impl CudaServerKey {
         streams.synchronize();
     }
 
+    /// Decrements a ciphertext by one, and returns whether the decremented value is still
+    /// nonzero.
+    ///
+    /// This is meant to drive encrypted loop counters, where the loop body runs while the
+    /// counter is nonzero. The decrement wraps around: decrementing a counter that is already
+    /// zero gives the maximum value and returns `false`, so that the loop does not restart.
+    ///
+    /// The decrement and the test are fused in a single carry propagation, whose carry out is
+    /// the returned flag. The decremented counter is left with a carry in its least significant
+    /// block, which is propagated by the next operation that needs it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// // Copy to GPU
+    /// let mut d_counter = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(2u64), &streams);
+    ///
+    /// let d_is_nonzero = sks.decrement_and_is_nonzero(&mut d_counter, &streams);
+    /// assert!(cks.decrypt_bool(&d_is_nonzero.to_boolean_block(&streams)));
+    ///
+    /// let d_is_nonzero = sks.decrement_and_is_nonzero(&mut d_counter, &streams);
+    /// assert!(!cks.decrypt_bool(&d_is_nonzero.to_boolean_block(&streams)));
+    ///
+    /// let dec_counter: u64 = cks.decrypt(&d_counter.to_radix_ciphertext(&streams));
+    /// assert_eq!(dec_counter, 0);
+    /// ```
+    pub fn decrement_and_is_nonzero(
+        &self,
+        ct: &mut CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let result = unsafe { self.decrement_and_is_nonzero_async(ct, streams) };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn decrement_and_is_nonzero_async(
+        &self,
+        ct: &mut CudaUnsignedRadixCiphertext,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        Self::assert_has_blocks(ct, "decrement_and_is_nonzero");
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_assign_async(ct, streams);
+        }
+
+        // ct - 2 is computed as ct + (2^n - 2), which carries out if and only if ct >= 2, that is
+        // if ct - 1 is nonzero and did not wrap around
+        self.unchecked_scalar_sub_assign_async(ct, 2u8, streams);
+        let carry_out =
+            self.propagate_single_carry_assign_async(ct, streams, None, OutputFlag::Carry);
+
+        // Adding one back only fills the carry of the first block, no propagation is needed
+        self.unchecked_scalar_add_assign_async(ct, 1u8, streams);
+
+        CudaBooleanBlock::from_cuda_radix_ciphertext(carry_out.ciphertext)
+    }
+
     /// ```rust
     /// use tfhe::core_crypto::gpu::CudaStreams;
     /// use tfhe::core_crypto::gpu::vec::GpuIndex;
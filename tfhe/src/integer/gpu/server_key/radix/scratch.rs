@@ -0,0 +1,263 @@
+use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
+use crate::core_crypto::gpu::vec::GpuIndex;
+use crate::core_crypto::gpu::CudaStreams;
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextCount};
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::info::CudaRadixCiphertextInfo;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaRadixCiphertext};
+use crate::integer::gpu::server_key::CudaBootstrappingKey;
+use crate::integer::gpu::{
+    cleanup_comparison_integer_radix_kb_async, comparison_integer_radix_with_buffers_kb_async,
+    scratch_comparison_integer_radix_kb_async, ComparisonType, CudaPbsParams, CudaServerKey,
+};
+use crate::shortint::ciphertext::Degree;
+use tfhe_cuda_backend::cuda_bind::cuda_create_stream;
+
+/// Device buffers allocated once for a comparison on ciphertexts of a given number of blocks,
+/// see [CudaServerKey::comparison_scratch].
+///
+/// Only comparisons returning a boolean can reuse their buffers this way, the other operations
+/// still allocate and release their buffers on each call.
+///
+/// The device buffers are released when the `CudaComparisonScratch` is dropped.
+pub struct CudaComparisonScratch {
+    mem_ptr: *mut i8,
+    gpu_indexes: Vec<GpuIndex>,
+    pbs_params: CudaPbsParams,
+    num_blocks: usize,
+    op: ComparisonType,
+    is_signed: bool,
+}
+
+impl CudaComparisonScratch {
+    /// Returns the number of blocks of the ciphertexts the scratch was allocated for
+    pub fn num_blocks(&self) -> usize {
+        self.num_blocks
+    }
+}
+
+impl Drop for CudaComparisonScratch {
+    fn drop(&mut self) {
+        // The streams used to allocate the scratch may be gone, the release is done on
+        // new streams on the same GPUs
+        let streams = CudaStreams {
+            ptr: self
+                .gpu_indexes
+                .iter()
+                .map(|gpu_index| unsafe { cuda_create_stream(gpu_index.0) })
+                .collect(),
+            gpu_indexes: self.gpu_indexes.clone(),
+            owned: true,
+        };
+        unsafe {
+            cleanup_comparison_integer_radix_kb_async(&streams, &mut self.mem_ptr);
+        }
+        streams.synchronize();
+    }
+}
+
+impl CudaServerKey {
+    /// Allocates on the GPUs of `streams` the buffers needed to compare ciphertexts of type `T`
+    /// with `num_blocks` blocks using `op`, so that they can be reused by
+    /// [CudaServerKey::unchecked_comparison_with_scratch] instead of being allocated and released
+    /// on each comparison.
+    ///
+    /// `op` must be one of the comparisons returning a boolean (i.e. not `MIN` or `MAX`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::{gen_keys_radix_gpu, ComparisonType};
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let mut scratch =
+    ///     sks.comparison_scratch::<CudaUnsignedRadixCiphertext>(ComparisonType::GT, size, &streams);
+    ///
+    /// for (msg1, msg2) in [(97u64, 14u64), (14u64, 97u64), (51u64, 51u64)] {
+    ///     let ct1 = cks.encrypt(msg1);
+    ///     let ct2 = cks.encrypt(msg2);
+    ///
+    ///     // Copy to GPU
+    ///     let d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct1, &streams);
+    ///     let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct2, &streams);
+    ///
+    ///     let d_ct_res = sks.unchecked_comparison_with_scratch(&d_ct1, &d_ct2, &mut scratch, &streams);
+    ///
+    ///     // Copy the result back to CPU
+    ///     let ct_res = d_ct_res.to_boolean_block(&streams);
+    ///
+    ///     // Decrypt:
+    ///     let dec_result = cks.decrypt_bool(&ct_res);
+    ///     assert_eq!(dec_result, msg1 > msg2);
+    /// }
+    /// ```
+    pub fn comparison_scratch<T>(
+        &self,
+        op: ComparisonType,
+        num_blocks: usize,
+        streams: &CudaStreams,
+    ) -> CudaComparisonScratch
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert!(
+            !matches!(op, ComparisonType::MAX | ComparisonType::MIN),
+            "Only comparisons returning a boolean can use a scratch"
+        );
+
+        let mut mem_ptr: *mut i8 = std::ptr::null_mut();
+
+        unsafe {
+            scratch_comparison_integer_radix_kb_async(
+                streams,
+                &mut mem_ptr,
+                &self.pbs_params(),
+                num_blocks as u32,
+                op,
+                T::IS_SIGNED,
+            );
+        }
+        streams.synchronize();
+
+        CudaComparisonScratch {
+            mem_ptr,
+            gpu_indexes: streams.gpu_indexes.clone(),
+            pbs_params: self.pbs_params(),
+            num_blocks,
+            op,
+            is_signed: T::IS_SIGNED,
+        }
+    }
+
+    /// Compares `ct_left` and `ct_right` with the comparison `scratch` was allocated for,
+    /// reusing its buffers.
+    ///
+    /// `scratch` must have been allocated with a key using the same parameters as this one, the
+    /// ciphertexts must have the number of blocks `scratch` was allocated for, and `streams` must
+    /// be on the same GPUs as the ones used to allocate it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of these requirements is not met.
+    ///
+    /// See [CudaServerKey::comparison_scratch] for an example.
+    pub fn unchecked_comparison_with_scratch<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        scratch: &mut CudaComparisonScratch,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let result = unsafe {
+            self.unchecked_comparison_with_scratch_async(ct_left, ct_right, scratch, streams)
+        };
+        streams.synchronize();
+        result
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn unchecked_comparison_with_scratch_async<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+        scratch: &mut CudaComparisonScratch,
+        streams: &CudaStreams,
+    ) -> CudaBooleanBlock
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        assert_eq!(
+            ct_left.as_ref().d_blocks.lwe_dimension(),
+            ct_right.as_ref().d_blocks.lwe_dimension()
+        );
+        let num_blocks = ct_left.as_ref().d_blocks.lwe_ciphertext_count().0;
+        assert_eq!(
+            num_blocks,
+            ct_right.as_ref().d_blocks.lwe_ciphertext_count().0
+        );
+        assert_eq!(
+            num_blocks, scratch.num_blocks,
+            "The scratch was allocated for {} blocks, got {num_blocks}",
+            scratch.num_blocks
+        );
+        assert_eq!(
+            T::IS_SIGNED,
+            scratch.is_signed,
+            "The scratch was allocated for a different signedness"
+        );
+        assert_eq!(
+            streams.gpu_indexes, scratch.gpu_indexes,
+            "The scratch was allocated on different GPUs"
+        );
+        assert_eq!(
+            self.pbs_params(),
+            scratch.pbs_params,
+            "The scratch was allocated for a key with different parameters"
+        );
+
+        let block = CudaLweCiphertextList::new(
+            ct_left.as_ref().d_blocks.lwe_dimension(),
+            LweCiphertextCount(1),
+            CiphertextModulus::new_native(),
+            streams,
+        );
+        let mut block_info = ct_left.as_ref().info.blocks[0];
+        block_info.degree = Degree::new(1);
+        let ct_info = vec![block_info];
+        let ct_info = CudaRadixCiphertextInfo { blocks: ct_info };
+
+        let mut result =
+            CudaBooleanBlock::from_cuda_radix_ciphertext(CudaRadixCiphertext::new(block, ct_info));
+
+        match &self.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                comparison_integer_radix_with_buffers_kb_async(
+                    streams,
+                    &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct_left.as_ref().d_blocks.0.d_vec,
+                    &ct_right.as_ref().d_blocks.0.d_vec,
+                    scratch.mem_ptr,
+                    &d_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    num_blocks as u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                comparison_integer_radix_with_buffers_kb_async(
+                    streams,
+                    &mut result.as_mut().ciphertext.d_blocks.0.d_vec,
+                    &ct_left.as_ref().d_blocks.0.d_vec,
+                    &ct_right.as_ref().d_blocks.0.d_vec,
+                    scratch.mem_ptr,
+                    &d_multibit_bsk.d_vec,
+                    &self.key_switching_key.d_vec,
+                    num_blocks as u32,
+                );
+            }
+        }
+
+        if matches!(scratch.op, ComparisonType::EQ) {
+            result.as_mut().ciphertext.info = result.as_ref().ciphertext.info.after_eq();
+        } else if matches!(scratch.op, ComparisonType::NE) {
+            result.as_mut().ciphertext.info = result.as_ref().ciphertext.info.after_ne();
+        }
+        result
+    }
+}
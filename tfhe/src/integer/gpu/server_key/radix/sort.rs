@@ -0,0 +1,177 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::CudaServerKey;
+
+impl CudaServerKey {
+    /// Orders `ct_left` and `ct_right` in place.
+    ///
+    /// If `ascending` is true, `ct_left` receives the minimum and `ct_right` the maximum,
+    /// otherwise it is the opposite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg1 = 97u64;
+    /// let msg2 = 14u64;
+    ///
+    /// // Copy to GPU
+    /// let mut d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let mut d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// sks.compare_and_swap(&mut d_ct1, &mut d_ct2, true, &streams);
+    ///
+    /// // Copy the result back to CPU
+    /// let ct1 = d_ct1.to_radix_ciphertext(&streams);
+    /// let ct2 = d_ct2.to_radix_ciphertext(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec1: u64 = cks.decrypt(&ct1);
+    /// let dec2: u64 = cks.decrypt(&ct2);
+    /// assert_eq!(dec1, 14);
+    /// assert_eq!(dec2, 97);
+    /// ```
+    pub fn compare_and_swap<T>(
+        &self,
+        ct_left: &mut T,
+        ct_right: &mut T,
+        ascending: bool,
+        streams: &CudaStreams,
+    ) where
+        T: CudaIntegerRadixCiphertext,
+    {
+        unsafe {
+            self.compare_and_swap_async(ct_left, ct_right, ascending, streams);
+        }
+        streams.synchronize();
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn compare_and_swap_async<T>(
+        &self,
+        ct_left: &mut T,
+        ct_right: &mut T,
+        ascending: bool,
+        streams: &CudaStreams,
+    ) where
+        T: CudaIntegerRadixCiphertext,
+    {
+        let (min, max) = self.min_max_async(ct_left, ct_right, streams);
+        if ascending {
+            *ct_left = min;
+            *ct_right = max;
+        } else {
+            *ct_left = max;
+            *ct_right = min;
+        }
+    }
+
+    /// Sorts the ciphertexts of `cts` in place, in ascending order if `ascending` is true and
+    /// in descending order otherwise.
+    ///
+    /// The sort is done with a bitonic sorting network built with
+    /// [compare_and_swap](Self::compare_and_swap), so the sequence of operations does not depend
+    /// on the encrypted values. Any number of ciphertexts is supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msgs = [14u64, 97, 3, 42, 7];
+    ///
+    /// // Copy to GPU
+    /// let mut d_cts = msgs
+    ///     .iter()
+    ///     .map(|&msg| CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// sks.sort(&mut d_cts, true, &streams);
+    ///
+    /// // Copy the result back to CPU and decrypt
+    /// let sorted = d_cts
+    ///     .iter()
+    ///     .map(|d_ct| cks.decrypt::<u64>(&d_ct.to_radix_ciphertext(&streams)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sorted, vec![3, 7, 14, 42, 97]);
+    /// ```
+    pub fn sort<T>(&self, cts: &mut [T], ascending: bool, streams: &CudaStreams)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        unsafe {
+            self.sort_async(cts, ascending, streams);
+        }
+        streams.synchronize();
+    }
+
+    /// # Safety
+    ///
+    /// - `streams` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until streams is synchronised
+    pub unsafe fn sort_async<T>(&self, cts: &mut [T], ascending: bool, streams: &CudaStreams)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        if cts.len() < 2 {
+            return;
+        }
+
+        // Sort each half in opposite directions, which gives a bitonic sequence
+        let mid = cts.len() / 2;
+        let (lower, upper) = cts.split_at_mut(mid);
+        self.sort_async(lower, !ascending, streams);
+        self.sort_async(upper, ascending, streams);
+
+        self.bitonic_merge_async(cts, ascending, streams);
+    }
+
+    // Sorts a bitonic sequence of any length
+    unsafe fn bitonic_merge_async<T>(&self, cts: &mut [T], ascending: bool, streams: &CudaStreams)
+    where
+        T: CudaIntegerRadixCiphertext,
+    {
+        if cts.len() < 2 {
+            return;
+        }
+
+        // Greatest power of two strictly less than the length
+        let distance = 1 << (cts.len() - 1).ilog2();
+        let (lower, upper) = cts.split_at_mut(distance);
+        for (ct_left, ct_right) in lower.iter_mut().zip(upper.iter_mut()) {
+            self.compare_and_swap_async(ct_left, ct_right, ascending, streams);
+        }
+
+        let (lower, upper) = cts.split_at_mut(distance);
+        self.bitonic_merge_async(lower, ascending, streams);
+        self.bitonic_merge_async(upper, ascending, streams);
+    }
+}
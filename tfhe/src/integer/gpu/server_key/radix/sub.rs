@@ -93,6 +93,8 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        Self::assert_has_blocks(ct_left, "unchecked_sub");
+        Self::assert_has_blocks(ct_right, "unchecked_sub");
         let neg = self.unchecked_neg_async(ct_right, streams);
         self.unchecked_add_assign_async(ct_left, &neg, streams);
     }
@@ -248,6 +250,8 @@ impl CudaServerKey {
         ct_right: &T,
         streams: &CudaStreams,
     ) {
+        Self::assert_has_blocks(ct_left, "sub");
+        Self::assert_has_blocks(ct_right, "sub");
         let mut tmp_rhs;
 
         let (lhs, rhs) = match (
@@ -326,6 +330,84 @@ impl CudaServerKey {
         self.unchecked_unsigned_overflowing_sub(lhs, rhs, stream)
     }
 
+    /// Computes homomorphically the subtraction `ct_left - ct_right`, writing the result in
+    /// `ct_left` and returning whether it overflowed (i.e. if `ct_right > ct_left`).
+    ///
+    /// This is the in-place version of [Self::unsigned_overflowing_sub].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks, &streams);
+    ///
+    /// let msg1: u8 = 8;
+    /// let msg2: u8 = 120;
+    ///
+    /// // Copy to GPU
+    /// let mut d_ct1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg1), &streams);
+    /// let d_ct2 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg2), &streams);
+    ///
+    /// // Compute homomorphically an overflowing subtraction:
+    /// let d_ct_overflowed = sks.unsigned_overflowing_sub_assign(&mut d_ct1, &d_ct2, &streams);
+    ///
+    /// let ct_res = d_ct1.to_radix_ciphertext(&streams);
+    /// let ct_overflowed = d_ct_overflowed.to_boolean_block(&streams);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed: bool = cks.decrypt_bool(&ct_overflowed);
+    /// let (clear_result, clear_overflowed) = msg1.overflowing_sub(msg2);
+    /// assert_eq!(dec_result, clear_result);
+    /// assert_eq!(dec_overflowed, clear_overflowed);
+    /// ```
+    pub fn unsigned_overflowing_sub_assign(
+        &self,
+        ct_left: &mut CudaUnsignedRadixCiphertext,
+        ct_right: &CudaUnsignedRadixCiphertext,
+        stream: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        assert_eq!(
+            ct_left.as_ref().d_blocks.lwe_ciphertext_count(),
+            ct_right.as_ref().d_blocks.lwe_ciphertext_count(),
+            "Left hand side must must have a number of blocks equal \
+            to the number of blocks of the right hand side: lhs {} blocks, rhs {} blocks",
+            ct_left.as_ref().d_blocks.lwe_ciphertext_count().0,
+            ct_right.as_ref().d_blocks.lwe_ciphertext_count().0
+        );
+
+        let mut tmp_rhs;
+        let ct_overflowed;
+        unsafe {
+            if !ct_left.block_carries_are_empty() {
+                self.full_propagate_assign_async(ct_left, stream);
+            }
+            let rhs = if ct_right.block_carries_are_empty() {
+                ct_right
+            } else {
+                tmp_rhs = ct_right.duplicate_async(stream);
+                self.full_propagate_assign_async(&mut tmp_rhs, stream);
+                &tmp_rhs
+            };
+
+            ct_overflowed =
+                self.unchecked_unsigned_overflowing_sub_assign_async(ct_left, rhs, stream);
+        }
+        stream.synchronize();
+
+        ct_overflowed
+    }
+
     pub fn unchecked_unsigned_overflowing_sub(
         &self,
         lhs: &CudaUnsignedRadixCiphertext,
@@ -363,6 +445,25 @@ impl CudaServerKey {
     ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
         let mut ct_res = lhs.duplicate_async(stream);
 
+        let ct_overflowed =
+            self.unchecked_unsigned_overflowing_sub_assign_async(&mut ct_res, rhs, stream);
+
+        (ct_res, ct_overflowed)
+    }
+
+    /// # Safety
+    ///
+    /// - `stream` __must__ be synchronized to guarantee computation has finished, and inputs must
+    ///   not be dropped until stream is synchronised
+    pub unsafe fn unchecked_unsigned_overflowing_sub_assign_async(
+        &self,
+        ct_res: &mut CudaUnsignedRadixCiphertext,
+        rhs: &CudaUnsignedRadixCiphertext,
+        stream: &CudaStreams,
+    ) -> CudaBooleanBlock {
+        let lhs_is_trivial =
+            ct_res.as_ref().info.blocks.last().unwrap().noise_level == NoiseLevel::ZERO;
+
         let compute_overflow = true;
         const INPUT_BORROW: Option<&CudaBooleanBlock> = None;
 
@@ -444,7 +545,7 @@ impl CudaServerKey {
                 b.noise_level = NoiseLevel::ZERO;
             });
 
-        if lhs.as_ref().info.blocks.last().unwrap().noise_level == NoiseLevel::ZERO
+        if lhs_is_trivial
             && rhs.as_ref().info.blocks.last().unwrap().noise_level == NoiseLevel::ZERO
         {
             overflow_block.as_mut().info =
@@ -463,7 +564,7 @@ impl CudaServerKey {
             .info
             .after_overflowing_sub(&rhs.as_ref().info);
 
-        (ct_res, ct_overflowed)
+        ct_overflowed
     }
 
     /// ```rust
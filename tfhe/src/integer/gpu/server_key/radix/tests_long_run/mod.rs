@@ -39,6 +39,8 @@ impl<F> GpuMultiDeviceFunctionExecutor<F> {
 
         let sks = CudaServerKey::new(cks.as_ref(), &streams);
         streams.synchronize();
+        // So that the first operation of the sequence doesn't include the GPU initialization
+        sks.warmup(&streams);
         let context = GpuContext { streams, sks };
         self.context = Some(context);
     }
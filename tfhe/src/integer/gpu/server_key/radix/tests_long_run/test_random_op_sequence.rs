@@ -360,10 +360,10 @@ where
     // Log2/Hamming weight ops
     let ilog2_executor = GpuMultiDeviceFunctionExecutor::new(&CudaServerKey::ilog2);
     //let count_zeros_executor = GpuMultiDeviceFunctionExecutor::new(&CudaServerKey::count_zeros);
-    //let count_ones_executor = GpuMultiDeviceFunctionExecutor::new(&CudaServerKey::count_ones);
+    let count_ones_executor = GpuMultiDeviceFunctionExecutor::new(&CudaServerKey::count_ones);
     let clear_ilog2 = |x: u64| x.ilog2() as u64;
     //let clear_count_zeros = |x: u64| x.count_zeros() as u64;
-    //let clear_count_ones = |x: u64| x.count_ones() as u64;
+    let clear_count_ones = |x: u64| x.count_ones() as u64;
 
     #[allow(clippy::type_complexity)]
     let mut log2_ops: Vec<(Log2OpExecutor, &dyn Fn(u64) -> u64, String)> = vec![
@@ -373,11 +373,11 @@ where
         //    &clear_count_zeros,
         //    "count zeros".to_string(),
         //),
-        //(
-        //    Box::new(count_ones_executor),
-        //    &clear_count_ones,
-        //    "count ones".to_string(),
-        //),
+        (
+            Box::new(count_ones_executor),
+            &clear_count_ones,
+            "count ones".to_string(),
+        ),
     ];
 
     random_op_sequence_test(
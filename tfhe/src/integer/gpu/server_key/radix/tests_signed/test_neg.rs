@@ -1,14 +1,18 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaSignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_signed::test_neg::{
     signed_default_neg_test, signed_unchecked_neg_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_neg);
 create_gpu_parameterized_test!(integer_neg);
+create_gpu_parameterized_test!(integer_neg_is_involution);
 
 fn integer_unchecked_neg<P>(param: P)
 where
@@ -25,3 +29,35 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::neg);
     signed_default_neg_test(param, executor);
 }
+
+fn integer_neg_is_involution<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let half_modulus = (param.message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    // MIN is its own opposite, so it is also preserved by a double negation
+    let clears = [-half_modulus, half_modulus - 1, 0, -1]
+        .into_iter()
+        .chain((0..4).map(|_| rng.gen_range(-half_modulus..half_modulus)));
+
+    for clear in clears {
+        let d_ctxt = CudaSignedRadixCiphertext::from_signed_radix_ciphertext(
+            &cks.encrypt_signed(clear),
+            &streams,
+        );
+
+        let d_neg = sks.neg(&d_ctxt, &streams);
+        let d_res = sks.neg(&d_neg, &streams);
+
+        let dec: i64 = cks.decrypt_signed(&d_res.to_signed_radix_ciphertext(&streams));
+        assert_eq!(dec, clear, "neg(neg(x)) != x for x = {clear}");
+    }
+}
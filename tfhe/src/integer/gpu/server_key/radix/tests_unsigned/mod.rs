@@ -1,11 +1,18 @@
 pub(crate) mod test_add;
+pub(crate) mod test_bit_reverse;
 pub(crate) mod test_bitwise_op;
+pub(crate) mod test_cast;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 pub(crate) mod test_div_mod;
+pub(crate) mod test_fallible;
+pub(crate) mod test_histogram;
 pub(crate) mod test_ilog2;
+pub(crate) mod test_migrate;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
+pub(crate) mod test_prepared_lut;
+pub(crate) mod test_reduce;
 pub(crate) mod test_rotate;
 pub(crate) mod test_scalar_add;
 pub(crate) mod test_scalar_bitwise_op;
@@ -15,14 +22,18 @@ pub(crate) mod test_scalar_mul;
 pub(crate) mod test_scalar_rotate;
 pub(crate) mod test_scalar_shift;
 pub(crate) mod test_scalar_sub;
+pub(crate) mod test_scratch;
 pub(crate) mod test_shift;
+pub(crate) mod test_sort;
 pub(crate) mod test_sub;
+pub(crate) mod test_trivial;
 pub(crate) mod test_vector_comparisons;
 pub(crate) mod test_vector_find;
+pub(crate) mod test_warmup;
 
 use crate::core_crypto::gpu::CudaStreams;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
-use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::CudaServerKey;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::*;
 pub use crate::integer::server_key::radix_parallel::MatchValues;
@@ -54,6 +65,37 @@ macro_rules! create_gpu_parameterized_test{
 
 pub(crate) use create_gpu_parameterized_test;
 
+/// Downloads a ciphertext to the CPU and decrypts it, printing the degree and noise level of each
+/// of its blocks.
+///
+/// This is only meant to help debugging failing tests. The blocks are decrypted as an unsigned
+/// value, carries included, so signed ciphertexts are returned in two's complement.
+pub(crate) fn debug_decrypt<T: CudaIntegerRadixCiphertext>(
+    ct: &T,
+    cks: &RadixClientKey,
+    streams: &CudaStreams,
+) -> u128 {
+    for (i, block) in ct.as_ref().info.blocks.iter().enumerate() {
+        println!(
+            "block {i}: degree {}, noise level {:?}",
+            block.degree.get(),
+            block.noise_level
+        );
+    }
+
+    let ct = RadixCiphertext::from(ct.as_ref().to_cpu_blocks(streams));
+    cks.decrypt(&ct)
+}
+
+/// Runs `func` and returns the message of the panic it raised, or `None` if it did not panic
+pub(crate) fn panic_message<F: FnOnce() -> R, R>(func: F) -> Option<String> {
+    let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)).err()?;
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|msg| (*msg).to_string()))
+}
+
 pub(crate) struct GpuContext {
     pub(crate) streams: CudaStreams,
     pub(crate) sks: CudaServerKey,
@@ -1,21 +1,43 @@
+use crate::core_crypto::gpu::vec::GpuIndex;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
-    create_gpu_parameterized_test, GpuFunctionExecutor,
+    create_gpu_parameterized_test, debug_decrypt, panic_message, GpuFunctionExecutor,
+};
+use crate::integer::gpu::server_key::CudaBootstrappingKey;
+use crate::integer::gpu::{
+    compute_prefix_sum_hillis_steele_with_params_async, gen_keys_radix_gpu, CudaServerKey,
 };
-use crate::integer::gpu::CudaServerKey;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_add_test, default_sum_ciphertexts_vec_test, unchecked_add_assign_test,
     unchecked_add_test,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_add::default_overflowing_add_test;
+use crate::integer::{ClientKey, CompressedServerKey};
+use crate::safe_serialization::safe_serialize;
+use crate::shortint::ciphertext::Degree;
 use crate::shortint::parameters::*;
+use rand::Rng;
+use tfhe_cuda_backend::cuda_bind::{
+    cuda_create_stream, cuda_destroy_stream, cuda_synchronize_stream,
+};
 
 create_gpu_parameterized_test!(integer_unchecked_add);
 create_gpu_parameterized_test!(integer_unchecked_add_assign);
 create_gpu_parameterized_test!(integer_add);
 create_gpu_parameterized_test!(integer_sum_ciphertexts_vec);
 create_gpu_parameterized_test!(integer_default_overflowing_add);
+create_gpu_parameterized_test!(integer_propagate_carries);
+create_gpu_parameterized_test!(integer_reset_degrees);
+create_gpu_parameterized_test!(integer_max_noise_level);
+create_gpu_parameterized_test!(integer_debug_decrypt);
+create_gpu_parameterized_test!(integer_duplicate);
+create_gpu_parameterized_test!(integer_compute_generate_propagate);
+create_gpu_parameterized_test!(integer_add_zero_blocks);
+create_gpu_parameterized_test!(integer_deserialize_from_cpu);
+create_gpu_parameterized_test!(integer_serialize_round_trip);
+create_gpu_parameterized_test!(integer_add_boolean);
 
 fn integer_unchecked_add<P>(param: P)
 where
@@ -65,3 +87,534 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::unsigned_overflowing_add);
     default_overflowing_add_test(param, executor);
 }
+
+fn integer_propagate_carries<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    // Forced carry and no carry cases, then random ones
+    let mut clears = vec![(modulus - 1, 1), (modulus - 2, 1), (0, 0)];
+    clears.extend((0..4).map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus)));
+
+    for (clear_0, clear_1) in clears {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let d_ctxt_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_0, &streams);
+        let d_ctxt_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_1, &streams);
+
+        let mut d_ct_res = sks.unchecked_add(&d_ctxt_0, &d_ctxt_1, &streams);
+        let d_carry = sks.propagate_carries(&mut d_ct_res, &streams);
+
+        let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+        let carry = d_carry.to_boolean_block(&streams);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_carry = cks.decrypt_bool(&carry);
+
+        assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+        assert_eq!(
+            dec_carry,
+            clear_0 + clear_1 >= modulus,
+            "Invalid carry for {clear_0} + {clear_1}"
+        );
+    }
+}
+
+fn integer_reset_degrees<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+    let carry_modulus = param.carry_modulus().0;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_2 = rng.gen::<u64>() % modulus;
+
+        let d_ctxt_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ctxt_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+        let d_ctxt_2 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_2), &streams);
+
+        // The carries of the sum may be non-empty, simulate metadata claiming otherwise
+        let mut d_sum = sks.unchecked_add(&d_ctxt_0, &d_ctxt_1, &streams);
+        for block in d_sum.as_mut().info.blocks.iter_mut() {
+            block.degree = Degree::new(0);
+        }
+
+        sks.reset_degrees(&mut d_sum);
+        assert!(d_sum
+            .as_ref()
+            .info
+            .blocks
+            .iter()
+            .all(|block| block.degree.get() == message_modulus * carry_modulus - 1));
+
+        // Behaves as if the sum was freshly encrypted
+        let d_fresh_sum = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt((clear_0 + clear_1) % modulus),
+            &streams,
+        );
+        let d_res = sks.add(&d_sum, &d_ctxt_2, &streams);
+        let d_fresh_res = sks.add(&d_fresh_sum, &d_ctxt_2, &streams);
+
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        let fresh_res: u64 = cks.decrypt(&d_fresh_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear_0 + clear_1 + clear_2) % modulus);
+        assert_eq!(res, fresh_res);
+    }
+}
+
+fn integer_max_noise_level<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let max_noise_level = sks.max_noise_level();
+    assert_eq!(max_noise_level, param.max_noise_level());
+
+    // Summing `max_noise_level` fresh ciphertexts with unchecked additions is allowed
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..max_noise_level.get())
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let mut d_sum =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clears[0]), &streams);
+    for &clear in &clears[1..] {
+        let d_ctxt =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+        sks.unchecked_add_assign(&mut d_sum, &d_ctxt, &streams);
+    }
+
+    assert!(d_sum
+        .as_ref()
+        .info
+        .blocks
+        .iter()
+        .all(|block| max_noise_level.validate(block.noise_level).is_ok()));
+
+    let res: u64 = cks.decrypt(&d_sum.to_radix_ciphertext(&streams));
+    assert_eq!(res, clears.iter().sum::<u64>() % modulus);
+}
+
+fn integer_debug_decrypt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for (clear_0, clear_1) in [(0, 0), (1, 2), (modulus - 1, 1), (modulus - 1, modulus - 1)] {
+        let d_ctxt_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ctxt_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+        assert_eq!(debug_decrypt(&d_ctxt_0, &cks, &streams), clear_0 as u128);
+
+        // Also works with non-empty carries
+        let d_sum = sks.unchecked_add(&d_ctxt_0, &d_ctxt_1, &streams);
+        let expected: u64 = cks.decrypt(&d_sum.to_radix_ciphertext(&streams));
+        assert_eq!(debug_decrypt(&d_sum, &cks, &streams), expected as u128);
+    }
+}
+
+fn integer_duplicate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let clear = rng.gen::<u64>() % modulus;
+    let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+    let mut d_copy = d_ctxt.duplicate(&streams);
+    let res: u64 = cks.decrypt(&d_copy.to_radix_ciphertext(&streams));
+    assert_eq!(res, clear);
+
+    // Mutating the copy must not affect the original
+    sks.scalar_add_assign(&mut d_copy, 1u64, &streams);
+    let res: u64 = cks.decrypt(&d_copy.to_radix_ciphertext(&streams));
+    assert_eq!(res, (clear + 1) % modulus);
+    let res: u64 = cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams));
+    assert_eq!(res, clear);
+}
+
+// Resolves the carry of each block by running the Hillis-Steele prefix sum
+// on the generate/propagate states
+fn resolve_carries(
+    sks: &CudaServerKey,
+    d_states: &CudaUnsignedRadixCiphertext,
+    streams: &CudaStreams,
+) -> CudaUnsignedRadixCiphertext {
+    let num_blocks = d_states.as_ref().d_blocks.lwe_ciphertext_count().0;
+    let lut = sks.generate_lookup_table_bivariate(
+        |block, previous| {
+            if block == 2 {
+                previous
+            } else {
+                block
+            }
+        },
+    );
+
+    let mut d_resolved = d_states.duplicate(streams);
+    let mut d_generates_or_propagates = d_states.duplicate(streams);
+    let mut output_slice = d_resolved
+        .as_mut()
+        .d_blocks
+        .0
+        .d_vec
+        .as_mut_slice(.., 0)
+        .unwrap();
+    let mut generates_or_propagates = d_generates_or_propagates
+        .as_mut()
+        .d_blocks
+        .0
+        .d_vec
+        .as_mut_slice(.., 0)
+        .unwrap();
+
+    unsafe {
+        match &sks.bootstrapping_key {
+            CudaBootstrappingKey::Classic(d_bsk) => {
+                compute_prefix_sum_hillis_steele_with_params_async(
+                    streams,
+                    &mut output_slice,
+                    &mut generates_or_propagates,
+                    lut.acc.acc.as_ref(),
+                    &d_bsk.d_vec,
+                    &sks.key_switching_key.d_vec,
+                    &sks.pbs_params(),
+                    num_blocks as u32,
+                    0u32,
+                );
+            }
+            CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
+                compute_prefix_sum_hillis_steele_with_params_async(
+                    streams,
+                    &mut output_slice,
+                    &mut generates_or_propagates,
+                    lut.acc.acc.as_ref(),
+                    &d_multibit_bsk.d_vec,
+                    &sks.key_switching_key.d_vec,
+                    &sks.pbs_params(),
+                    num_blocks as u32,
+                    0u32,
+                );
+            }
+        }
+    }
+    streams.synchronize();
+    d_resolved
+}
+
+fn integer_compute_generate_propagate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    // Full propagation chain and no carry cases, then random ones
+    let mut clears = vec![(modulus - 1, 1), (0, 0)];
+    clears.extend((0..4).map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus)));
+
+    for (clear_0, clear_1) in clears {
+        let d_ctxt_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ctxt_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+        let d_sum = sks.unchecked_add(&d_ctxt_0, &d_ctxt_1, &streams);
+
+        let d_states = sks.compute_generate_propagate(&d_sum, &streams);
+        let d_resolved = resolve_carries(&sks, &d_states, &streams);
+
+        let sum = d_sum.to_radix_ciphertext(&streams);
+        let resolved = d_resolved.to_radix_ciphertext(&streams);
+
+        // A block receives a carry when the resolved state of the previous block is 'generated'
+        let mut carry_in = 0;
+        let mut res = 0;
+        for (i, (block, state)) in sum.blocks.iter().zip(resolved.blocks.iter()).enumerate() {
+            let value = cks.as_ref().key.decrypt_message_and_carry(block);
+            res += ((value + carry_in) % message_modulus) * message_modulus.pow(i as u32);
+            carry_in = u64::from(cks.decrypt_one_block(state) == 1);
+        }
+
+        let mut d_propagated = d_sum.duplicate(&streams);
+        unsafe {
+            sks.full_propagate_assign_async(&mut d_propagated, &streams);
+        }
+        streams.synchronize();
+        let expected: u64 = cks.decrypt(&d_propagated.to_radix_ciphertext(&streams));
+
+        assert_eq!(expected, (clear_0 + clear_1) % modulus);
+        assert_eq!(
+            res, expected,
+            "Invalid propagation for {clear_0} + {clear_1}"
+        );
+    }
+}
+
+fn integer_add_zero_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, 4, &streams);
+
+    let d_empty: CudaUnsignedRadixCiphertext = sks.create_trivial_zero_radix(0, &streams);
+    let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(1u64), &streams);
+
+    for (lhs, rhs) in [(&d_empty, &d_ctxt), (&d_ctxt, &d_empty)] {
+        let message = panic_message(|| sks.add(lhs, rhs, &streams))
+            .expect("add should panic on a ciphertext with 0 blocks");
+        assert!(
+            message.contains("add requires at least one block"),
+            "Unexpected panic message: {message}"
+        );
+    }
+}
+
+fn integer_deserialize_from_cpu<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let cks = ClientKey::new(param);
+    let compressed_sks = CompressedServerKey::new_radix_compressed_server_key(&cks);
+
+    let sks = CudaServerKey::decompress_from_cpu(&compressed_sks, &streams);
+    let size_limit = 1 << 30;
+    let mut serialized_sks = vec![];
+    safe_serialize(&compressed_sks, &mut serialized_sks, size_limit).unwrap();
+    let deserialized_sks =
+        CudaServerKey::deserialize(&serialized_sks, size_limit, &streams).unwrap();
+
+    // Data larger than the limit is rejected
+    assert!(CudaServerKey::deserialize(&serialized_sks, 16, &streams).is_err());
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let d_ctxt_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt_radix(clear_0, NB_CTXT),
+            &streams,
+        );
+        let d_ctxt_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt_radix(clear_1, NB_CTXT),
+            &streams,
+        );
+
+        let d_res = sks.add(&d_ctxt_0, &d_ctxt_1, &streams);
+        let d_deserialized_res = deserialized_sks.add(&d_ctxt_0, &d_ctxt_1, &streams);
+
+        let res: u64 = cks.decrypt_radix(&d_res.to_radix_ciphertext(&streams));
+        let deserialized_res: u64 =
+            cks.decrypt_radix(&d_deserialized_res.to_radix_ciphertext(&streams));
+
+        assert_eq!(res, (clear_0 + clear_1) % modulus);
+        assert_eq!(deserialized_res, res);
+    }
+}
+
+fn integer_serialize_round_trip<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let size_limit = 1 << 30;
+    let serialized_sks = sks.serialize(size_limit).unwrap();
+    let deserialized_sks =
+        CudaServerKey::deserialize(&serialized_sks, size_limit, &streams).unwrap();
+
+    // The re-uploaded key serializes to the same data
+    assert_eq!(
+        deserialized_sks.serialize(size_limit).unwrap(),
+        serialized_sks
+    );
+    // Keys larger than the limit are rejected
+    assert!(sks.serialize(16).is_err());
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let d_ctxt_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ctxt_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+        let d_res = sks.add(&d_ctxt_0, &d_ctxt_1, &streams);
+        let d_deserialized_res = deserialized_sks.add(&d_ctxt_0, &d_ctxt_1, &streams);
+
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        let deserialized_res: u64 = cks.decrypt(&d_deserialized_res.to_radix_ciphertext(&streams));
+
+        assert_eq!(res, (clear_0 + clear_1) % modulus);
+        assert_eq!(deserialized_res, res);
+    }
+}
+
+fn integer_add_boolean<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    // Values just below a block boundary so that the carry crosses one or several blocks,
+    // the max value to check the wrap around, then random values
+    let mut clears = vec![
+        message_modulus - 1,
+        message_modulus.pow(2) - 1,
+        modulus - 1,
+        0,
+    ];
+    clears.extend((0..4).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in clears {
+        for boolean in [true, false] {
+            let d_ct =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+            let d_boolean =
+                CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(boolean), &streams);
+
+            let d_res = sks.add_boolean(&d_ct, &d_boolean, &streams);
+            let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+            assert_eq!(
+                res,
+                (clear + u64::from(boolean)) % modulus,
+                "Invalid result for {clear} + {boolean}"
+            );
+            assert!(d_res.block_carries_are_empty());
+
+            // Inputs with carries
+            let d_sum = sks.unchecked_add(&d_ct, &d_ct, &streams);
+            let mut d_res = d_sum.duplicate(&streams);
+            sks.add_boolean_assign(&mut d_res, &d_boolean, &streams);
+            let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+            assert_eq!(
+                res,
+                (2 * clear + u64::from(boolean)) % modulus,
+                "Invalid result for 2 * {clear} + {boolean}"
+            );
+        }
+    }
+}
+
+create_gpu_parameterized_test!(integer_add_on_borrowed_stream);
+
+fn integer_add_on_borrowed_stream<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let gpu_index = GpuIndex(0);
+
+    // Stream owned by the caller, e.g. an application with its own kernels
+    let raw_stream = unsafe { cuda_create_stream(gpu_index.0) };
+    let streams = unsafe { CudaStreams::from_raw(raw_stream, gpu_index) };
+
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let d_ct_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ct_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+        let mut d_res = d_ct_0.duplicate(&streams);
+        unsafe { sks.add_assign_async(&mut d_res, &d_ct_1, &streams) };
+        // The caller synchronizes its own stream
+        unsafe { cuda_synchronize_stream(raw_stream, gpu_index.0) };
+
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear_0 + clear_1) % modulus);
+    }
+
+    // Dropping the borrowed streams must leave the caller's stream alive
+    drop(streams);
+    unsafe {
+        cuda_synchronize_stream(raw_stream, gpu_index.0);
+        cuda_destroy_stream(raw_stream, gpu_index.0);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::gpu::CudaServerKey;
+use crate::shortint::parameters::*;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_bit_reverse_permute);
+
+fn integer_bit_reverse_permute<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    for len in [8u64, 16] {
+        let clears = (0..len).collect::<Vec<_>>();
+        let mut d_ctxts = sks.create_trivial_radix_vec(&clears, NB_CTXT, &streams);
+
+        let num_bits = len.ilog2();
+        let expected = clears
+            .iter()
+            .map(|i| i.reverse_bits() >> (u64::BITS - num_bits))
+            .collect::<Vec<_>>();
+
+        CudaServerKey::bit_reverse_permute(&mut d_ctxts);
+        let decs = d_ctxts
+            .iter()
+            .map(|d_ctxt| cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams)))
+            .collect::<Vec<u64>>();
+        assert_eq!(decs, expected);
+
+        // The permutation is an involution
+        CudaServerKey::bit_reverse_permute(&mut d_ctxts);
+        let decs = d_ctxts
+            .iter()
+            .map(|d_ctxt| cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams)))
+            .collect::<Vec<u64>>();
+        assert_eq!(decs, clears);
+    }
+}
@@ -1,12 +1,15 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_bitand_test, default_bitnot_test, default_bitor_test, default_bitxor_test,
     unchecked_bitand_test, unchecked_bitnot_test, unchecked_bitor_test, unchecked_bitxor_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_bitnot);
 create_gpu_parameterized_test!(integer_unchecked_bitand);
@@ -16,6 +19,7 @@ create_gpu_parameterized_test!(integer_bitnot);
 create_gpu_parameterized_test!(integer_bitand);
 create_gpu_parameterized_test!(integer_bitor);
 create_gpu_parameterized_test!(integer_bitxor);
+create_gpu_parameterized_test!(integer_bitop_max_concurrent_blocks);
 
 fn integer_unchecked_bitnot<P>(param: P)
 where
@@ -80,3 +84,67 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::bitxor);
     default_bitxor_test(param, executor);
 }
+
+fn integer_bitop_max_concurrent_blocks<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    const NB_CTXT: usize = 8;
+    const NB_TESTS: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, mut sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let d_ctxt_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_0, &streams);
+        let d_ctxt_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_1, &streams);
+
+        // Includes a limit that does not divide the number of blocks and one above it
+        for max_concurrent_blocks in [None, Some(1), Some(3), Some(NB_CTXT), Some(2 * NB_CTXT)] {
+            sks.set_max_concurrent_blocks(max_concurrent_blocks);
+
+            let results = [
+                (
+                    sks.bitand(&d_ctxt_0, &d_ctxt_1, &streams),
+                    clear_0 & clear_1,
+                ),
+                (sks.bitor(&d_ctxt_0, &d_ctxt_1, &streams), clear_0 | clear_1),
+                (
+                    sks.bitxor(&d_ctxt_0, &d_ctxt_1, &streams),
+                    clear_0 ^ clear_1,
+                ),
+                (
+                    sks.scalar_bitand(&d_ctxt_0, scalar, &streams),
+                    clear_0 & scalar,
+                ),
+                (
+                    sks.scalar_bitor(&d_ctxt_0, scalar, &streams),
+                    clear_0 | scalar,
+                ),
+                (
+                    sks.scalar_bitxor(&d_ctxt_0, scalar, &streams),
+                    clear_0 ^ scalar,
+                ),
+            ];
+
+            for (d_ct_res, expected) in results {
+                let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+                let dec_res: u64 = cks.decrypt(&ct_res);
+                assert_eq!(
+                    dec_res, expected,
+                    "Invalid result with max_concurrent_blocks: {max_concurrent_blocks:?}"
+                );
+            }
+        }
+    }
+}
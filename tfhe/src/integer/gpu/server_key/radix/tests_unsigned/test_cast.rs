@@ -0,0 +1,132 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 8;
+
+create_gpu_parameterized_test!(integer_checked_cast_to);
+create_gpu_parameterized_test!(integer_truncate_blocks);
+create_gpu_parameterized_test!(integer_extend_blocks);
+
+fn integer_checked_cast_to<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+
+    for target_num_blocks in [NB_CTXT / 2, NB_CTXT, NB_CTXT + 2] {
+        let target_modulus = message_modulus.pow(target_num_blocks.min(NB_CTXT) as u32);
+
+        // A value that fits in the target number of blocks, and one that does not (if narrowing)
+        let fitting = rng.gen::<u64>() % target_modulus;
+        let too_large = target_modulus + rng.gen::<u64>() % target_modulus;
+
+        for clear in [fitting, too_large] {
+            let ct = cks.as_ref().encrypt_radix(clear, NB_CTXT);
+            let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+            let (d_res, d_truncated) = sks.checked_cast_to(&d_ct, target_num_blocks, &streams);
+
+            let res = d_res.to_radix_ciphertext(&streams);
+            assert_eq!(res.blocks.len(), target_num_blocks);
+            let res: u64 = cks.decrypt(&res);
+            let truncated = cks.decrypt_bool(&d_truncated.to_boolean_block(&streams));
+
+            let max_value = message_modulus.pow(NB_CTXT as u32);
+            let clear = clear % max_value;
+            let expected = clear % target_modulus;
+            assert_eq!(
+                res, expected,
+                "Invalid cast of {clear} to {target_num_blocks} blocks"
+            );
+            assert_eq!(
+                truncated,
+                expected != clear,
+                "Invalid truncation flag for the cast of {clear} to {target_num_blocks} blocks"
+            );
+        }
+    }
+}
+
+fn integer_truncate_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, _sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+
+    for target_num_blocks in [1, NB_CTXT / 2, NB_CTXT, NB_CTXT + 2] {
+        let kept_num_blocks = target_num_blocks.min(NB_CTXT);
+        // The high blocks encrypt 0
+        let clear = rng.gen::<u64>() % message_modulus.pow(kept_num_blocks as u32);
+
+        let ct = cks.as_ref().encrypt_radix(clear, NB_CTXT);
+        let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        d_ct.truncate_blocks(target_num_blocks);
+        assert_eq!(
+            d_ct.as_ref().d_blocks.lwe_ciphertext_count().0,
+            kept_num_blocks
+        );
+        assert_eq!(d_ct.as_ref().info.blocks.len(), kept_num_blocks);
+
+        let res = d_ct.to_radix_ciphertext(&streams);
+        assert_eq!(res.blocks.len(), kept_num_blocks);
+        let res: u64 = cks.decrypt(&res);
+        assert_eq!(
+            res, clear,
+            "Invalid truncation of {clear} to {target_num_blocks} blocks"
+        );
+    }
+}
+
+fn integer_extend_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    // The sum does not fit in NB_CTXT blocks
+    let clear_0 = modulus - 1 - rng.gen::<u64>() % (modulus / 2);
+    let clear_1 = modulus - 1 - rng.gen::<u64>() % (modulus / 2);
+
+    let mut d_ct_0 =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+    let mut d_ct_1 =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+    let d_res = sks.add(&d_ct_0, &d_ct_1, &streams);
+    let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+    assert_eq!(res, (clear_0 + clear_1) % modulus);
+
+    d_ct_0.extend_blocks(1, &streams);
+    d_ct_1.extend_blocks(1, &streams);
+    assert_eq!(
+        d_ct_0.as_ref().d_blocks.lwe_ciphertext_count().0,
+        NB_CTXT + 1
+    );
+    assert_eq!(d_ct_0.as_ref().info.blocks.len(), NB_CTXT + 1);
+
+    let d_res = sks.add(&d_ct_0, &d_ct_1, &streams);
+    let res = d_res.to_radix_ciphertext(&streams);
+    assert_eq!(res.blocks.len(), NB_CTXT + 1);
+    let res: u64 = cks.as_ref().decrypt_radix(&res);
+    assert_eq!(res, clear_0 + clear_1);
+}
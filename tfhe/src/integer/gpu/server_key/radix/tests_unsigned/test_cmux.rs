@@ -1,11 +1,19 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
 use crate::integer::gpu::CudaServerKey;
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_cmux::default_if_then_else_test;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_if_then_else);
+create_gpu_parameterized_test!(integer_cmux_assign);
+create_gpu_parameterized_test!(integer_switch);
+create_gpu_parameterized_test!(integer_mux);
 
 fn integer_if_then_else<P>(param: P)
 where
@@ -14,3 +22,124 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::if_then_else);
     default_if_then_else_test(param, executor);
 }
+
+fn integer_cmux_assign<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for condition in [true, false] {
+        let clear_dst = rng.gen::<u64>() % modulus;
+        let clear_src = rng.gen::<u64>() % modulus;
+
+        let mut d_dst =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_dst), &streams);
+        let d_src =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_src), &streams);
+        let d_cond = CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(condition), &streams);
+
+        sks.cmux_assign(&mut d_dst, &d_cond, &d_src, &streams);
+
+        let res: u64 = cks.decrypt(&d_dst.to_radix_ciphertext(&streams));
+        let expected = if condition { clear_src } else { clear_dst };
+        assert_eq!(res, expected);
+    }
+}
+
+fn integer_switch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let encrypt = |clear: u64| {
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+    };
+
+    // The last case is a duplicate of the second one, which must take precedence
+    let clear_cases = [3u64, 17, 42, 17];
+    let clear_values = (0..clear_cases.len())
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let clear_default = rng.gen::<u64>() % modulus;
+
+    let cases = clear_cases
+        .iter()
+        .zip(clear_values.iter())
+        .map(|(&case, &value)| (case, encrypt(value)))
+        .collect::<Vec<_>>();
+    let d_default = encrypt(clear_default);
+
+    for clear_selector in clear_cases.into_iter().chain([100]) {
+        let d_selector = encrypt(clear_selector);
+
+        let d_res = sks.switch(&d_selector, &cases, &d_default, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+        let expected = clear_cases
+            .iter()
+            .position(|&case| case == clear_selector)
+            .map_or(clear_default, |i| clear_values[i]);
+        assert_eq!(
+            res, expected,
+            "Invalid result for selector {clear_selector}"
+        );
+    }
+}
+
+fn integer_mux<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let encrypt = |clear: u64| {
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+    };
+
+    for num_values in [4u64, 8] {
+        let clear_values = (0..num_values)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let values = clear_values
+            .iter()
+            .map(|&value| encrypt(value))
+            .collect::<Vec<_>>();
+
+        // The last selector is out of range
+        for selector in 0..=num_values {
+            let d_selector = encrypt(selector);
+
+            let d_res = sks.mux(&d_selector, &values, &streams);
+            let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+            let expected = clear_values.get(selector as usize).copied().unwrap_or(0);
+            assert_eq!(
+                res, expected,
+                "Invalid result for {num_values}-way mux with selector {selector}"
+            );
+        }
+    }
+}
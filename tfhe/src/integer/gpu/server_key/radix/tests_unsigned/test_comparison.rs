@@ -1,12 +1,16 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_comparison::{
     test_default_function, test_default_minmax, test_unchecked_function, test_unchecked_minmax,
 };
 use crate::integer::U256;
 use crate::shortint::parameters::*;
+use rand::Rng;
+
 /// This macro generates the tests for a given comparison fn
 ///
 /// All our comparison function have 2 variants:
@@ -113,3 +117,175 @@ define_gpu_comparison_test_functions!(lt, U256);
 define_gpu_comparison_test_functions!(le, U256);
 define_gpu_comparison_test_functions!(gt, U256);
 define_gpu_comparison_test_functions!(ge, U256);
+
+create_gpu_parameterized_test!(integer_eq_trivial);
+
+fn integer_eq_trivial<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let nonzero = rng.gen_range(1..modulus);
+    for (clear, trivial) in [(0, 0), (nonzero, 0), (0, nonzero), (nonzero, nonzero)] {
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+        let d_trivial: CudaUnsignedRadixCiphertext =
+            sks.create_trivial_radix(trivial, NB_CTXT, &streams);
+
+        let d_res = sks.eq_trivial(&d_ct, &d_trivial, &streams);
+        let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+        assert_eq!(
+            res,
+            clear == trivial,
+            "Invalid result for {clear} == {trivial}"
+        );
+    }
+
+    // A non trivial operand falls back to the regular comparison
+    let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(nonzero), &streams);
+    let d_res = sks.eq_trivial(&d_ct, &d_ct, &streams);
+    assert!(cks.decrypt_bool(&d_res.to_boolean_block(&streams)));
+}
+
+create_gpu_parameterized_test!(integer_cmp);
+
+fn integer_cmp<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for i in 0..6 {
+        let clear_lhs = rng.gen::<u64>() % modulus;
+        // Make sure the equal case is covered
+        let clear_rhs = if i % 3 == 0 {
+            clear_lhs
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let d_lhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_lhs), &streams);
+        let d_rhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_rhs), &streams);
+
+        let d_res = sks.cmp(&d_lhs, &d_rhs, &streams);
+        let res: i64 = cks.decrypt_signed(&d_res.to_signed_radix_ciphertext(&streams));
+
+        let expected = clear_lhs.cmp(&clear_rhs) as i64;
+        assert_eq!(
+            res, expected,
+            "Invalid result for cmp({clear_lhs}, {clear_rhs})"
+        );
+    }
+}
+
+create_gpu_parameterized_test!(integer_min_max);
+
+fn integer_min_max<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for i in 0..6 {
+        let clear_lhs = rng.gen::<u64>() % modulus;
+        // Make sure the equal case is covered
+        let clear_rhs = if i % 3 == 0 {
+            clear_lhs
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let d_lhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_lhs), &streams);
+        let d_rhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_rhs), &streams);
+
+        let (d_min, d_max) = sks.min_max(&d_lhs, &d_rhs, &streams);
+        let min: u64 = cks.decrypt(&d_min.to_radix_ciphertext(&streams));
+        let max: u64 = cks.decrypt(&d_max.to_radix_ciphertext(&streams));
+
+        assert_eq!(
+            min,
+            clear_lhs.min(clear_rhs),
+            "Invalid min for ({clear_lhs}, {clear_rhs})"
+        );
+        assert_eq!(
+            max,
+            clear_lhs.max(clear_rhs),
+            "Invalid max for ({clear_lhs}, {clear_rhs})"
+        );
+    }
+}
+
+create_gpu_parameterized_test!(integer_min_max_many);
+
+fn integer_min_max_many<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let empty: [CudaUnsignedRadixCiphertext; 0] = [];
+    assert!(sks.unchecked_min_many(&empty, &streams).is_none());
+    assert!(sks.unchecked_max_many(&empty, &streams).is_none());
+
+    // Powers of two and odd lengths exercise both shapes of the reduction tree
+    for len in [1, 2, 5, 8] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let d_cts = clears
+            .iter()
+            .map(|&clear| {
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+            })
+            .collect::<Vec<_>>();
+
+        let d_min = sks.unchecked_min_many(&d_cts, &streams).unwrap();
+        let d_max = sks.unchecked_max_many(&d_cts, &streams).unwrap();
+        let min: u64 = cks.decrypt(&d_min.to_radix_ciphertext(&streams));
+        let max: u64 = cks.decrypt(&d_max.to_radix_ciphertext(&streams));
+
+        assert_eq!(
+            min,
+            *clears.iter().min().unwrap(),
+            "Invalid min of {clears:?}"
+        );
+        assert_eq!(
+            max,
+            *clears.iter().max().unwrap(),
+            "Invalid max of {clears:?}"
+        );
+    }
+}
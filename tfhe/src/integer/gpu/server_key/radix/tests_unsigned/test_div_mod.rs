@@ -1,3 +1,6 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
@@ -6,10 +9,12 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::test_div_mod::{
     default_div_rem_test, default_div_test, default_rem_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_div);
 create_gpu_parameterized_test!(integer_div_rem);
 create_gpu_parameterized_test!(integer_rem);
+create_gpu_parameterized_test!(integer_div_rem_vec);
 
 fn integer_div<P>(param: P)
 where
@@ -34,3 +39,62 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::rem);
     default_rem_test(param, executor);
 }
+
+fn integer_div_rem_vec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let mut clear_numerators = (0..8)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let mut clear_divisors = (0..8)
+        .map(|_| rng.gen_range(1..modulus))
+        .collect::<Vec<_>>();
+    // Divide by one
+    clear_divisors[0] = 1;
+    // Divisor equal to the dividend
+    clear_numerators[1] = clear_divisors[1];
+
+    let encrypt = |clears: &[u64]| {
+        clears
+            .iter()
+            .map(|&clear| {
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+            })
+            .collect::<Vec<_>>()
+    };
+    let d_numerators = encrypt(&clear_numerators);
+    let d_divisors = encrypt(&clear_divisors);
+
+    let (d_quotients, d_remainders) = sks.div_rem_vec(&d_numerators, &d_divisors, &streams);
+    assert_eq!(d_quotients.len(), clear_numerators.len());
+    assert_eq!(d_remainders.len(), clear_numerators.len());
+
+    for (i, (clear_numerator, clear_divisor)) in clear_numerators
+        .iter()
+        .zip(clear_divisors.iter())
+        .enumerate()
+    {
+        let q: u64 = cks.decrypt(&d_quotients[i].to_radix_ciphertext(&streams));
+        let r: u64 = cks.decrypt(&d_remainders[i].to_radix_ciphertext(&streams));
+        assert_eq!(
+            q,
+            clear_numerator / clear_divisor,
+            "Invalid quotient for {clear_numerator} / {clear_divisor}"
+        );
+        assert_eq!(
+            r,
+            clear_numerator % clear_divisor,
+            "Invalid remainder for {clear_numerator} % {clear_divisor}"
+        );
+    }
+}
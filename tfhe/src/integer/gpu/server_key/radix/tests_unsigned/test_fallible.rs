@@ -0,0 +1,108 @@
+use crate::core_crypto::gpu::vec::GpuIndex;
+use crate::core_crypto::gpu::{CudaError, CudaStreams};
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
+use crate::shortint::parameters::*;
+use rand::Rng;
+use tfhe_cuda_backend::cuda_bind::cuda_get_number_of_gpus;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_fallible_ops);
+create_gpu_parameterized_test!(integer_same_gpu);
+
+fn integer_fallible_ops<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_single_gpu(GpuIndex(0));
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..2 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let d_ct_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ct_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+        let d_res = sks.try_add(&d_ct_0, &d_ct_1, &streams).unwrap();
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear_0.wrapping_add(clear_1) % modulus);
+
+        let d_res = sks.try_sub(&d_ct_0, &d_ct_1, &streams).unwrap();
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear_0.wrapping_sub(clear_1) % modulus);
+
+        let d_res = sks.try_mul(&d_ct_0, &d_ct_1, &streams).unwrap();
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear_0.wrapping_mul(clear_1) % modulus);
+
+        let d_res = sks.try_lt(&d_ct_0, &d_ct_1, &streams).unwrap();
+        let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+        assert_eq!(res, clear_0 < clear_1);
+    }
+
+    // The partial products of a multiplication of such ciphertexts cannot fit on a GPU
+    let num_blocks = 1 << 12;
+    let d_huge: CudaUnsignedRadixCiphertext = sks.create_trivial_zero_radix(num_blocks, &streams);
+    let result = sks.try_mul(&d_huge, &d_huge, &streams);
+    assert!(
+        matches!(result, Err(CudaError::OutOfMemory { .. })),
+        "Expected an out of memory error"
+    );
+
+    if unsafe { cuda_get_number_of_gpus() } >= 2 {
+        let other_streams = CudaStreams::new_single_gpu(GpuIndex(1));
+        let d_ct_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u64), &streams);
+        let d_ct_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u64), &other_streams);
+
+        let result = sks.try_eq(&d_ct_0, &d_ct_1, &streams);
+        assert!(
+            matches!(
+                result,
+                Err(CudaError::GpuMismatch {
+                    expected: GpuIndex(0),
+                    got: GpuIndex(1)
+                })
+            ),
+            "Expected a GPU mismatch error"
+        );
+    }
+}
+
+fn integer_same_gpu<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let streams = CudaStreams::new_single_gpu(GpuIndex(0));
+    let (cks, _sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let d_ct_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u64), &streams);
+    let d_ct_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(1u64), &streams);
+    assert!(CudaServerKey::same_gpu(&d_ct_0, &d_ct_1));
+    assert!(CudaServerKey::require_same_gpu(&d_ct_0, &d_ct_1).is_ok());
+
+    if unsafe { cuda_get_number_of_gpus() } >= 2 {
+        let other_streams = CudaStreams::new_single_gpu(GpuIndex(1));
+        let d_ct_2 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(2u64), &other_streams);
+
+        assert!(!CudaServerKey::same_gpu(&d_ct_0, &d_ct_2));
+        assert!(matches!(
+            CudaServerKey::require_same_gpu(&d_ct_0, &d_ct_2),
+            Err(CudaError::GpuMismatch {
+                expected: GpuIndex(0),
+                got: GpuIndex(1)
+            })
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_ELEMENTS: usize = 8;
+const NUM_BINS: u32 = 8;
+
+create_gpu_parameterized_test!(integer_histogram);
+
+fn integer_histogram<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+
+    // u8 values
+    let nb_ctxt = 8 / param.message_modulus().0.ilog2() as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // Some values are out of the bins range and must be dropped
+    let clears = (0..NB_ELEMENTS)
+        .map(|_| rng.gen_range(0..NUM_BINS as u64 + 4))
+        .collect::<Vec<_>>();
+
+    let d_cts = clears
+        .iter()
+        .map(|&clear| {
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+        })
+        .collect::<Vec<_>>();
+
+    let d_bins = sks.histogram(&d_cts, NUM_BINS, &streams);
+    assert_eq!(d_bins.len(), NUM_BINS as usize);
+
+    for (bin, d_bin) in d_bins.iter().enumerate() {
+        let count: u64 = cks.decrypt(&d_bin.to_radix_ciphertext(&streams));
+        let expected = clears.iter().filter(|&&clear| clear == bin as u64).count() as u64;
+        assert_eq!(
+            count, expected,
+            "Invalid count for bin {bin}, values: {clears:?}"
+        );
+    }
+}
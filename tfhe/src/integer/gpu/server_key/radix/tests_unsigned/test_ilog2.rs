@@ -1,12 +1,16 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_gpu, gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_ilog2::{
     default_checked_ilog2_test, default_ilog2_test, default_leading_ones_test,
     default_leading_zeros_test, default_trailing_ones_test, default_trailing_zeros_test,
 };
+use crate::integer::ServerKey;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_default_trailing_zeros);
 create_gpu_parameterized_test!(integer_default_trailing_ones);
@@ -14,6 +18,15 @@ create_gpu_parameterized_test!(integer_default_leading_zeros);
 create_gpu_parameterized_test!(integer_default_leading_ones);
 create_gpu_parameterized_test!(integer_default_ilog2);
 create_gpu_parameterized_test!(integer_default_checked_ilog2);
+create_gpu_parameterized_test!(integer_has_at_least_k_bits_set);
+create_gpu_parameterized_test!(integer_count_ones);
+create_gpu_parameterized_test!(integer_ilog);
+create_gpu_parameterized_test!(integer_leading_trailing_zeros_vs_cpu);
+create_gpu_parameterized_test!(integer_ilog2_extreme_values);
+// A single bit ciphertext needs 1 bit messages
+create_gpu_parameterized_test!(integer_ilog2_single_bit {
+    V1_0_PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_1_CARRY_1_KS_PBS_GAUSSIAN_2M64
+});
 
 fn integer_default_trailing_zeros<P>(param: P)
 where
@@ -62,3 +75,207 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::checked_ilog2);
     default_checked_ilog2_test(param, executor);
 }
+
+fn integer_has_at_least_k_bits_set<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let num_blocks = 32 / param.message_modulus().0.ilog2() as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // Edge cases, then random values
+    let mut clears = vec![0u32, u32::MAX, 1];
+    clears.extend((0..3).map(|_| rng.gen::<u32>()));
+
+    for clear in clears {
+        let d_ctxt =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        for k in [0, 1, clear.count_ones(), clear.count_ones() + 1, 16, 32, 33] {
+            let d_res = sks.has_at_least_k_bits_set(&d_ctxt, k, &streams);
+            let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+
+            assert_eq!(
+                res,
+                clear.count_ones() >= k,
+                "Invalid result for {clear:#x} with k = {k}"
+            );
+        }
+    }
+}
+
+fn integer_count_ones<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let num_blocks = 32 / param.message_modulus().0.ilog2() as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // Edge cases, then random values
+    let mut clears = vec![0u32, u32::MAX, 1, 1 << 31];
+    clears.extend((0..4).map(|_| rng.gen::<u32>()));
+
+    for clear in clears {
+        let d_ctxt =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        let d_res = sks.count_ones(&d_ctxt, &streams);
+        let res: u32 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear.count_ones(), "Invalid result for {clear:#x}");
+
+        // Add a value so that the input has non-empty carries
+        let d_ctxt = sks.unchecked_scalar_add(&d_ctxt, 1u32, &streams);
+        let expected = clear.wrapping_add(1).count_ones();
+        let d_res = sks.count_ones(&d_ctxt, &streams);
+        let res: u32 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, expected, "Invalid result for {clear:#x} + 1");
+    }
+}
+
+fn integer_ilog<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let num_blocks = 16 / param.message_modulus().0.ilog2() as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    for (clear, base) in [
+        (999u16, 10),
+        (1000, 10),
+        (1, 10),
+        (u16::MAX, 10),
+        (81, 3),
+        (80, 3),
+    ] {
+        let d_ctxt =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        let d_res = sks.ilog(&d_ctxt, u32::from(base), &streams);
+        let res: u32 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            res,
+            clear.ilog(base),
+            "Invalid ilog of {clear} in base {base}"
+        );
+    }
+
+    // The clear ilog panics on 0, the encrypted one returns 0
+    let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u16), &streams);
+    let d_res = sks.ilog(&d_ctxt, 10, &streams);
+    let res: u32 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+    assert_eq!(res, 0);
+}
+
+fn integer_leading_trailing_zeros_vs_cpu<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_gpu(param, &streams);
+    let cpu_sks = ServerKey::new_radix_server_key(&cks);
+
+    let mut rng = rand::thread_rng();
+    let num_bits_in_message = param.message_modulus().0.ilog2();
+
+    for num_bits in [8u32, 16, 32, 64] {
+        let num_blocks = num_bits.div_ceil(num_bits_in_message) as usize;
+        let modulus_mask = u64::MAX >> (u64::BITS - num_bits);
+
+        // Edge cases, an all-zero input must give the full bit width, then random values
+        let mut clears = vec![0u64, 1, modulus_mask, 1 << (num_bits - 1)];
+        clears.extend((0..2).map(|_| rng.gen::<u64>() & modulus_mask));
+
+        for clear in clears {
+            let ctxt = cks.encrypt_radix(clear, num_blocks);
+            let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+            let d_leading = sks.leading_zeros(&d_ctxt, &streams);
+            let leading: u32 = cks.decrypt_radix(&d_leading.to_radix_ciphertext(&streams));
+            let expected: u32 = cks.decrypt_radix(&cpu_sks.leading_zeros_parallelized(&ctxt));
+            assert_eq!(
+                leading, expected,
+                "Invalid leading zeros for {clear:#x} on {num_bits} bits"
+            );
+            assert_eq!(leading, clear.leading_zeros() - (u64::BITS - num_bits));
+
+            let d_trailing = sks.trailing_zeros(&d_ctxt, &streams);
+            let trailing: u32 = cks.decrypt_radix(&d_trailing.to_radix_ciphertext(&streams));
+            let expected: u32 = cks.decrypt_radix(&cpu_sks.trailing_zeros_parallelized(&ctxt));
+            assert_eq!(
+                trailing, expected,
+                "Invalid trailing zeros for {clear:#x} on {num_bits} bits"
+            );
+            assert_eq!(trailing, clear.trailing_zeros().min(num_bits));
+        }
+    }
+}
+
+fn integer_ilog2_extreme_values<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_gpu(param, &streams);
+    let num_bits_in_message = param.message_modulus().0.ilog2();
+
+    for num_bits in [8u32, 16, 32, 64] {
+        let num_blocks = num_bits.div_ceil(num_bits_in_message) as usize;
+        let max_value = u64::MAX >> (u64::BITS - num_bits);
+
+        // The output must be wide enough to hold the ilog2 of the largest input
+        for clear in [1u64, max_value, 1 << (num_bits - 1)] {
+            let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                &cks.encrypt_radix(clear, num_blocks),
+                &streams,
+            );
+
+            let (d_res, d_is_ok) = sks.checked_ilog2(&d_ctxt, &streams);
+            let res: u32 = cks.decrypt_radix(&d_res.to_radix_ciphertext(&streams));
+            assert_eq!(res, clear.ilog2(), "Invalid ilog2 of {clear:#x}");
+            assert!(cks.decrypt_bool(&d_is_ok.to_boolean_block(&streams)));
+        }
+
+        // The ilog2 of zero is undefined, which the checked variant reports
+        let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt_radix(0u64, num_blocks),
+            &streams,
+        );
+        let (_, d_is_ok) = sks.checked_ilog2(&d_ctxt, &streams);
+        assert!(!cks.decrypt_bool(&d_is_ok.to_boolean_block(&streams)));
+    }
+}
+
+fn integer_ilog2_single_bit<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_gpu(param, &streams);
+    assert_eq!(param.message_modulus().0, 2);
+
+    // One block of a 1 bit message is a single bit ciphertext
+    let d_ctxt =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(1u64, 1), &streams);
+    let (d_res, d_is_ok) = sks.checked_ilog2(&d_ctxt, &streams);
+    let res: u32 = cks.decrypt_radix(&d_res.to_radix_ciphertext(&streams));
+    assert_eq!(res, 0);
+    assert!(cks.decrypt_bool(&d_is_ok.to_boolean_block(&streams)));
+
+    let d_ctxt =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt_radix(0u64, 1), &streams);
+    let (_, d_is_ok) = sks.checked_ilog2(&d_ctxt, &streams);
+    assert!(!cks.decrypt_bool(&d_is_ok.to_boolean_block(&streams)));
+}
@@ -0,0 +1,80 @@
+use crate::core_crypto::gpu::vec::GpuIndex;
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use tfhe_cuda_backend::cuda_bind::cuda_get_number_of_gpus;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_auto_migrate);
+
+fn integer_auto_migrate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    // Migration needs a second GPU to copy from
+    if unsafe { cuda_get_number_of_gpus() } < 2 {
+        return;
+    }
+
+    let param = param.into();
+    let streams = CudaStreams::new_single_gpu(GpuIndex(0));
+    let other_streams = CudaStreams::new_single_gpu(GpuIndex(1));
+    let (cks, mut sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+    sks.set_auto_migrate(true);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        // Both operands are produced on the other GPU
+        let d_ct_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt(clear_0),
+            &other_streams,
+        );
+        let d_ct_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt(clear_1),
+            &other_streams,
+        );
+
+        let d_res = sks.unchecked_add(&d_ct_0, &d_ct_1, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear_0 + clear_1) % modulus);
+
+        let d_res = sks.unchecked_gt(&d_ct_0, &d_ct_1, &streams);
+        let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+        assert_eq!(res, clear_0 > clear_1);
+
+        let d_res = sks.unchecked_max(&d_ct_0, &d_ct_1, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear_0.max(clear_1));
+
+        let d_res = sks.try_mul(&d_ct_0, &d_ct_1, &streams).unwrap();
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear_0 * clear_1) % modulus);
+
+        // The left operand is replaced by its copy, the original is released once copied
+        let mut d_acc = d_ct_0.duplicate(&other_streams);
+        sks.unchecked_add_assign(&mut d_acc, &d_ct_1, &streams);
+        let res: u64 = cks.decrypt(&d_acc.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear_0 + clear_1) % modulus);
+    }
+
+    // Without migration, the fallible versions report the mismatch
+    sks.set_auto_migrate(false);
+    let d_ct_0 =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(1u64), &other_streams);
+    let d_ct_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(2u64), &streams);
+    assert!(sks.try_add(&d_ct_0, &d_ct_1, &streams).is_err());
+    assert!(sks.try_gt(&d_ct_0, &d_ct_1, &streams).is_err());
+    let mut d_acc = d_ct_1.duplicate(&streams);
+    assert!(sks
+        .try_unchecked_add_assign(&mut d_acc, &d_ct_0, &streams)
+        .is_err());
+}
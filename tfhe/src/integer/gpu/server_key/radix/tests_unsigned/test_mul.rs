@@ -1,14 +1,21 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
-    create_gpu_parameterized_test, GpuFunctionExecutor,
+    create_gpu_parameterized_test, panic_message, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_gpu, gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_mul_test, unchecked_mul_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_mul);
 create_gpu_parameterized_test!(integer_mul);
+create_gpu_parameterized_test!(integer_saturating_mul_u16);
+create_gpu_parameterized_test!(integer_mul_zero_blocks);
+create_gpu_parameterized_test!(integer_mac_matrix_vector);
+create_gpu_parameterized_test!(integer_matvec);
 
 fn integer_unchecked_mul<P>(param: P)
 where
@@ -25,3 +32,180 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::mul);
     default_mul_test(param, executor);
 }
+
+fn integer_saturating_mul_u16<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let num_blocks = (u16::BITS / param.message_modulus().0.ilog2()) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // Overflowing and non overflowing products, then random ones
+    let mut clears = vec![
+        (u16::MAX, 2u16),
+        (300, 400),
+        (255, 257),
+        (256, 256),
+        (255, 255),
+        (u16::MAX, 1),
+        (0, u16::MAX),
+    ];
+    clears.extend((0..4).map(|_| (rng.gen::<u16>(), rng.gen::<u16>())));
+    clears.extend((0..4).map(|_| (rng.gen::<u8>() as u16, rng.gen::<u8>() as u16)));
+
+    for (clear_0, clear_1) in clears {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let d_ctxt_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_0, &streams);
+        let d_ctxt_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_1, &streams);
+
+        let d_ct_res = sks.saturating_mul(&d_ctxt_0, &d_ctxt_1, &streams);
+        let dec_res: u16 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            dec_res,
+            clear_0.saturating_mul(clear_1),
+            "Invalid result for {clear_0} * {clear_1}"
+        );
+
+        let (d_ct_res, d_overflowed) = sks.unsigned_overflowing_mul(&d_ctxt_0, &d_ctxt_1, &streams);
+        let dec_res: u16 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+        let dec_overflowed = cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams));
+        assert_eq!((dec_res, dec_overflowed), clear_0.overflowing_mul(clear_1));
+    }
+}
+
+fn integer_mul_zero_blocks<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, 4, &streams);
+
+    let d_empty: CudaUnsignedRadixCiphertext = sks.create_trivial_zero_radix(0, &streams);
+    let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(1u64), &streams);
+
+    for (lhs, rhs) in [(&d_empty, &d_ctxt), (&d_ctxt, &d_empty)] {
+        let message = panic_message(|| sks.mul(lhs, rhs, &streams))
+            .expect("mul should panic on a ciphertext with 0 blocks");
+        assert!(
+            message.contains("mul requires at least one block"),
+            "Unexpected panic message: {message}"
+        );
+    }
+}
+
+fn integer_mac_matrix_vector<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let num_bits_in_message = param.message_modulus().0.ilog2();
+    let operand_num_blocks = (u8::BITS / num_bits_in_message) as usize;
+    let acc_num_blocks = (u16::BITS / num_bits_in_message) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_gpu(param, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    let matrix: [[u8; 3]; 2] = [[rng.gen(), rng.gen(), rng.gen()], [255, 255, 255]];
+    let vector: [u8; 3] = [rng.gen(), rng.gen(), 255];
+
+    let encrypt = |clear: u8| {
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+            &cks.encrypt_radix(clear, operand_num_blocks),
+            &streams,
+        )
+    };
+    let d_vector = vector.map(encrypt);
+
+    for row in matrix {
+        let expected = row
+            .iter()
+            .zip(vector.iter())
+            .map(|(&a, &b)| u16::from(a) * u16::from(b))
+            .fold(0u16, u16::wrapping_add);
+
+        let d_row = row.map(encrypt);
+
+        // Carries are propagated after each product
+        let mut d_acc: CudaUnsignedRadixCiphertext =
+            sks.create_trivial_zero_radix(acc_num_blocks, &streams);
+        for (d_a, d_b) in d_row.iter().zip(d_vector.iter()) {
+            sks.mac(&mut d_acc, d_a, d_b, true, &streams);
+            assert!(d_acc.block_carries_are_empty());
+        }
+        let res: u16 = cks.decrypt_radix(&d_acc.to_radix_ciphertext(&streams));
+        assert_eq!(res, expected, "Invalid mac result for row {row:?}");
+
+        // Carries are propagated once, with the last product
+        let mut d_acc: CudaUnsignedRadixCiphertext =
+            sks.create_trivial_zero_radix(acc_num_blocks, &streams);
+        let (d_last_a, d_row_init) = d_row.split_last().unwrap();
+        let (d_last_b, d_vector_init) = d_vector.split_last().unwrap();
+        for (d_a, d_b) in d_row_init.iter().zip(d_vector_init.iter()) {
+            sks.mac(&mut d_acc, d_a, d_b, false, &streams);
+            assert!(!d_acc.block_carries_are_empty());
+        }
+        sks.mac(&mut d_acc, d_last_a, d_last_b, true, &streams);
+        assert!(d_acc.block_carries_are_empty());
+        let res: u16 = cks.decrypt_radix(&d_acc.to_radix_ciphertext(&streams));
+        assert_eq!(
+            res, expected,
+            "Invalid mac result with carries left for row {row:?}"
+        );
+    }
+}
+
+fn integer_matvec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let num_blocks = (u8::BITS / param.message_modulus().0.ilog2()) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    let matrix: [[u8; 3]; 3] = [
+        [rng.gen(), rng.gen(), rng.gen()],
+        [255, 255, 255],
+        [0, 1, rng.gen()],
+    ];
+    let vector: [u8; 3] = [rng.gen(), rng.gen(), 255];
+
+    let encrypt = |clear: u8| {
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+    };
+    let d_matrix = matrix.map(|row| row.map(encrypt));
+    let d_vector = vector.map(encrypt);
+
+    let d_result = sks.matvec(&d_matrix, &d_vector, &streams);
+
+    // The outputs are wide enough to hold the exact dot products
+    assert_eq!(d_result.len(), matrix.len());
+    for (row, d_res) in matrix.iter().zip(d_result.iter()) {
+        let expected: u64 = row
+            .iter()
+            .zip(vector.iter())
+            .map(|(&a, &b)| u64::from(a) * u64::from(b))
+            .sum();
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, expected, "Invalid result for row {row:?}");
+    }
+
+    let message = panic_message(|| sks.matvec(&d_matrix, &d_vector[..2], &streams))
+        .expect("matvec should panic on mismatched dimensions");
+    assert!(
+        message.contains("columns"),
+        "Unexpected panic message: {message}"
+    );
+}
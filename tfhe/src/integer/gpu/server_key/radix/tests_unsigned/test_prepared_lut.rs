@@ -0,0 +1,114 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_apply_prepared_lut);
+create_gpu_parameterized_test!(integer_apply_bivariate_lookup_table);
+
+fn integer_apply_prepared_lut<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    let square = |x: u64| (x * x) % message_modulus;
+    let lut = sks.generate_lookup_table(square);
+    let prepared_lut = sks.prepare_lut(&lut, NB_CTXT, &streams);
+
+    for _ in 0..4 {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        let d_prepared_res = sks.apply_prepared_lut(&d_ct, &prepared_lut, &streams);
+
+        // Per-call version, uploading the lut each time
+        let mut d_res = d_ct.duplicate(&streams);
+        unsafe {
+            sks.apply_lookup_table_async(d_res.as_mut(), d_ct.as_ref(), &lut, 0..NB_CTXT, &streams);
+        }
+        streams.synchronize();
+
+        let prepared_res: u64 = cks.decrypt(&d_prepared_res.to_radix_ciphertext(&streams));
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+        let expected = (0..NB_CTXT as u32).fold(0, |acc, i| {
+            let block = (clear / message_modulus.pow(i)) % message_modulus;
+            acc + square(block) * message_modulus.pow(i)
+        });
+        assert_eq!(prepared_res, expected);
+        assert_eq!(prepared_res, res);
+    }
+
+    // Fewer blocks than the lut was prepared for
+    let clear = rng.gen::<u64>() % message_modulus;
+    let ct = cks.as_ref().encrypt_radix(clear, 1);
+    let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+    let d_res = sks.apply_prepared_lut(&d_ct, &prepared_lut, &streams);
+    let res: u64 = cks
+        .as_ref()
+        .decrypt_radix(&d_res.to_radix_ciphertext(&streams));
+    assert_eq!(res, square(clear));
+}
+
+fn integer_apply_bivariate_lookup_table<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let message_modulus = param.message_modulus().0;
+    let modulus = message_modulus.pow(NB_CTXT as u32);
+
+    let block_wise_min = |lhs: u64, rhs: u64| {
+        (0..NB_CTXT as u32).fold(0, |acc, i| {
+            let lhs_block = (lhs / message_modulus.pow(i)) % message_modulus;
+            let rhs_block = (rhs / message_modulus.pow(i)) % message_modulus;
+            acc + lhs_block.min(rhs_block) * message_modulus.pow(i)
+        })
+    };
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let d_ct_0 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+        let d_ct_1 =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+        let d_res = sks.apply_bivariate_lookup_table(&d_ct_0, &d_ct_1, |a, b| a.min(b), &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            res,
+            block_wise_min(clear_0, clear_1),
+            "Invalid block-wise min of {clear_0} and {clear_1}"
+        );
+
+        // Inputs with carries are propagated first
+        let d_sum = sks.unchecked_add(&d_ct_0, &d_ct_1, &streams);
+        let d_res = sks.apply_bivariate_lookup_table(&d_sum, &d_ct_1, |a, b| a.min(b), &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        let clear_sum = (clear_0 + clear_1) % modulus;
+        assert_eq!(
+            res,
+            block_wise_min(clear_sum, clear_1),
+            "Invalid block-wise min of {clear_sum} and {clear_1}"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::integer::gpu::server_key::ReduceOp;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+const NB_ELEMENTS: usize = 8;
+
+type ClearOp = fn(u64, u64) -> u64;
+
+create_gpu_parameterized_test!(integer_reduce);
+
+fn integer_reduce<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let ops: [(ReduceOp, u64, ClearOp); 7] = [
+        (ReduceOp::Add, 0, |a, b| a.wrapping_add(b)),
+        (ReduceOp::Mul, 1, |a, b| a.wrapping_mul(b)),
+        (ReduceOp::Max, 0, |a, b| a.max(b)),
+        (ReduceOp::Min, modulus - 1, |a, b| a.min(b)),
+        (ReduceOp::BitAnd, modulus - 1, |a, b| a & b),
+        (ReduceOp::BitOr, 0, |a, b| a | b),
+        (ReduceOp::BitXor, 0, |a, b| a ^ b),
+    ];
+
+    for (op, init, clear_op) in ops {
+        let clears = (0..NB_ELEMENTS)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let d_cts = clears
+            .iter()
+            .map(|&clear| {
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+            })
+            .collect::<Vec<_>>();
+        let d_init =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(init), &streams);
+
+        let d_res = sks.reduce(&d_cts, &d_init, op, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+        let expected = clears.iter().fold(init, |acc, &x| clear_op(acc, x)) % modulus;
+        assert_eq!(res, expected, "Invalid result for {op:?}");
+
+        // Empty list returns the init
+        let d_res = sks.reduce(&[], &d_init, op, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, init, "Invalid result for {op:?} on an empty list");
+    }
+}
@@ -1,17 +1,21 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_rotate_left_test, default_rotate_right_test, unchecked_rotate_left_test,
     unchecked_rotate_right_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_rotate_left);
 create_gpu_parameterized_test!(integer_unchecked_rotate_right);
 create_gpu_parameterized_test!(integer_rotate_left);
 create_gpu_parameterized_test!(integer_rotate_right);
+create_gpu_parameterized_test!(integer_rotate_left_secret);
 
 fn integer_unchecked_rotate_right<P>(param: P)
 where
@@ -44,3 +48,35 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::rotate_left);
     default_rotate_left_test(param, executor);
 }
+
+fn integer_rotate_left_secret<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_ctxt = 32 / param.message_modulus().0.ilog2() as usize;
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..4 {
+        let clear = rng.gen::<u32>();
+        // Amounts greater than the bit width must wrap around
+        let amount = rng.gen_range(0..256u32);
+
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+        let d_amount =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(amount), &streams);
+
+        let d_res = sks.rotate_left_secret(&d_ct, &d_amount, &streams);
+        let res: u32 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+        assert_eq!(
+            res,
+            clear.rotate_left(amount),
+            "Invalid result for {clear}.rotate_left({amount})"
+        );
+    }
+}
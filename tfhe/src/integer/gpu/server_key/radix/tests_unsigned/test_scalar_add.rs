@@ -1,3 +1,6 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
@@ -10,6 +13,7 @@ use crate::shortint::parameters::*;
 create_gpu_parameterized_test!(integer_unchecked_scalar_add);
 create_gpu_parameterized_test!(integer_scalar_add);
 create_gpu_parameterized_test!(integer_default_overflowing_scalar_add);
+create_gpu_parameterized_test!(integer_checked_scalar_add_assign);
 fn integer_unchecked_scalar_add<P>(param: P)
 where
     P: Into<PBSParameters> + Copy,
@@ -33,3 +37,47 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::unsigned_overflowing_scalar_add);
     default_overflowing_scalar_add_test(param, executor);
 }
+
+fn integer_checked_scalar_add_assign<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let message_modulus = param.message_modulus().0;
+    let carry_modulus = param.carry_modulus().0;
+    let scalar = message_modulus - 1;
+
+    let mut d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(0u64), &streams);
+
+    // A fresh block has a degree of message_modulus - 1, each addition adds the same amount to
+    // the first block, until it reaches the carry capacity
+    let max_degree = message_modulus * carry_modulus - 1;
+    let num_possible_adds = (max_degree - (message_modulus - 1)) / scalar;
+
+    for i in 0..num_possible_adds {
+        assert!(
+            sks.checked_scalar_add_assign(&mut d_ct, scalar, &streams)
+                .is_ok(),
+            "Addition {i} should have been possible"
+        );
+        assert_eq!(
+            d_ct.ciphertext.info.blocks[0].degree.get(),
+            (i + 2) * scalar
+        );
+    }
+
+    let degree_before = d_ct.ciphertext.info.blocks[0].degree;
+    assert!(sks
+        .checked_scalar_add_assign(&mut d_ct, scalar, &streams)
+        .is_err());
+    // The ciphertext is left untouched on error
+    assert_eq!(d_ct.ciphertext.info.blocks[0].degree, degree_before);
+
+    let res: u64 = cks.decrypt(&d_ct.to_radix_ciphertext(&streams));
+    assert_eq!(res, num_possible_adds * scalar);
+}
@@ -1,15 +1,19 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_bitand_test, default_scalar_bitor_test, default_scalar_bitxor_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_scalar_bitand);
 create_gpu_parameterized_test!(integer_scalar_bitor);
 create_gpu_parameterized_test!(integer_scalar_bitxor);
+create_gpu_parameterized_test!(integer_reduce_to_bits_u32);
 
 fn integer_scalar_bitand<P>(param: P)
 where
@@ -34,3 +38,46 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::scalar_bitxor);
     default_scalar_bitxor_test(param, executor);
 }
+
+fn integer_reduce_to_bits_u32<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let param = param.into();
+    let num_blocks = (u32::BITS / param.message_modulus().0.ilog2()) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for bits in [12u32, 0, 1, 7, 31, 32, 40] {
+        let clear = rng.gen::<u32>();
+        let expected = if bits >= u32::BITS {
+            clear
+        } else {
+            clear & ((1 << bits) - 1)
+        };
+
+        let ctxt = cks.encrypt(clear);
+        let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+        let d_ct_res = sks.reduce_to_bits(&d_ctxt, bits, &streams);
+        assert_eq!(
+            d_ct_res.as_ref().d_blocks.lwe_ciphertext_count().0,
+            num_blocks
+        );
+        let dec_res: u32 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            dec_res, expected,
+            "Invalid result for {clear} reduced to {bits} bits"
+        );
+    }
+
+    let clear = rng.gen::<u32>();
+    let ctxt = cks.encrypt(clear);
+    let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+    let d_ct_res = sks.reduce_to_bits(&d_ctxt, 12, &streams);
+    let dec_res: u32 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+    assert_eq!(dec_res, clear & 0xFFF);
+}
@@ -1,9 +1,9 @@
 use crate::core_crypto::gpu::CudaStreams;
-use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::{gen_keys_gpu, CudaServerKey};
+use crate::integer::gpu::{gen_keys_gpu, gen_keys_radix_gpu, ComparisonType, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_scalar_comparison::{
     test_default_scalar_function, test_default_scalar_minmax, test_unchecked_scalar_function,
     test_unchecked_scalar_minmax,
@@ -256,3 +256,271 @@ create_gpu_parameterized_test!(integer_unchecked_scalar_comparisons_edge {
     PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64,
     V1_0_PARAM_GPU_MULTI_BIT_GROUP_2_MESSAGE_2_CARRY_2_KS_PBS_GAUSSIAN_2M64,
 });
+
+create_gpu_parameterized_test!(integer_any_block_nonzero);
+
+fn integer_any_block_nonzero<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let msg_bits = param.message_modulus().0.ilog2();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    // All zero, only the most significant block set, then random values
+    let high_block_only = 1u64 << (msg_bits * (NB_CTXT as u32 - 1));
+    let mut clears = vec![0u64, high_block_only];
+    clears.extend((0..4).map(|_| rng.gen::<u64>() % modulus));
+
+    for clear in clears {
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        let d_res = sks.any_block_nonzero(&d_ct, &streams);
+        let res = d_res.to_boolean_block(&streams);
+        let decrypted = cks.decrypt_bool(&res);
+        assert_eq!(decrypted, clear != 0, "Invalid result for {clear}");
+    }
+}
+
+create_gpu_parameterized_test!(integer_all_blocks_max);
+
+fn integer_all_blocks_max<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let msg_bits = param.message_modulus().0.ilog2();
+    let nb_ctxt = (u16::BITS / msg_bits) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    // All ones, then values where exactly one block is not at its maximum
+    let mut clears = vec![u16::MAX];
+    for _ in 0..4 {
+        let block_index = rng.gen_range(0..nb_ctxt as u32);
+        clears.push(u16::MAX ^ (1 << (block_index * msg_bits)));
+    }
+    clears.push(0);
+
+    for clear in clears {
+        let ct = cks.encrypt(clear);
+        let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+        let d_res = sks.all_blocks_max(&d_ct, &streams);
+        let res = d_res.to_boolean_block(&streams);
+        let decrypted = cks.decrypt_bool(&res);
+        assert_eq!(decrypted, clear == u16::MAX, "Invalid result for {clear}");
+    }
+}
+
+create_gpu_parameterized_test!(integer_is_all_zero);
+
+fn integer_is_all_zero<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let msg_bits = param.message_modulus().0.ilog2();
+    let streams = CudaStreams::new_multi_gpu();
+
+    let mut rng = rand::thread_rng();
+
+    // Small and large ciphertexts, so that blocks fit in one or several sums
+    for nb_ctxt in [4, 32] {
+        let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+        let num_bits = msg_bits * nb_ctxt as u32;
+
+        let d_trivial_zero: CudaUnsignedRadixCiphertext =
+            sks.create_trivial_zero_radix(nb_ctxt, &streams);
+        let d_res = sks.is_all_zero(&d_trivial_zero, &streams);
+        let res = d_res.to_boolean_block(&streams);
+        assert!(cks.decrypt_bool(&res), "Invalid result for trivial zero");
+
+        // Zero, values with a single non-zero block, then random values
+        let mut clears = vec![U256::ZERO];
+        for _ in 0..3 {
+            let block_index = rng.gen_range(0..nb_ctxt as u32);
+            let block_value = rng.gen_range(1..param.message_modulus().0);
+            clears.push(U256::from(block_value) << (block_index * msg_bits));
+        }
+        for _ in 0..3 {
+            clears.push(U256::from((rng.gen::<u128>(), rng.gen::<u128>())) >> (256 - num_bits));
+        }
+
+        for clear in clears {
+            let ct = cks.encrypt(clear);
+            let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ct, &streams);
+
+            let d_res = sks.is_all_zero(&d_ct, &streams);
+            let res = d_res.to_boolean_block(&streams);
+            let decrypted = cks.decrypt_bool(&res);
+            assert_eq!(
+                decrypted,
+                clear == U256::ZERO,
+                "Invalid result for {clear:?}"
+            );
+        }
+    }
+}
+
+create_gpu_parameterized_test!(integer_scalar_ge_radix);
+
+fn integer_scalar_ge_radix<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u64>() % modulus;
+        let clear_other = rng.gen::<u64>() % modulus;
+
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+        let d_other =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_other), &streams);
+
+        let d_res = sks.scalar_ge_radix(&d_ct, scalar, &streams);
+        assert_eq!(d_res.as_ref().d_blocks.lwe_ciphertext_count().0, 1);
+
+        let d_bool = sks.scalar_ge(&d_ct, scalar, &streams);
+        let expected = cks.decrypt_bool(&d_bool.to_boolean_block(&streams));
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            res,
+            u64::from(expected),
+            "Invalid result for {clear} >= {scalar}"
+        );
+        assert_eq!(expected, clear >= scalar);
+
+        // The result can be used as a factor once extended
+        let d_factor = sks.extend_radix_with_trivial_zero_blocks_msb(&d_res, NB_CTXT - 1, &streams);
+        let d_product = sks.mul(&d_other, &d_factor, &streams);
+        let product: u64 = cks.decrypt(&d_product.to_radix_ciphertext(&streams));
+        assert_eq!(product, if clear >= scalar { clear_other } else { 0 });
+    }
+}
+
+create_gpu_parameterized_test!(integer_scalar_cmp_mask);
+
+fn integer_scalar_cmp_mask<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+    const NB_ELEMENTS: usize = 16;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let clears = (0..NB_ELEMENTS)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let d_cts = clears
+        .iter()
+        .map(|&clear| {
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+        })
+        .collect::<Vec<_>>();
+
+    // Use one of the values as the scalar so that the equality case is hit
+    let scalar = clears[rng.gen_range(0..NB_ELEMENTS)];
+
+    for op in [
+        ComparisonType::EQ,
+        ComparisonType::NE,
+        ComparisonType::GT,
+        ComparisonType::GE,
+        ComparisonType::LT,
+        ComparisonType::LE,
+    ] {
+        let d_mask = sks.scalar_cmp_mask(&d_cts, op, scalar, &streams);
+        let mask: u64 = cks.decrypt(&d_mask.to_radix_ciphertext(&streams));
+
+        for (i, &clear) in clears.iter().enumerate() {
+            let expected = match op {
+                ComparisonType::EQ => clear == scalar,
+                ComparisonType::NE => clear != scalar,
+                ComparisonType::GT => clear > scalar,
+                ComparisonType::GE => clear >= scalar,
+                ComparisonType::LT => clear < scalar,
+                ComparisonType::LE => clear <= scalar,
+                ComparisonType::MAX | ComparisonType::MIN => unreachable!(),
+            };
+
+            assert_eq!(
+                (mask >> i) & 1 == 1,
+                expected,
+                "Invalid bit {i} of the mask for {clear} and scalar {scalar}"
+            );
+        }
+    }
+}
+
+create_gpu_parameterized_test!(integer_is_in_range);
+
+fn integer_is_in_range<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let msg_bits = param.message_modulus().0.ilog2();
+    let nb_ctxt = (u16::BITS / msg_bits) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..2 {
+        let clear = rng.gen::<u16>();
+
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        let low = rng.gen::<u16>();
+        let high = rng.gen::<u16>();
+
+        // Random ranges (maybe empty), bounds equal to the value, single value ranges,
+        // the full range and empty ranges
+        for (low, high) in [
+            (low, high),
+            (clear, u16::MAX),
+            (0, clear),
+            (clear, clear),
+            (0, u16::MAX),
+            (clear.wrapping_add(1), clear),
+            (u16::MAX, 0),
+        ] {
+            let d_res = sks.is_in_range(&d_ct, low, high, &streams);
+            let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+            assert_eq!(
+                res,
+                (low..=high).contains(&clear),
+                "Invalid result for {clear} in {low}..={high}"
+            );
+        }
+    }
+}
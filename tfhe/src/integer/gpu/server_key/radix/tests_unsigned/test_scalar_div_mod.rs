@@ -1,11 +1,17 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_scalar_div_mod::default_scalar_div_rem_test;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_scalar_div_rem);
+create_gpu_parameterized_test!(integer_scalar_div_power_of_two);
+create_gpu_parameterized_test!(integer_scalar_div_floor);
+create_gpu_parameterized_test!(integer_to_radix_digits);
 
 fn integer_scalar_div_rem<P>(param: P)
 where
@@ -14,3 +20,131 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::scalar_div_rem);
     default_scalar_div_rem_test(param, executor);
 }
+
+fn integer_scalar_div_power_of_two<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+    const NB_TESTS: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+        // 256 is >= modulus with 2_2 parameters, so the quotient is always 0
+        for divisor in [2u64, 4, 256] {
+            let d_quotient = sks.scalar_div(&d_ctxt, divisor, &streams);
+            let quotient: u64 = cks.decrypt(&d_quotient.to_radix_ciphertext(&streams));
+            assert_eq!(
+                quotient,
+                clear / divisor,
+                "Invalid result for {clear} / {divisor}"
+            );
+
+            let (d_quotient, d_remainder) = sks.scalar_div_rem(&d_ctxt, divisor, &streams);
+            let quotient: u64 = cks.decrypt(&d_quotient.to_radix_ciphertext(&streams));
+            let remainder: u64 = cks.decrypt(&d_remainder.to_radix_ciphertext(&streams));
+            assert_eq!(quotient, clear / divisor);
+            assert_eq!(
+                remainder,
+                clear % divisor,
+                "Invalid result for {clear} % {divisor}"
+            );
+
+            if divisor < modulus {
+                let d_shifted = sks.scalar_right_shift(&d_ctxt, divisor.ilog2(), &streams);
+                let shifted: u64 = cks.decrypt(&d_shifted.to_radix_ciphertext(&streams));
+                assert_eq!(quotient, shifted);
+            }
+        }
+    }
+}
+
+fn integer_scalar_div_floor<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+    const NB_TESTS: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+        for divisor in [1u64, 3, 7, 10, modulus - 1] {
+            let d_quotient = sks.scalar_div_floor(&d_ctxt, divisor, &streams);
+            let quotient: u64 = cks.decrypt(&d_quotient.to_radix_ciphertext(&streams));
+            assert_eq!(
+                quotient,
+                clear / divisor,
+                "Invalid result for {clear} / {divisor}"
+            );
+        }
+    }
+}
+
+fn integer_to_radix_digits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 8;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let clear_digits = |mut value: u64, base: u64| {
+        // As many digits as needed to write the maximum value
+        let mut max_value = modulus - 1;
+        let mut digits = vec![];
+        while max_value != 0 {
+            digits.push(value % base);
+            value /= base;
+            max_value /= base;
+        }
+        digits
+    };
+
+    for (clear, base) in [
+        (1234u64, 10u32),
+        (rng.gen::<u64>() % modulus, 7),
+        (rng.gen::<u64>() % modulus, 16),
+    ] {
+        let ctxt = cks.encrypt(clear);
+        let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+        let d_digits = sks.to_radix_digits(&d_ctxt, base, &streams);
+        let digits: Vec<u64> = d_digits
+            .iter()
+            .map(|d_digit| cks.decrypt(&d_digit.to_radix_ciphertext(&streams)))
+            .collect();
+
+        assert_eq!(
+            digits,
+            clear_digits(clear, u64::from(base)),
+            "Invalid base {base} digits of {clear}"
+        );
+    }
+}
@@ -1,14 +1,19 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_mul_test, unchecked_scalar_mul_corner_cases_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_scalar_mul);
 create_gpu_parameterized_test!(integer_scalar_mul);
+create_gpu_parameterized_test!(integer_scalar_mul_assign);
+create_gpu_parameterized_test!(integer_unsigned_overflowing_scalar_mul_u32);
 
 fn integer_unchecked_scalar_mul<P>(param: P)
 where
@@ -25,3 +30,77 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::scalar_mul);
     default_scalar_mul_test(param, executor);
 }
+
+fn integer_scalar_mul_assign<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    const NB_CTXT: usize = 4;
+    const NB_ELEMENTS: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let scalar = rng.gen::<u64>() % modulus;
+    let clears = (0..NB_ELEMENTS)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let mut d_cts = clears
+        .iter()
+        .map(|&clear| {
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams)
+        })
+        .collect::<Vec<_>>();
+
+    // Scale the list in place, and compare with the allocating version
+    for (d_ct, clear) in d_cts.iter_mut().zip(clears) {
+        let d_expected = sks.scalar_mul(d_ct, scalar, &streams);
+        sks.scalar_mul_assign(d_ct, scalar, &streams);
+
+        let expected: u64 = cks.decrypt(&d_expected.to_radix_ciphertext(&streams));
+        let res: u64 = cks.decrypt(&d_ct.to_radix_ciphertext(&streams));
+        assert_eq!(res, expected);
+        assert_eq!(res, clear.wrapping_mul(scalar) % modulus);
+    }
+}
+
+fn integer_unsigned_overflowing_scalar_mul_u32<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let param = param.into();
+    let num_blocks = (u32::BITS / param.message_modulus().0.ilog2()) as usize;
+
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, num_blocks, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for scalar in [0u32, 1, 2, 3, 65537, rng.gen::<u32>()] {
+        // Values near MAX, and the largest value that does not overflow
+        let mut clears = vec![u32::MAX, u32::MAX - 1, u32::MAX - rng.gen_range(0..1024)];
+        if let Some(max_no_overflow) = u32::MAX.checked_div(scalar) {
+            clears.push(max_no_overflow);
+        }
+
+        for clear in clears {
+            let ctxt = cks.encrypt(clear);
+            let d_ctxt = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt, &streams);
+
+            let (d_ct_res, d_overflowed) =
+                sks.unsigned_overflowing_scalar_mul(&d_ctxt, scalar, &streams);
+            let dec_res: u32 = cks.decrypt(&d_ct_res.to_radix_ciphertext(&streams));
+            let dec_overflowed = cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams));
+
+            assert_eq!(
+                (dec_res, dec_overflowed),
+                clear.overflowing_mul(scalar),
+                "Invalid result for {clear} * {scalar}"
+            );
+        }
+    }
+}
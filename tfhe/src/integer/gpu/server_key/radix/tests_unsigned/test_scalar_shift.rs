@@ -1,17 +1,22 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_left_shift_test, default_scalar_right_shift_test,
     unchecked_scalar_left_shift_test, unchecked_scalar_right_shift_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_scalar_left_shift);
 create_gpu_parameterized_test!(integer_unchecked_scalar_right_shift);
 create_gpu_parameterized_test!(integer_scalar_left_shift);
 create_gpu_parameterized_test!(integer_scalar_right_shift);
+create_gpu_parameterized_test!(integer_rescale);
+create_gpu_parameterized_test!(integer_overflowing_scalar_shl);
 
 fn integer_unchecked_scalar_right_shift<P>(param: P)
 where
@@ -44,3 +49,80 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::scalar_left_shift);
     default_scalar_left_shift_test(param, executor);
 }
+
+fn integer_rescale<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+
+    // Q8.8 values, on 16 bits
+    let nb_ctxt = 16 / param.message_modulus().0.ilog2() as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..3 {
+        let clear = rng.gen::<u16>() as u64;
+
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        // Q8.8 -> Q8.4, rounded to nearest
+        let d_res = sks.rescale(&d_ct, 8, 4, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, (clear + 8) >> 4, "Invalid downscale of {clear}");
+
+        // Q8.4 -> Q8.8, exact
+        let d_res = sks.rescale(&d_res, 4, 8, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(
+            res,
+            ((clear + 8) >> 4 << 4) % (1 << 16),
+            "Invalid upscale of {clear}"
+        );
+
+        // Same precision, unchanged
+        let d_res = sks.rescale(&d_ct, 8, 8, &streams);
+        let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear);
+    }
+}
+
+fn integer_overflowing_scalar_shl<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+
+    // 16 bits values
+    let num_bits = 16u32;
+    let nb_ctxt = (num_bits / param.message_modulus().0.ilog2()) as usize;
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..3 {
+        let clear = rng.gen::<u16>() as u64;
+
+        let d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+        for n in [0, 1, rng.gen_range(2..num_bits), num_bits, num_bits + 3] {
+            let (d_res, d_overflowed) = sks.overflowing_scalar_shl(&d_ct, n, &streams);
+            let res: u64 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+            let overflowed = cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams));
+
+            let wide = if n < u64::BITS { clear << n } else { 0 };
+            let expected = wide % (1 << num_bits);
+            let expected_overflow = clear != 0 && (n >= num_bits || wide >= 1 << num_bits);
+            assert_eq!(res, expected, "Invalid result for {clear} << {n}");
+            assert_eq!(
+                overflowed, expected_overflow,
+                "Invalid overflow flag for {clear} << {n}"
+            );
+        }
+    }
+}
@@ -1,14 +1,19 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_scalar_sub_test, unchecked_scalar_sub_test,
 };
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_scalar_sub);
 create_gpu_parameterized_test!(integer_scalar_sub);
+create_gpu_parameterized_test!(integer_decrement_and_is_nonzero);
+create_gpu_parameterized_test!(integer_scalar_sub_narrow_scalar);
 
 fn integer_unchecked_scalar_sub<P>(param: P)
 where
@@ -25,3 +30,68 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::scalar_sub);
     default_scalar_sub_test(param, executor);
 }
+
+fn integer_decrement_and_is_nonzero<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut d_counter =
+        CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(3u64), &streams);
+
+    let mut flags = Vec::new();
+    for expected_counter in [2u64, 1, 0] {
+        let d_is_nonzero = sks.decrement_and_is_nonzero(&mut d_counter, &streams);
+        flags.push(cks.decrypt_bool(&d_is_nonzero.to_boolean_block(&streams)));
+
+        let counter: u64 = cks.decrypt(&d_counter.to_radix_ciphertext(&streams));
+        assert_eq!(counter, expected_counter);
+    }
+    assert_eq!(flags, [true, true, false]);
+
+    // Decrementing zero wraps around, without restarting the loop
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+    let d_is_nonzero = sks.decrement_and_is_nonzero(&mut d_counter, &streams);
+    assert!(!cks.decrypt_bool(&d_is_nonzero.to_boolean_block(&streams)));
+    let counter: u64 = cks.decrypt(&d_counter.to_radix_ciphertext(&streams));
+    assert_eq!(counter, modulus - 1);
+
+    // The wrapped counter is decremented again
+    let d_is_nonzero = sks.decrement_and_is_nonzero(&mut d_counter, &streams);
+    assert!(cks.decrypt_bool(&d_is_nonzero.to_boolean_block(&streams)));
+    let counter: u64 = cks.decrypt(&d_counter.to_radix_ciphertext(&streams));
+    assert_eq!(counter, modulus - 2);
+}
+
+fn integer_scalar_sub_narrow_scalar<P>(param: P)
+where
+    P: Into<PBSParameters> + Copy,
+{
+    // The ciphertext holds more bits than the scalar type, the blocks above the scalar
+    // have to be subtracted as the sign extension of its negation
+    const NB_CTXT: usize = 8;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear = rng.gen::<u64>() % modulus;
+        let scalar = rng.gen::<u8>();
+
+        let mut d_ct =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+        sks.scalar_sub_assign(&mut d_ct, scalar, &streams);
+
+        let res: u64 = cks.decrypt(&d_ct.to_radix_ciphertext(&streams));
+        assert_eq!(res, clear.wrapping_sub(u64::from(scalar)) % modulus);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::server_key::radix::tests_unsigned::{
+    create_gpu_parameterized_test, panic_message,
+};
+use crate::integer::gpu::{gen_keys_radix_gpu, ComparisonType};
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_unchecked_comparison_with_scratch);
+create_gpu_parameterized_test!(integer_comparison_scratch_mismatch);
+
+fn integer_unchecked_comparison_with_scratch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    let ops = [
+        ComparisonType::EQ,
+        ComparisonType::NE,
+        ComparisonType::GT,
+        ComparisonType::GE,
+        ComparisonType::LT,
+        ComparisonType::LE,
+    ];
+
+    for op in ops {
+        let mut scratch =
+            sks.comparison_scratch::<CudaUnsignedRadixCiphertext>(op, NB_CTXT, &streams);
+
+        // The same scratch is reused for every comparison
+        for i in 0..4 {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = if i == 0 {
+                clear_0
+            } else {
+                rng.gen::<u64>() % modulus
+            };
+
+            let d_ct_0 =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+            let d_ct_1 =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+            let d_res =
+                sks.unchecked_comparison_with_scratch(&d_ct_0, &d_ct_1, &mut scratch, &streams);
+            let res = cks.decrypt_bool(&d_res.to_boolean_block(&streams));
+            let expected = match op {
+                ComparisonType::EQ => clear_0 == clear_1,
+                ComparisonType::NE => clear_0 != clear_1,
+                ComparisonType::GT => clear_0 > clear_1,
+                ComparisonType::GE => clear_0 >= clear_1,
+                ComparisonType::LT => clear_0 < clear_1,
+                ComparisonType::LE => clear_0 <= clear_1,
+                ComparisonType::MAX | ComparisonType::MIN => unreachable!(),
+            };
+            assert_eq!(
+                res, expected,
+                "Invalid comparison of {clear_0} and {clear_1}"
+            );
+        }
+    }
+}
+
+fn integer_comparison_scratch_mismatch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut scratch = sks.comparison_scratch::<CudaUnsignedRadixCiphertext>(
+        ComparisonType::EQ,
+        NB_CTXT - 1,
+        &streams,
+    );
+
+    let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(1u64), &streams);
+    let message = panic_message(|| {
+        sks.unchecked_comparison_with_scratch(&d_ct, &d_ct, &mut scratch, &streams)
+    })
+    .expect("A scratch allocated for another number of blocks should not be used");
+    assert!(
+        message.contains("The scratch was allocated for"),
+        "Unexpected panic message: {message}"
+    );
+}
@@ -0,0 +1,97 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+const NB_TESTS: usize = 4;
+
+create_gpu_parameterized_test!(integer_compare_and_swap);
+create_gpu_parameterized_test!(integer_sort);
+
+fn integer_compare_and_swap<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    // Equal values, then random ones
+    let mut clears = vec![(3, 3)];
+    clears.extend((0..NB_TESTS).map(|_| (rng.gen::<u64>() % modulus, rng.gen::<u64>() % modulus)));
+
+    for (clear_0, clear_1) in clears {
+        for ascending in [true, false] {
+            let mut d_ctxt_0 =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_0), &streams);
+            let mut d_ctxt_1 =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_1), &streams);
+
+            sks.compare_and_swap(&mut d_ctxt_0, &mut d_ctxt_1, ascending, &streams);
+
+            let res_0: u64 = cks.decrypt(&d_ctxt_0.to_radix_ciphertext(&streams));
+            let res_1: u64 = cks.decrypt(&d_ctxt_1.to_radix_ciphertext(&streams));
+
+            let expected = if ascending {
+                (clear_0.min(clear_1), clear_0.max(clear_1))
+            } else {
+                (clear_0.max(clear_1), clear_0.min(clear_1))
+            };
+            assert_eq!(
+                (res_0, res_1),
+                expected,
+                "Invalid result for ({clear_0}, {clear_1}), ascending: {ascending}"
+            );
+        }
+    }
+}
+
+fn integer_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    // Lengths that are not powers of two are also supported
+    for len in [0, 1, 5, 8] {
+        for ascending in [true, false] {
+            let mut clears = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            let mut d_cts = clears
+                .iter()
+                .map(|&clear| {
+                    CudaUnsignedRadixCiphertext::from_radix_ciphertext(
+                        &cks.encrypt(clear),
+                        &streams,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            sks.sort(&mut d_cts, ascending, &streams);
+
+            let res = d_cts
+                .iter()
+                .map(|d_ct| cks.decrypt::<u64>(&d_ct.to_radix_ciphertext(&streams)))
+                .collect::<Vec<_>>();
+
+            clears.sort_unstable();
+            if !ascending {
+                clears.reverse();
+            }
+            assert_eq!(res, clears, "Invalid result for ascending: {ascending}");
+        }
+    }
+}
@@ -1,16 +1,20 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_sub_test, unchecked_sub_test,
 };
 use crate::integer::server_key::radix_parallel::tests_unsigned::test_sub::default_overflowing_sub_test;
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_sub);
 create_gpu_parameterized_test!(integer_sub);
 create_gpu_parameterized_test!(integer_default_overflowing_sub);
+create_gpu_parameterized_test!(integer_unsigned_overflowing_sub_assign);
 
 fn integer_unchecked_sub<P>(param: P)
 where
@@ -35,3 +39,46 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::unsigned_overflowing_sub);
     default_overflowing_sub_test(param, executor);
 }
+
+fn integer_unsigned_overflowing_sub_assign<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    const NB_CTXT: usize = 4;
+
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for _ in 0..4 {
+        let clear_lhs = rng.gen::<u64>() % modulus;
+        let clear_rhs = rng.gen::<u64>() % modulus;
+
+        let mut d_lhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_lhs), &streams);
+        let d_rhs =
+            CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear_rhs), &streams);
+
+        let d_overflowed = sks.unsigned_overflowing_sub_assign(&mut d_lhs, &d_rhs, &streams);
+
+        let res: u64 = cks.decrypt(&d_lhs.to_radix_ciphertext(&streams));
+        let overflowed = cks.decrypt_bool(&d_overflowed.to_boolean_block(&streams));
+        assert_eq!(
+            res,
+            clear_lhs.wrapping_sub(clear_rhs) % modulus,
+            "Invalid result for {clear_lhs} - {clear_rhs}"
+        );
+        assert_eq!(
+            overflowed,
+            clear_rhs > clear_lhs,
+            "Invalid borrow for {clear_lhs} - {clear_rhs}"
+        );
+
+        // rhs is left untouched
+        let rhs: u64 = cks.decrypt(&d_rhs.to_radix_ciphertext(&streams));
+        assert_eq!(rhs, clear_rhs);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaIntegerRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_create_trivial_radix_vec);
+
+fn integer_create_trivial_radix_vec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    for num_scalars in [0, 1, 5] {
+        let scalars = (0..num_scalars)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let d_ctxts = sks.create_trivial_radix_vec(&scalars, NB_CTXT, &streams);
+        assert_eq!(d_ctxts.len(), scalars.len());
+
+        for (d_ctxt, scalar) in d_ctxts.iter().zip(scalars.iter()) {
+            assert_eq!(d_ctxt.as_ref().d_blocks.lwe_ciphertext_count().0, NB_CTXT);
+            let dec: u64 = cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams));
+            assert_eq!(dec, *scalar);
+        }
+    }
+
+    // Scalars that do not fit are truncated, like with create_trivial_radix
+    let d_ctxts = sks.create_trivial_radix_vec(&[u64::MAX, modulus + 3], NB_CTXT, &streams);
+    let decs = d_ctxts
+        .iter()
+        .map(|d_ctxt| cks.decrypt(&d_ctxt.to_radix_ciphertext(&streams)))
+        .collect::<Vec<u64>>();
+    assert_eq!(decs, vec![modulus - 1, 3]);
+}
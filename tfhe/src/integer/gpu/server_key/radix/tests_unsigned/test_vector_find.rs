@@ -1,7 +1,10 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::tests_unsigned::{
     create_gpu_parameterized_test, GpuFunctionExecutor,
 };
-use crate::integer::gpu::CudaServerKey;
+use crate::integer::gpu::{gen_keys_radix_gpu, CudaServerKey};
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
     default_contains_clear_test_case, default_contains_test_case,
     default_first_index_in_clears_test_case, default_first_index_of_clear_test_case,
@@ -17,6 +20,7 @@ use crate::integer::server_key::radix_parallel::tests_cases_unsigned::{
 };
 
 use crate::shortint::parameters::*;
+use rand::Rng;
 
 create_gpu_parameterized_test!(integer_unchecked_match_value);
 create_gpu_parameterized_test!(integer_unchecked_match_value_or);
@@ -41,6 +45,8 @@ create_gpu_parameterized_test!(integer_default_index_of);
 create_gpu_parameterized_test!(integer_default_index_of_clear);
 create_gpu_parameterized_test!(integer_default_first_index_of);
 create_gpu_parameterized_test!(integer_default_first_index_of_clear);
+create_gpu_parameterized_test!(integer_apply_integer_function);
+create_gpu_parameterized_test!(integer_first_true_index);
 
 fn integer_unchecked_match_value<P>(param: P)
 where
@@ -218,3 +224,78 @@ where
     let executor = GpuFunctionExecutor::new(&CudaServerKey::first_index_of_clear);
     default_first_index_of_clear_test_case(param, executor);
 }
+
+type IntegerFunction = fn(u128) -> u128;
+
+fn integer_apply_integer_function<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_ctxt = 8 / param.message_modulus().0.ilog2() as usize;
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, nb_ctxt, &streams);
+
+    let mut rng = rand::thread_rng();
+
+    let functions: [(IntegerFunction, &str); 4] = [
+        (|x| x * x, "x * x"),
+        (|x| x * x * x + 1, "x * x * x + 1"),
+        (|x| u128::from((x as u8).reverse_bits()), "reverse_bits"),
+        (|_| 0, "0"),
+    ];
+
+    for (f, name) in functions {
+        for _ in 0..2 {
+            let clear = rng.gen::<u8>();
+
+            let d_ct =
+                CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(clear), &streams);
+
+            let d_res = sks.apply_integer_function(&d_ct, f, &streams);
+            assert_eq!(d_res.as_ref().d_blocks.lwe_ciphertext_count().0, nb_ctxt);
+            let res: u8 = cks.decrypt(&d_res.to_radix_ciphertext(&streams));
+
+            let expected = (f(u128::from(clear)) % 256) as u8;
+            assert_eq!(res, expected, "Invalid result for {name} with x = {clear}");
+        }
+    }
+}
+
+fn integer_first_true_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, 1, &streams);
+
+    let patterns: [&[bool]; 7] = [
+        &[],
+        &[false],
+        &[true],
+        &[false, false, false, false, false],
+        &[true, true, false, true],
+        &[false, false, true, false, true],
+        &[false, false, false, false, false, false, false, true],
+    ];
+
+    for pattern in patterns {
+        let d_selectors = pattern
+            .iter()
+            .map(|b| CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(*b), &streams))
+            .collect::<Vec<_>>();
+
+        let (d_index, d_is_some) = sks.first_true_index(&d_selectors, &streams);
+        let index: u64 = cks.decrypt(&d_index.to_radix_ciphertext(&streams));
+        let is_some = cks.decrypt_bool(&d_is_some.to_boolean_block(&streams));
+
+        let expected = pattern.iter().position(|b| *b);
+        assert_eq!(is_some, expected.is_some(), "Invalid flag for {pattern:?}");
+        assert_eq!(
+            index,
+            expected.unwrap_or(0) as u64,
+            "Invalid index for {pattern:?}"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::core_crypto::gpu::CudaStreams;
+use crate::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+use crate::integer::gpu::gen_keys_radix_gpu;
+use crate::integer::gpu::server_key::radix::tests_unsigned::create_gpu_parameterized_test;
+use crate::shortint::parameters::*;
+use rand::Rng;
+
+const NB_CTXT: usize = 4;
+
+create_gpu_parameterized_test!(integer_warmup);
+
+fn integer_warmup<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let streams = CudaStreams::new_multi_gpu();
+    let (cks, sks) = gen_keys_radix_gpu(param, NB_CTXT, &streams);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus().0.pow(NB_CTXT as u32);
+
+    sks.warmup(&streams);
+    // Warming up several times must be harmless
+    sks.warmup(&streams);
+
+    let clear_0 = rng.gen::<u64>() % modulus;
+    let clear_1 = rng.gen::<u64>() % modulus;
+
+    let ctxt_0 = cks.encrypt(clear_0);
+    let ctxt_1 = cks.encrypt(clear_1);
+    let d_ctxt_0 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_0, &streams);
+    let d_ctxt_1 = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&ctxt_1, &streams);
+
+    // Operations issued after the warmup, with and without bootstraps, give correct results
+    let d_ct_res = sks.add(&d_ctxt_0, &d_ctxt_1, &streams);
+    let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, (clear_0 + clear_1) % modulus);
+
+    let d_ct_res = sks.mul(&d_ctxt_0, &d_ctxt_1, &streams);
+    let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert_eq!(dec_res, (clear_0 * clear_1) % modulus);
+}
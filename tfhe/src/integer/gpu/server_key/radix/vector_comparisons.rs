@@ -1,13 +1,12 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::LweBskGroupingFactor;
+use crate::integer::gpu::apply_bivariate_lut_kb_with_params_async;
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRadixCiphertext};
 use crate::integer::gpu::server_key::radix::{
     CudaBlockInfo, CudaRadixCiphertext, CudaRadixCiphertextInfo,
 };
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
-use crate::integer::gpu::{apply_bivariate_lut_kb_async, PBSType};
 
 impl CudaServerKey {
     #[allow(clippy::unused_self)]
@@ -109,7 +108,7 @@ impl CudaServerKey {
         unsafe {
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    apply_bivariate_lut_kb_async(
+                    apply_bivariate_lut_kb_with_params_async(
                         streams,
                         &mut comparisons_slice,
                         &packed_lhs.0.d_vec,
@@ -117,25 +116,13 @@ impl CudaServerKey {
                         block_equality_lut.acc.acc.as_ref(),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_radix_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                         self.message_modulus.0 as u32,
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    apply_bivariate_lut_kb_async(
+                    apply_bivariate_lut_kb_with_params_async(
                         streams,
                         &mut comparisons_slice,
                         &packed_lhs.0.d_vec,
@@ -143,20 +130,8 @@ impl CudaServerKey {
                         block_equality_lut.acc.acc.as_ref(),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_radix_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                         self.message_modulus.0 as u32,
                     );
                 }
@@ -1,6 +1,6 @@
 use crate::core_crypto::gpu::lwe_ciphertext_list::CudaLweCiphertextList;
 use crate::core_crypto::gpu::CudaStreams;
-use crate::core_crypto::prelude::{LweBskGroupingFactor, UnsignedInteger};
+use crate::core_crypto::prelude::UnsignedInteger;
 use crate::integer::block_decomposition::{BlockDecomposer, Decomposable, DecomposableInto};
 use crate::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
 use crate::integer::gpu::ciphertext::info::{CudaBlockInfo, CudaRadixCiphertextInfo};
@@ -8,7 +8,7 @@ use crate::integer::gpu::ciphertext::{CudaIntegerRadixCiphertext, CudaUnsignedRa
 use crate::integer::gpu::server_key::radix::CudaRadixCiphertext;
 use crate::integer::gpu::server_key::{CudaBootstrappingKey, CudaServerKey};
 use crate::integer::gpu::{
-    apply_univariate_lut_kb_async, compute_prefix_sum_hillis_steele_async, PBSType,
+    apply_univariate_lut_kb_with_params_async, compute_prefix_sum_hillis_steele_with_params_async,
 };
 pub use crate::integer::server_key::radix_parallel::MatchValues;
 use crate::prelude::CastInto;
@@ -373,6 +373,85 @@ impl CudaServerKey {
         }
     }
 
+    /// Applies a function of the whole integer to `ct`
+    ///
+    /// Unlike lookup tables, which are applied to each block independently, `f` is applied to the
+    /// value encrypted by all the blocks of `ct`, and its output is reduced modulo the modulus of
+    /// `ct`, so the result has the same number of blocks as `ct`.
+    ///
+    /// The function is evaluated on every possible input and the result is selected with
+    /// [Self::unchecked_match_value], so the cost grows with `2^num_bits` and this is only meant
+    /// for small integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ct` encrypts more than 16 bits
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::integer::gpu::ciphertext::CudaUnsignedRadixCiphertext;
+    /// use tfhe::integer::gpu::gen_keys_radix_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// let size = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, size, &streams);
+    ///
+    /// let msg = 23u8;
+    ///
+    /// let d_ct = CudaUnsignedRadixCiphertext::from_radix_ciphertext(&cks.encrypt(msg), &streams);
+    ///
+    /// // Homomorphically square the value
+    /// let d_ct_res = sks.apply_integer_function(&d_ct, |x| x * x, &streams);
+    ///
+    /// // Decrypt
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg.wrapping_mul(msg));
+    /// ```
+    pub fn apply_integer_function(
+        &self,
+        ct: &CudaUnsignedRadixCiphertext,
+        f: fn(u128) -> u128,
+        streams: &CudaStreams,
+    ) -> CudaUnsignedRadixCiphertext {
+        let num_blocks = ct.as_ref().d_blocks.lwe_ciphertext_count().0;
+        let num_bits = self.message_modulus.0.ilog2() as usize * num_blocks;
+        assert!(
+            num_bits <= 16,
+            "apply_integer_function only supports integers of up to 16 bits, got {num_bits} bits"
+        );
+
+        let modulus = 1u128 << num_bits;
+        let matches = MatchValues::new(
+            (0..modulus)
+                .map(|input| (input as u64, (f(input) % modulus) as u64))
+                .collect(),
+        )
+        .expect("inputs are unique");
+
+        let (result, _) = self.match_value(ct, &matches, streams);
+
+        // The output only has the blocks needed to represent the largest value of f
+        let result_num_blocks = result.as_ref().d_blocks.lwe_ciphertext_count().0;
+        if result_num_blocks < num_blocks {
+            self.extend_radix_with_trivial_zero_blocks_msb(
+                &result,
+                num_blocks - result_num_blocks,
+                streams,
+            )
+        } else {
+            result
+        }
+    }
+
     // /// Returns an encrypted `true` if the encrypted `value` is found in the encrypted slice
     pub fn unchecked_contains<T>(
         &self,
@@ -1271,35 +1350,12 @@ impl CudaServerKey {
             return (trivial_ct, trivial_bool);
         }
 
-        let num_blocks_result =
-            (cts.len().ilog2() + 1).div_ceil(self.message_modulus.0.ilog2()) as usize;
-
         let selectors = cts
             .iter()
             .map(|ct| self.eq(ct, value, streams))
             .collect::<Vec<_>>();
 
-        let packed_selectors =
-            self.convert_selectors_to_unsigned_radix_ciphertext(&selectors, streams);
-
-        let mut only_first_selectors = self.only_keep_first_true(packed_selectors, streams);
-
-        let unpacked_selectors =
-            self.convert_unsigned_radix_ciphertext_to_selectors(&mut only_first_selectors, streams);
-
-        let possible_values = self.create_possible_results(
-            num_blocks_result,
-            unpacked_selectors
-                .into_par_iter()
-                .enumerate()
-                .map(|(i, v)| (v, i as u64)),
-            streams,
-        );
-        let out_ct = self.aggregate_one_hot_vector(&possible_values, streams);
-
-        let block =
-            self.unchecked_is_at_least_one_comparisons_block_true(&only_first_selectors, streams);
-        (out_ct, block)
+        self.first_true_index(&selectors, streams)
     }
 
     /// Returns the encrypted index of the _first_ occurrence of encrypted `value` in the ciphertext
@@ -1391,6 +1447,85 @@ impl CudaServerKey {
         self.unchecked_first_index_of(cts, value, streams)
     }
 
+    /// Returns the encrypted index of the _first_ boolean block encrypting `true` in the slice,
+    /// also, it returns an encrypted boolean that is `true` if any of the blocks encrypts `true`.
+    ///
+    /// # Notes
+    ///
+    /// - If all the boolean blocks encrypt `false`, the returned index is 0
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::gpu::CudaStreams;
+    /// use tfhe::core_crypto::gpu::vec::GpuIndex;
+    /// use tfhe::integer::gpu::ciphertext::boolean_value::CudaBooleanBlock;
+    /// use tfhe::integer::gpu::gen_keys_gpu;
+    /// use tfhe::shortint::parameters::PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// let gpu_index = 0;
+    /// let streams = CudaStreams::new_single_gpu(GpuIndex(gpu_index));
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_gpu(PARAM_GPU_MULTI_BIT_GROUP_3_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, &streams);
+    ///
+    /// let d_selectors = [false, false, true, false, true]
+    ///     .iter()
+    ///     .map(|b| CudaBooleanBlock::from_boolean_block(&cks.encrypt_bool(*b), &streams))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Homomorphically get the index of the first true boolean
+    /// let (d_ct_res, d_check) = sks.first_true_index(&d_selectors, &streams);
+    ///
+    /// // Decrypt
+    /// let ct_res = d_ct_res.to_radix_ciphertext(&streams);
+    /// let res: u16 = cks.decrypt_radix(&ct_res);
+    ///
+    /// let check = d_check.to_boolean_block(&streams);
+    /// let is_ok = cks.decrypt_bool(&check);
+    ///
+    /// assert_eq!(res, 2u16);
+    /// assert_eq!(is_ok, true)
+    /// ```
+    pub fn first_true_index(
+        &self,
+        selectors: &[CudaBooleanBlock],
+        streams: &CudaStreams,
+    ) -> (CudaUnsignedRadixCiphertext, CudaBooleanBlock) {
+        if selectors.is_empty() {
+            let trivial_ct: CudaUnsignedRadixCiphertext = self.create_trivial_radix(0, 1, streams);
+
+            let trivial_bool = CudaBooleanBlock::from_cuda_radix_ciphertext(
+                trivial_ct.duplicate(streams).into_inner(),
+            );
+            return (trivial_ct, trivial_bool);
+        }
+
+        let num_blocks_result =
+            (selectors.len().ilog2() + 1).div_ceil(self.message_modulus.0.ilog2()) as usize;
+
+        let packed_selectors =
+            self.convert_selectors_to_unsigned_radix_ciphertext(selectors, streams);
+
+        let mut only_first_selectors = self.only_keep_first_true(packed_selectors, streams);
+
+        let unpacked_selectors =
+            self.convert_unsigned_radix_ciphertext_to_selectors(&mut only_first_selectors, streams);
+
+        let possible_values = self.create_possible_results(
+            num_blocks_result,
+            unpacked_selectors
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, v)| (v, i as u64)),
+            streams,
+        );
+        let out_ct = self.aggregate_one_hot_vector(&possible_values, streams);
+
+        let block =
+            self.unchecked_is_at_least_one_comparisons_block_true(&only_first_selectors, streams);
+        (out_ct, block)
+    }
+
     fn compute_final_index_from_selectors(
         &self,
         selectors: Vec<CudaBooleanBlock>,
@@ -1659,51 +1794,27 @@ impl CudaServerKey {
                     .unwrap();
                 match &self.bootstrapping_key {
                     CudaBootstrappingKey::Classic(d_bsk) => {
-                        apply_univariate_lut_kb_async(
+                        apply_univariate_lut_kb_with_params_async(
                             streams,
                             &mut aggregated_mut_slice,
                             &aggregated_slice,
                             identity_lut.acc.as_ref(),
                             &d_bsk.d_vec,
                             &self.key_switching_key.d_vec,
-                            self.key_switching_key
-                                .output_key_lwe_size()
-                                .to_lwe_dimension(),
-                            d_bsk.glwe_dimension,
-                            d_bsk.polynomial_size,
-                            self.key_switching_key.decomposition_level_count(),
-                            self.key_switching_key.decomposition_base_log(),
-                            d_bsk.decomp_level_count,
-                            d_bsk.decomp_base_log,
+                            &self.pbs_params(),
                             num_ct_blocks as u32,
-                            self.message_modulus,
-                            self.carry_modulus,
-                            PBSType::Classical,
-                            LweBskGroupingFactor(0),
                         );
                     }
                     CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                        apply_univariate_lut_kb_async(
+                        apply_univariate_lut_kb_with_params_async(
                             streams,
                             &mut aggregated_mut_slice,
                             &aggregated_slice,
                             identity_lut.acc.as_ref(),
                             &d_multibit_bsk.d_vec,
                             &self.key_switching_key.d_vec,
-                            self.key_switching_key
-                                .output_key_lwe_size()
-                                .to_lwe_dimension(),
-                            d_multibit_bsk.glwe_dimension,
-                            d_multibit_bsk.polynomial_size,
-                            self.key_switching_key.decomposition_level_count(),
-                            self.key_switching_key.decomposition_base_log(),
-                            d_multibit_bsk.decomp_level_count,
-                            d_multibit_bsk.decomp_base_log,
+                            &self.pbs_params(),
                             num_ct_blocks as u32,
-                            self.message_modulus,
-                            self.carry_modulus,
-                            PBSType::MultiBit,
-                            d_multibit_bsk.grouping_factor,
                         );
                     }
                 }
@@ -1755,51 +1866,27 @@ impl CudaServerKey {
                 .unwrap();
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut carry_mut_slice,
                         &aggregated_slice,
                         carry_extract_lut.acc.as_ref(),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut carry_mut_slice,
                         &aggregated_slice,
                         carry_extract_lut.acc.as_ref(),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                     );
                 }
             }
@@ -1815,51 +1902,27 @@ impl CudaServerKey {
                 .unwrap();
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut message_mut_slice,
                         &aggregated_slice,
                         message_extract_lut.acc.as_ref(),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut message_mut_slice,
                         &aggregated_slice,
                         message_extract_lut.acc.as_ref(),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                     );
                 }
             }
@@ -1960,52 +2023,28 @@ impl CudaServerKey {
             unsafe {
                 match &self.bootstrapping_key {
                     CudaBootstrappingKey::Classic(d_bsk) => {
-                        compute_prefix_sum_hillis_steele_async(
+                        compute_prefix_sum_hillis_steele_with_params_async(
                             streams,
                             &mut slice_out,
                             &mut slice_in,
                             lut_fn.acc.acc.as_ref(),
                             &d_bsk.d_vec,
                             &self.key_switching_key.d_vec,
-                            self.key_switching_key
-                                .output_key_lwe_size()
-                                .to_lwe_dimension(),
-                            d_bsk.glwe_dimension,
-                            d_bsk.polynomial_size,
-                            self.key_switching_key.decomposition_level_count(),
-                            self.key_switching_key.decomposition_base_log(),
-                            d_bsk.decomp_level_count,
-                            d_bsk.decomp_base_log,
+                            &self.pbs_params(),
                             num_ct_blocks as u32,
-                            self.message_modulus,
-                            self.carry_modulus,
-                            PBSType::Classical,
-                            LweBskGroupingFactor(0),
                             0u32,
                         );
                     }
                     CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                        compute_prefix_sum_hillis_steele_async(
+                        compute_prefix_sum_hillis_steele_with_params_async(
                             streams,
                             &mut slice_out,
                             &mut slice_in,
                             lut_fn.acc.acc.as_ref(),
                             &d_multibit_bsk.d_vec,
                             &self.key_switching_key.d_vec,
-                            self.key_switching_key
-                                .output_key_lwe_size()
-                                .to_lwe_dimension(),
-                            d_multibit_bsk.glwe_dimension,
-                            d_multibit_bsk.polynomial_size,
-                            self.key_switching_key.decomposition_level_count(),
-                            self.key_switching_key.decomposition_base_log(),
-                            d_multibit_bsk.decomp_level_count,
-                            d_multibit_bsk.decomp_base_log,
+                            &self.pbs_params(),
                             num_ct_blocks as u32,
-                            self.message_modulus,
-                            self.carry_modulus,
-                            PBSType::MultiBit,
-                            d_multibit_bsk.grouping_factor,
                             0u32,
                         );
                     }
@@ -2040,51 +2079,27 @@ impl CudaServerKey {
         unsafe {
             match &self.bootstrapping_key {
                 CudaBootstrappingKey::Classic(d_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut slice_out,
                         &slice_in_final,
                         lut.acc.as_ref(),
                         &d_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_bsk.glwe_dimension,
-                        d_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_bsk.decomp_level_count,
-                        d_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::Classical,
-                        LweBskGroupingFactor(0),
                     );
                 }
                 CudaBootstrappingKey::MultiBit(d_multibit_bsk) => {
-                    apply_univariate_lut_kb_async(
+                    apply_univariate_lut_kb_with_params_async(
                         streams,
                         &mut slice_out,
                         &slice_in_final,
                         lut.acc.as_ref(),
                         &d_multibit_bsk.d_vec,
                         &self.key_switching_key.d_vec,
-                        self.key_switching_key
-                            .output_key_lwe_size()
-                            .to_lwe_dimension(),
-                        d_multibit_bsk.glwe_dimension,
-                        d_multibit_bsk.polynomial_size,
-                        self.key_switching_key.decomposition_level_count(),
-                        self.key_switching_key.decomposition_base_log(),
-                        d_multibit_bsk.decomp_level_count,
-                        d_multibit_bsk.decomp_base_log,
+                        &self.pbs_params(),
                         num_ct_blocks as u32,
-                        self.message_modulus,
-                        self.carry_modulus,
-                        PBSType::MultiBit,
-                        d_multibit_bsk.grouping_factor,
                     );
                 }
             }
@@ -312,6 +312,10 @@ impl ParameterSetConformant for ServerKey {
     }
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "integer::CompressedServerKey";
+}
+
 impl ParameterSetConformant for CompressedServerKey {
     type ParameterSet = PBSParameters;
 
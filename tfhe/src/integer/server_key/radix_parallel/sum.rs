@@ -1,5 +1,8 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::{BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::integer::server_key::radix_parallel::cmux::ServerKeyDefaultCMux;
+use crate::integer::{
+    BooleanBlock, IntegerCiphertext, RadixCiphertext, ServerKey, SignedRadixCiphertext,
+};
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::Ciphertext;
 use rayon::prelude::*;
@@ -365,4 +368,244 @@ impl ServerKey {
 
         self.unchecked_unsigned_overflowing_sum_ciphertexts_parallelized(ciphertexts.as_ref())
     }
+
+    /// Computes the sum of the signed ciphertexts in parallel.
+    /// Returns a boolean indicating if the sum overflowed, that is,
+    /// the exact (non wrapped) sum is not representable by a ciphertext.
+    ///
+    /// Intermediate overflows that cancel out (e.g. `i8::MAX + 1 + (-1)`) are not reported.
+    ///
+    /// - Expects all ciphertexts to have empty carries
+    /// - Expects all ciphertexts to have the same size
+    pub fn unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized(
+        &self,
+        mut ciphertexts: Vec<SignedRadixCiphertext>,
+    ) -> Option<(SignedRadixCiphertext, BooleanBlock)> {
+        if ciphertexts.is_empty() {
+            return None;
+        }
+
+        if ciphertexts.len() == 1 {
+            return Some((
+                ciphertexts.pop().unwrap(),
+                BooleanBlock::new_unchecked(self.key.create_trivial(0)),
+            ));
+        }
+
+        let num_blocks = ciphertexts[0].blocks().len();
+        assert!(
+            ciphertexts[1..]
+                .iter()
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        if ciphertexts.len() == 2 {
+            return Some(
+                self.signed_overflowing_add_parallelized(&ciphertexts[0], &ciphertexts[1]),
+            );
+        }
+
+        // The sum overflowed if it is not the sign extension of its truncation
+        let wide_sum = self.unchecked_signed_exact_sum_ciphertexts_vec_parallelized(ciphertexts)?;
+        let extra_blocks = wide_sum.blocks().len() - num_blocks;
+
+        let result = SignedRadixCiphertext::from(wide_sum.blocks()[..num_blocks].to_vec());
+        let overflowed = self.unchecked_ne_parallelized(
+            &wide_sum,
+            &self.extend_radix_with_sign_msb(&result, extra_blocks),
+        );
+
+        Some((result, overflowed))
+    }
+
+    // Returns the sum of the signed ciphertexts, extended with enough blocks so that it is exact.
+    //
+    // The sum of n values needs at most ceil(log2(n)) more bits than the values,
+    // so it is computed on sign extended ciphertexts.
+    fn unchecked_signed_exact_sum_ciphertexts_vec_parallelized(
+        &self,
+        ciphertexts: Vec<SignedRadixCiphertext>,
+    ) -> Option<SignedRadixCiphertext> {
+        if ciphertexts.is_empty() {
+            return None;
+        }
+
+        let extra_bits = ciphertexts.len().ilog2() + 1;
+        let extra_blocks = extra_bits.div_ceil(self.message_modulus().0.ilog2()) as usize;
+
+        let extended = ciphertexts
+            .par_iter()
+            .map(|ct| self.extend_radix_with_sign_msb(ct, extra_blocks))
+            .collect::<Vec<_>>();
+        self.unchecked_sum_ciphertexts_vec_parallelized(extended)
+    }
+
+    /// Computes the sum of the signed ciphertexts in parallel.
+    /// Returns a boolean indicating if the sum overflowed, that is,
+    /// the exact (non wrapped) sum is not representable by a ciphertext.
+    ///
+    /// See [Self::unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized]
+    pub fn unchecked_signed_overflowing_sum_ciphertexts_parallelized<'a, C>(
+        &self,
+        ciphertexts: C,
+    ) -> Option<(SignedRadixCiphertext, BooleanBlock)>
+    where
+        C: IntoIterator<Item = &'a SignedRadixCiphertext>,
+    {
+        let ciphertexts = ciphertexts.into_iter().map(Clone::clone).collect();
+        self.unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized(ciphertexts)
+    }
+
+    /// Computes the sum of the signed ciphertexts in parallel.
+    /// Returns a boolean indicating if the sum overflowed, that is,
+    /// the exact (non wrapped) sum is not representable by a ciphertext.
+    ///
+    /// - Returns None if ciphertexts is empty
+    ///
+    /// See [Self::unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized]
+    pub fn signed_overflowing_sum_ciphertexts_parallelized<'a, C>(
+        &self,
+        ciphertexts: C,
+    ) -> Option<(SignedRadixCiphertext, BooleanBlock)>
+    where
+        C: IntoIterator<Item = &'a SignedRadixCiphertext>,
+    {
+        let mut ciphertexts = ciphertexts
+            .into_iter()
+            .map(Clone::clone)
+            .collect::<Vec<_>>();
+        ciphertexts.par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(ct);
+            }
+        });
+
+        self.unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized(ciphertexts)
+    }
+
+    /// Computes the sum of the signed ciphertexts in parallel.
+    /// Returns a boolean indicating if the sum overflowed, that is,
+    /// the exact (non wrapped) sum is not representable by a ciphertext.
+    ///
+    /// - Returns None if ciphertexts is empty
+    ///
+    /// See [Self::unchecked_signed_overflowing_sum_ciphertexts_vec_parallelized]
+    pub fn smart_signed_overflowing_sum_ciphertexts_parallelized<C>(
+        &self,
+        mut ciphertexts: C,
+    ) -> Option<(SignedRadixCiphertext, BooleanBlock)>
+    where
+        C: AsMut<[SignedRadixCiphertext]> + AsRef<[SignedRadixCiphertext]>,
+    {
+        ciphertexts.as_mut().par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(ct);
+            }
+        });
+
+        self.unchecked_signed_overflowing_sum_ciphertexts_parallelized(ciphertexts.as_ref())
+    }
+
+    /// Computes the sum of the ciphertexts in parallel, saturating at the bounds of the
+    /// ciphertext type instead of wrapping around.
+    ///
+    /// If the exact sum is greater than the maximum value representable by the ciphertexts,
+    /// that maximum is returned, and likewise with the minimum for signed ciphertexts.
+    ///
+    /// - Returns None if ciphertexts is empty
+    /// - Returns the ciphertext unchanged if there is only one
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64, num_blocks);
+    ///
+    /// let cts = [200u64, 50, 10]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt(msg))
+    ///     .collect::<Vec<_>>();
+    /// let ct_res = sks.saturating_sum_ciphertexts_parallelized(&cts).unwrap();
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 255);
+    ///
+    /// let cts = [-100i64, -50, 10]
+    ///     .iter()
+    ///     .map(|&msg| cks.encrypt_signed(msg))
+    ///     .collect::<Vec<_>>();
+    /// let ct_res = sks.saturating_sum_ciphertexts_parallelized(&cts).unwrap();
+    /// let dec: i64 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, -128);
+    /// ```
+    pub fn saturating_sum_ciphertexts_parallelized<'a, T, C>(&self, ciphertexts: C) -> Option<T>
+    where
+        C: IntoIterator<Item = &'a T>,
+        T: IntegerRadixCiphertext + 'a,
+    {
+        let mut ciphertexts = ciphertexts
+            .into_iter()
+            .map(Clone::clone)
+            .collect::<Vec<T>>();
+        if ciphertexts.len() <= 1 {
+            return ciphertexts.pop();
+        }
+
+        ciphertexts.par_iter_mut().for_each(|ct| {
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(ct);
+            }
+        });
+
+        let num_blocks = ciphertexts[0].blocks().len();
+
+        if T::IS_SIGNED {
+            let ciphertexts = ciphertexts
+                .into_iter()
+                .map(|ct| SignedRadixCiphertext::from_blocks(ct.into_blocks()))
+                .collect();
+            let wide_sum =
+                self.unchecked_signed_exact_sum_ciphertexts_vec_parallelized(ciphertexts)?;
+            let extra_blocks = wide_sum.blocks().len() - num_blocks;
+            let result = SignedRadixCiphertext::from(wide_sum.blocks()[..num_blocks].to_vec());
+
+            let (overflowed, is_negative) = rayon::join(
+                || {
+                    self.unchecked_ne_parallelized(
+                        &wide_sum,
+                        &self.extend_radix_with_sign_msb(&result, extra_blocks),
+                    )
+                },
+                || self.unchecked_scalar_lt_parallelized(&wide_sum, 0),
+            );
+
+            let bound = self.if_then_else_parallelized(
+                &is_negative,
+                &self.create_trivial_min_radix::<SignedRadixCiphertext>(num_blocks),
+                &self.create_trivial_max_radix(num_blocks),
+            );
+            let result = self.if_then_else_parallelized(&overflowed, &bound, &result);
+
+            Some(T::from_blocks(result.into_blocks()))
+        } else {
+            let ciphertexts = ciphertexts
+                .into_iter()
+                .map(|ct| RadixCiphertext::from_blocks(ct.into_blocks()))
+                .collect();
+            let (result, overflowed) =
+                self.unchecked_unsigned_overflowing_sum_ciphertexts_vec_parallelized(ciphertexts)?;
+
+            let result = self.if_then_else_parallelized(
+                &overflowed,
+                &self.create_trivial_max_radix(num_blocks),
+                &result,
+            );
+
+            Some(T::from_blocks(result.into_blocks()))
+        }
+    }
 }
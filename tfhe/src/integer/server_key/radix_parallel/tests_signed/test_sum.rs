@@ -0,0 +1,179 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::tests_signed::NB_CTXT;
+use crate::integer::server_key::radix_parallel::tests_unsigned::{
+    nb_tests_smaller_for_params, CpuFunctionExecutor,
+};
+use crate::integer::tests::create_parameterized_test;
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey, SignedRadixCiphertext,
+};
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+use rand::Rng;
+use std::sync::Arc;
+
+create_parameterized_test!(integer_signed_default_overflowing_sum_ciphertexts_vec);
+create_parameterized_test!(integer_signed_smart_overflowing_sum_ciphertexts_slice);
+create_parameterized_test!(integer_signed_default_saturating_sum_ciphertexts);
+
+fn integer_signed_default_overflowing_sum_ciphertexts_vec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    // Without this the compiler seems lost, and outputs errors about
+    // 'one type is more general than the other' probably because the
+    // `signed_overflowing_sum_ciphertexts_parallelized` is generic over the input collection
+    let sum_vec = |sks: &ServerKey,
+                   ctxt: &Vec<SignedRadixCiphertext>|
+     -> Option<(SignedRadixCiphertext, BooleanBlock)> {
+        sks.signed_overflowing_sum_ciphertexts_parallelized(ctxt)
+    };
+    let executor = CpuFunctionExecutor::new(sum_vec);
+    signed_default_overflowing_sum_ciphertexts_test(param, executor);
+}
+
+fn integer_signed_smart_overflowing_sum_ciphertexts_slice<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let sum_vec = |sks: &ServerKey,
+                   ctxt: &Vec<SignedRadixCiphertext>|
+     -> Option<(SignedRadixCiphertext, BooleanBlock)> {
+        sks.smart_signed_overflowing_sum_ciphertexts_parallelized(ctxt.clone())
+    };
+    let executor = CpuFunctionExecutor::new(sum_vec);
+    signed_default_overflowing_sum_ciphertexts_test(param, executor);
+}
+
+// Returns the sum wrapped in [-modulus..modulus[ and whether the exact sum is outside that range
+fn signed_overflowing_sum_under_modulus(clears: &[i64], modulus: i64) -> (i64, bool) {
+    let sum = clears.iter().sum::<i64>();
+    let wrapped = (sum + modulus).rem_euclid(2 * modulus) - modulus;
+    (wrapped, wrapped != sum)
+}
+
+pub(crate) fn signed_default_overflowing_sum_ciphertexts_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        &'a Vec<SignedRadixCiphertext>,
+        Option<(SignedRadixCiphertext, BooleanBlock)>,
+    >,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+    let sks = Arc::new(sks);
+
+    executor.setup(&cks, sks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let mut run_test = |clears: &[i64]| {
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_signed(clear))
+            .collect::<Vec<_>>();
+
+        let (ct_res, overflow_res) = executor.execute(&ctxts).unwrap();
+
+        let decrypted_res: i64 = cks.decrypt_signed(&ct_res);
+        let decrypted_overflow = cks.decrypt_bool(&overflow_res);
+
+        let (expected_clear, expected_overflow) =
+            signed_overflowing_sum_under_modulus(clears, modulus);
+
+        assert_eq!(
+            decrypted_res, expected_clear,
+            "Invalid result for sum of {clears:?}, expected {expected_clear} got {decrypted_res}"
+        );
+        assert_eq!(
+            decrypted_overflow, expected_overflow,
+            "Invalid overflow flag for sum of {clears:?}, expected {expected_overflow} got {decrypted_overflow}"
+        );
+    };
+
+    // Values straddling the overflow boundary, in both directions
+    let max = modulus - 1;
+    let min = -modulus;
+    run_test(&[max, 1, 0]);
+    run_test(&[max, 1, -1]);
+    run_test(&[max, max, min, min]);
+    run_test(&[min, -1, 0]);
+    run_test(&[min, -1, 1]);
+    run_test(&[min, min, max, max, 1]);
+    run_test(&[max / 2, max / 2, 1, 0]);
+    run_test(&[max / 2, max / 2, 2, 0]);
+
+    for len in [1, 2, 3, 15, 16, 17] {
+        for _ in 0..nb_tests_smaller {
+            // Large magnitudes of both signs, so that sums often sit around the boundary
+            let clears = (0..len)
+                .map(|_| {
+                    let magnitude = rng.gen_range(modulus / 2..modulus);
+                    if rng.gen::<bool>() {
+                        magnitude
+                    } else {
+                        -magnitude
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            run_test(&clears);
+        }
+    }
+}
+
+fn integer_signed_default_saturating_sum_ciphertexts<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let run_test = |clears: &[i64]| {
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt_signed(clear))
+            .collect::<Vec<_>>();
+
+        let ct_res = sks.saturating_sum_ciphertexts_parallelized(&ctxts).unwrap();
+        let decrypted_res: i64 = cks.decrypt_signed(&ct_res);
+
+        let expected_clear = clears.iter().sum::<i64>().clamp(-modulus, modulus - 1);
+        assert_eq!(
+            decrypted_res, expected_clear,
+            "Invalid saturating sum of {clears:?}, expected {expected_clear} got {decrypted_res}"
+        );
+    };
+
+    // Saturating in both directions, and intermediate overflows that cancel out
+    let max = modulus - 1;
+    let min = -modulus;
+    run_test(&[max, 1, 0]);
+    run_test(&[max, 1, -1]);
+    run_test(&[min, -1, 0]);
+    run_test(&[min, min, max, max, 1]);
+    run_test(&[max, max, max, min]);
+
+    for len in [1, 2, 3, 16] {
+        for _ in 0..nb_tests_smaller {
+            let clears = (0..len)
+                .map(|_| rng.gen_range(min..=max))
+                .collect::<Vec<_>>();
+
+            run_test(&clears);
+        }
+    }
+}
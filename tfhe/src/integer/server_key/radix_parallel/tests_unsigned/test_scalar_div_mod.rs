@@ -53,7 +53,8 @@ where
     // hard-coded tests
     // 10, 7, 14 are from the paper and should trigger different branches
     // 16 is a power of two and should trigger the corresponding branch
-    let hard_coded_divisors: [u64; 4] = [10, 7, 14, 16];
+    // 1 is the power of two that results in a shift by 0
+    let hard_coded_divisors: [u64; 5] = [10, 7, 14, 16, 1];
     for divisor in hard_coded_divisors {
         let clear = rng.gen::<u64>() % modulus;
         let ct = cks.encrypt(clear);
@@ -14,6 +14,7 @@ use std::sync::Arc;
 create_parameterized_test!(integer_smart_sum_ciphertexts_slice);
 create_parameterized_test!(integer_default_unsigned_overflowing_sum_ciphertexts_vec);
 create_parameterized_test!(integer_default_sum_ciphertexts_vec);
+create_parameterized_test!(integer_default_saturating_sum_ciphertexts);
 
 fn integer_default_unsigned_overflowing_sum_ciphertexts_vec<P>(param: P)
 where
@@ -217,3 +218,51 @@ where
         }
     }
 }
+
+fn integer_default_saturating_sum_ciphertexts<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests_smaller = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32);
+
+    assert!(sks
+        .saturating_sum_ciphertexts_parallelized::<RadixCiphertext, _>(&[])
+        .is_none());
+
+    for len in [1, 2, 3, 16, 17] {
+        for _ in 0..nb_tests_smaller {
+            // Small values so that some sums do not saturate
+            let bound = if rng.gen::<bool>() {
+                modulus
+            } else {
+                modulus / len
+            };
+            let clears = (0..len)
+                .map(|_| rng.gen::<u64>() % bound)
+                .collect::<Vec<_>>();
+
+            let ctxts = clears
+                .iter()
+                .copied()
+                .map(|clear| cks.encrypt(clear))
+                .collect::<Vec<_>>();
+
+            let ct_res = sks.saturating_sum_ciphertexts_parallelized(&ctxts).unwrap();
+            let decrypted_res: u64 = cks.decrypt(&ct_res);
+
+            let expected_clear = clears.iter().sum::<u64>().min(modulus - 1);
+            assert_eq!(
+                decrypted_res, expected_clear,
+                "Invalid saturating sum of {clears:?}, expected {expected_clear} got {decrypted_res}"
+            );
+        }
+    }
+}
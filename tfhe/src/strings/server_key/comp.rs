@@ -1,7 +1,10 @@
-use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
+use crate::integer::{
+    BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey as IntegerServerKey,
+};
 use crate::strings::ciphertext::{FheString, GenericPattern, GenericPatternRef};
 use crate::strings::server_key::{FheStringIsEmpty, ServerKey};
 use crate::ClearString;
+use rayon::prelude::*;
 use std::borrow::Borrow;
 
 impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
@@ -138,6 +141,46 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         sk.boolean_bitnot(&eq)
     }
 
+    /// Returns `true` if an encrypted string is equal to any of the given patterns (either
+    /// encrypted or clear).
+    ///
+    /// Returns `false` if it is equal to none of them, including when `rhs` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("yes", "ok");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = FheString::new(&ck, s2, None);
+    /// let candidates = [
+    ///     GenericPattern::Clear(ClearString::new("yes".to_string())),
+    ///     GenericPattern::Enc(FheString::new(&ck, "no", None)),
+    /// ];
+    ///
+    /// let result1 = sk.eq_any(&enc_s1, &candidates);
+    /// let result2 = sk.eq_any(&enc_s2, &candidates);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result1));
+    /// assert!(!ck.inner().decrypt_bool(&result2));
+    /// ```
+    pub fn eq_any(&self, lhs: &FheString, rhs: &[GenericPattern]) -> BooleanBlock {
+        let sk = self.inner();
+
+        rhs.par_iter()
+            .map(|pat| self.eq(lhs, pat.as_ref()))
+            .reduce_with(|a, b| sk.boolean_bitor(&a, &b))
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
     /// Returns `true` if the first encrypted string is less than the second encrypted string.
     ///
     /// Returns `false` otherwise.
@@ -338,4 +381,338 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         self.eq(&lhs, rhs.as_ref())
     }
+
+    /// Returns `true` if an encrypted string and a pattern (either encrypted or clear) are equal,
+    /// ignoring whitespace at the end of both of them.
+    ///
+    /// Returns `false` if they are not equal.
+    ///
+    /// The pattern for comparison (`rhs`) can be specified as either `GenericPatternRef::Clear` for
+    /// a clear string or `GenericPatternRef::Enc` for an encrypted string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("abc ", "abc");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, s2, None));
+    ///
+    /// let result = sk.eq_ignore_trailing_whitespace(&enc_s1, enc_s2.as_ref());
+    /// let are_equal = ck.inner().decrypt_bool(&result);
+    ///
+    /// assert!(are_equal);
+    /// ```
+    pub fn eq_ignore_trailing_whitespace(
+        &self,
+        lhs: &FheString,
+        rhs: GenericPatternRef<'_>,
+    ) -> BooleanBlock {
+        let (lhs, rhs) = rayon::join(
+            || self.trim_end(lhs),
+            || match rhs {
+                GenericPatternRef::Clear(rhs) => GenericPattern::Clear(ClearString::new(
+                    rhs.str()
+                        .trim_end_matches(|c: char| c.is_ascii_whitespace())
+                        .to_string(),
+                )),
+                GenericPatternRef::Enc(rhs) => GenericPattern::Enc(self.trim_end(rhs)),
+            },
+        );
+
+        self.eq(&lhs, rhs.as_ref())
+    }
+
+    /// Returns `true` if an encrypted string is equal to a clear byte array.
+    ///
+    /// Returns `false` if they are not equal. The result is a single encrypted boolean, so which
+    /// byte differs is not revealed.
+    ///
+    /// When the encrypted string cannot hold as many chars as there are bytes (or, if it is not
+    /// padded, holds a different number of chars), `false` is returned without any homomorphic
+    /// computation, as the length of the encrypted string is public.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "secret";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let result1 = sk.bytes_eq(&enc_s, b"secret");
+    /// let result2 = sk.bytes_eq(&enc_s, b"sacret");
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result1));
+    /// assert!(!ck.inner().decrypt_bool(&result2));
+    /// ```
+    pub fn bytes_eq(&self, str: &FheString, bytes: &[u8]) -> BooleanBlock {
+        let sk = self.inner();
+
+        if str.is_padded() {
+            // The last char of a padded string is always null
+            if str.len() <= bytes.len() {
+                return sk.create_trivial_boolean_block(false);
+            }
+        } else if str.len() != bytes.len() {
+            return sk.create_trivial_boolean_block(false);
+        }
+
+        // The chars after the bytes are padding and must be null
+        let differences: Vec<_> = str
+            .chars()
+            .par_iter()
+            .enumerate()
+            .map(|(i, char)| {
+                let byte = bytes.get(i).copied().unwrap_or(0);
+                let is_different = sk.scalar_ne_parallelized(char.ciphertext(), byte);
+                let radix: RadixCiphertext = is_different.into_radix(1, sk);
+                radix.into_blocks()[0].clone()
+            })
+            .collect();
+
+        if differences.is_empty() {
+            return sk.create_trivial_boolean_block(true);
+        }
+
+        // This will be 0 if all the chars are equal, non-zero otherwise
+        let combined_radix = RadixCiphertext::from(differences);
+
+        sk.scalar_eq_parallelized(&combined_radix, 0)
+    }
+
+    /// Returns `true` if the encrypted string is not empty and all its characters are equal.
+    ///
+    /// Padding null characters are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("aaaa", "aaab");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, Some(1));
+    /// let enc_s2 = FheString::new(&ck, s2, None);
+    ///
+    /// let result1 = sk.is_uniform(&enc_s1);
+    /// let result2 = sk.is_uniform(&enc_s2);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result1));
+    /// assert!(!ck.inner().decrypt_bool(&result2));
+    /// ```
+    pub fn is_uniform(&self, str: &FheString) -> BooleanBlock {
+        let sk = self.inner();
+
+        let Some((first, rest)) = str.chars().split_first() else {
+            return sk.create_trivial_boolean_block(false);
+        };
+
+        let eq_first_or_null: Vec<_> = rest
+            .par_iter()
+            .map(|char| {
+                if str.is_padded() {
+                    let (eq_first, is_null) = rayon::join(
+                        || sk.eq_parallelized(char.ciphertext(), first.ciphertext()),
+                        || sk.scalar_eq_parallelized(char.ciphertext(), 0u8),
+                    );
+
+                    // Padding nulls are only found after the non-null chars
+                    sk.boolean_bitor(&eq_first, &is_null)
+                } else {
+                    sk.eq_parallelized(char.ciphertext(), first.ciphertext())
+                }
+            })
+            .collect();
+
+        // If str is padded, it is empty when its first char is null
+        let mut result = if str.is_padded() {
+            sk.scalar_ne_parallelized(first.ciphertext(), 0u8)
+        } else {
+            sk.create_trivial_boolean_block(true)
+        };
+
+        for eq_first_or_null in eq_first_or_null {
+            sk.boolean_bitand_assign(&mut result, &eq_first_or_null);
+        }
+
+        result
+    }
+
+    /// Returns `true` if the content of an encrypted string reads the same forwards and backwards,
+    /// or `false` otherwise.
+    ///
+    /// Padding null characters are ignored. The empty string and strings of a single character are
+    /// palindromes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("noon", "abc");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, Some(2));
+    /// let enc_s2 = FheString::new(&ck, s2, None);
+    ///
+    /// let result1 = sk.is_palindrome(&enc_s1);
+    /// let result2 = sk.is_palindrome(&enc_s2);
+    ///
+    /// assert!(ck.inner().decrypt_bool(&result1));
+    /// assert!(!ck.inner().decrypt_bool(&result2));
+    /// ```
+    pub fn is_palindrome(&self, str: &FheString) -> BooleanBlock {
+        let sk = self.inner();
+        let chars = str.chars();
+
+        // Compares char i to char len - 1 - i for the first `len` chars
+        let first_chars_are_palindrome = |len: usize| {
+            let chars_eq: Vec<_> = (0..len / 2)
+                .into_par_iter()
+                .map(|i| sk.eq_parallelized(chars[i].ciphertext(), chars[len - 1 - i].ciphertext()))
+                .collect();
+
+            let mut result = sk.create_trivial_boolean_block(true);
+            for char_eq in chars_eq {
+                sk.boolean_bitand_assign(&mut result, &char_eq);
+            }
+            result
+        };
+
+        if !str.is_padded() {
+            return first_chars_are_palindrome(chars.len());
+        }
+
+        let is_not_null: Vec<_> = chars
+            .par_iter()
+            .map(|char| sk.scalar_ne_parallelized(char.ciphertext(), 0u8))
+            .collect();
+
+        // Padding nulls are only found after the non-null chars, so the content has length `len`
+        // when char len - 1 is not null and char len is null
+        let is_palindrome_with_len: Vec<_> = (0..=chars.len())
+            .into_par_iter()
+            .map(|len| {
+                let mut result = first_chars_are_palindrome(len);
+                if len > 0 {
+                    sk.boolean_bitand_assign(&mut result, &is_not_null[len - 1]);
+                }
+                if let Some(char_is_not_null) = is_not_null.get(len) {
+                    sk.boolean_bitand_assign(&mut result, &sk.boolean_bitnot(char_is_not_null));
+                }
+                result
+            })
+            .collect();
+
+        let mut result = sk.create_trivial_boolean_block(false);
+        for is_palindrome in is_palindrome_with_len {
+            sk.boolean_bitor_assign(&mut result, &is_palindrome);
+        }
+
+        result
+    }
+
+    /// Returns the number of leading characters that are equal in an encrypted string and a
+    /// pattern (either encrypted or clear), as an encrypted `RadixCiphertext`.
+    ///
+    /// Padding null characters are never counted as matching.
+    ///
+    /// The pattern (`rhs`) can be specified as either `GenericPatternRef::Clear` for a clear string
+    /// or `GenericPatternRef::Enc` for an encrypted string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s1, s2) = ("abcd", "abxy");
+    ///
+    /// let enc_s1 = FheString::new(&ck, s1, None);
+    /// let enc_s2 = GenericPattern::Enc(FheString::new(&ck, s2, None));
+    ///
+    /// let result = sk.common_prefix_len(&enc_s1, enc_s2.as_ref());
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&result), 2);
+    /// ```
+    pub fn common_prefix_len(
+        &self,
+        lhs: &FheString,
+        rhs: GenericPatternRef<'_>,
+    ) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let chars_eq: Vec<BooleanBlock> = match rhs {
+            GenericPatternRef::Clear(rhs) => lhs
+                .chars()
+                .par_iter()
+                .zip(rhs.str().as_bytes().par_iter())
+                .map(|(char, &clear_char)| sk.scalar_eq_parallelized(char.ciphertext(), clear_char))
+                .collect(),
+            GenericPatternRef::Enc(rhs) => lhs
+                .chars()
+                .par_iter()
+                .zip(rhs.chars().par_iter())
+                .map(|(lhs_char, rhs_char)| {
+                    let (eq, is_not_null) = rayon::join(
+                        || sk.eq_parallelized(lhs_char.ciphertext(), rhs_char.ciphertext()),
+                        || sk.scalar_ne_parallelized(lhs_char.ciphertext(), 0u8),
+                    );
+
+                    // Two padding nulls are equal but are not part of the common prefix
+                    sk.boolean_bitand(&eq, &is_not_null)
+                })
+                .collect(),
+        };
+
+        // A char belongs to the common prefix only if all the previous chars also do
+        let mut is_in_prefix = Vec::with_capacity(chars_eq.len());
+        for char_eq in chars_eq {
+            let prefix_eq = match is_in_prefix.last() {
+                Some(previous) => sk.boolean_bitand(previous, &char_eq),
+                None => char_eq,
+            };
+            is_in_prefix.push(prefix_eq);
+        }
+
+        let is_in_prefix: Vec<RadixCiphertext> = is_in_prefix
+            .into_par_iter()
+            .map(|bool| bool.into_radix(16, sk))
+            .collect();
+
+        sk.sum_ciphertexts_parallelized(is_in_prefix.iter())
+            .unwrap_or_else(|| sk.create_trivial_zero_radix(16))
+    }
 }
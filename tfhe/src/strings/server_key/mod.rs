@@ -3,7 +3,7 @@ mod no_patterns;
 mod pattern;
 mod trim;
 
-pub use trim::split_ascii_whitespace;
+pub use trim::{split_ascii_whitespace, split_inclusive_whitespace};
 
 use crate::integer::bigint::static_unsigned::StaticUnsignedBigInt;
 use crate::integer::prelude::*;
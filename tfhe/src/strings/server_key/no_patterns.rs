@@ -1,5 +1,6 @@
-use crate::integer::ServerKey as IntegerServerKey;
-use crate::strings::ciphertext::{FheString, UIntArg};
+use crate::integer::prelude::*;
+use crate::integer::{RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPatternRef, UIntArg};
 use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
 use rayon::prelude::*;
 use std::borrow::Borrow;
@@ -46,29 +47,59 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     /// }
     /// ```
     pub fn len(&self, str: &FheString) -> FheStringLen {
-        let sk = self.inner();
-
         if str.is_padded() {
-            let non_zero_chars: Vec<_> = str
-                .chars()
-                .par_iter()
-                .map(|char| {
-                    let bool = sk.scalar_ne_parallelized(char.ciphertext(), 0u8);
-                    bool.into_radix(16, sk)
-                })
-                .collect();
-
-            // If we add the number of non-zero elements we get the actual length, without padding
-            let len = sk
-                .sum_ciphertexts_parallelized(non_zero_chars.iter())
-                .expect("There's at least one padding character");
-
-            FheStringLen::Padding(len)
+            FheStringLen::Padding(self.ascii_char_count(str))
         } else {
             FheStringLen::NoPadding(str.len())
         }
     }
 
+    /// Returns the number of non-null characters of an encrypted string, as an encrypted
+    /// `RadixCiphertext`.
+    ///
+    /// Unlike [`Self::len`], the count is always computed homomorphically, even if the string
+    /// is not padded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "hello";
+    /// let number_of_nulls = 3;
+    ///
+    /// let enc_s_no_padding = FheString::new(&ck, s, None);
+    /// let enc_s_with_padding = FheString::new(&ck, s, Some(number_of_nulls));
+    ///
+    /// let result_no_padding = sk.ascii_char_count(&enc_s_no_padding);
+    /// let result_with_padding = sk.ascii_char_count(&enc_s_with_padding);
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&result_no_padding), 5);
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&result_with_padding), 5);
+    /// ```
+    pub fn ascii_char_count(&self, str: &FheString) -> RadixCiphertext {
+        let sk = self.inner();
+
+        let non_zero_chars: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let bool = sk.scalar_ne_parallelized(char.ciphertext(), 0u8);
+                bool.into_radix(16, sk)
+            })
+            .collect();
+
+        // If we add the number of non-zero elements we get the actual length, without padding
+        sk.sum_ciphertexts_parallelized(non_zero_chars.iter())
+            .unwrap_or_else(|| sk.create_trivial_zero_radix(16))
+    }
+
     /// Returns whether an encrypted string is empty or not as an `FheStringIsEmpty` enum.
     ///
     /// If the encrypted string has no padding, the result is a clear boolean.
@@ -241,6 +272,102 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         lowercase
     }
 
+    /// Returns a new encrypted string where the first character of each whitespace-delimited word
+    /// is converted to uppercase and the remaining ones to lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "hello wORLD";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let result = sk.to_titlecase(&enc_s);
+    /// let titlecased = ck.decrypt_ascii(&result);
+    ///
+    /// assert_eq!(titlecased, "Hello World");
+    /// ```
+    pub fn to_titlecase(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        let mut titlecase = str.clone();
+
+        // For each character, whether it is whitespace, lowercase and uppercase
+        let char_kinds: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let (is_whitespace, (is_lowercase, is_uppercase)) = rayon::join(
+                    || self.is_whitespace(char, false),
+                    || {
+                        rayon::join(
+                            || {
+                                let (ge_97, le_122) = rayon::join(
+                                    || sk.scalar_ge_parallelized(char.ciphertext(), 97u8),
+                                    || sk.scalar_le_parallelized(char.ciphertext(), 122u8),
+                                );
+                                sk.boolean_bitand(&ge_97, &le_122)
+                            },
+                            || {
+                                let (ge_65, le_90) = rayon::join(
+                                    || sk.scalar_ge_parallelized(char.ciphertext(), 65u8),
+                                    || sk.scalar_le_parallelized(char.ciphertext(), 90u8),
+                                );
+                                sk.boolean_bitand(&ge_65, &le_90)
+                            },
+                        )
+                    },
+                );
+
+                (is_whitespace, is_lowercase, is_uppercase)
+            })
+            .collect();
+
+        // A character starts a word if it is the first one or if the previous one is whitespace
+        let starts_word: Vec<_> = std::iter::once(sk.create_trivial_boolean_block(true))
+            .chain(
+                char_kinds
+                    .iter()
+                    .map(|(is_whitespace, _, _)| is_whitespace.clone()),
+            )
+            .take(char_kinds.len())
+            .collect();
+
+        // Subtraction by 32 makes a word start uppercase, addition by 32 makes the rest lowercase
+        titlecase
+            .chars_mut()
+            .par_iter_mut()
+            .zip(char_kinds.into_par_iter())
+            .zip(starts_word.into_par_iter())
+            .for_each(|((char, (_, is_lowercase, is_uppercase)), starts_word)| {
+                let (to_upper, to_lower) = rayon::join(
+                    || sk.boolean_bitand(&starts_word, &is_lowercase),
+                    || sk.boolean_bitand(&sk.boolean_bitnot(&starts_word), &is_uppercase),
+                );
+
+                let mut subtract = sk.create_trivial_radix(32, self.num_ascii_blocks());
+                let mut add = sk.create_trivial_radix(32, self.num_ascii_blocks());
+
+                rayon::join(
+                    || sk.mul_assign_parallelized(&mut subtract, &to_upper.into_radix(1, sk)),
+                    || sk.mul_assign_parallelized(&mut add, &to_lower.into_radix(1, sk)),
+                );
+
+                sk.sub_assign_parallelized(char.ciphertext_mut(), &subtract);
+                sk.add_assign_parallelized(char.ciphertext_mut(), &add);
+            });
+
+        titlecase
+    }
+
     /// Concatenates two encrypted strings and returns the result as a new encrypted string.
     ///
     /// This function is equivalent to using the `+` operator on standard strings.
@@ -297,6 +424,63 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         result
     }
 
+    /// Inserts the given pattern (either encrypted or clear) at the clear byte `index` of an
+    /// encrypted string, and returns the result as a new encrypted string.
+    ///
+    /// The characters from `index` onwards are shifted right. If `index` is greater than the
+    /// length of the string, the pattern is appended at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s, pat) = ("abc", "XY");
+    ///
+    /// let enc_s = FheString::new(&ck, s, Some(2));
+    /// let enc_pat = GenericPattern::Enc(FheString::new(&ck, pat, None));
+    /// let clear_pat = GenericPattern::Clear(ClearString::new(pat.to_string()));
+    ///
+    /// let result_enc = sk.insert(&enc_s, 1, enc_pat.as_ref());
+    /// let result_clear = sk.insert(&enc_s, 10, clear_pat.as_ref());
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result_enc), "aXYbc");
+    /// assert_eq!(ck.decrypt_ascii(&result_clear), "abcXY");
+    /// ```
+    pub fn insert(&self, str: &FheString, index: u32, pat: GenericPatternRef<'_>) -> FheString {
+        let trivial_or_enc_pat = match pat {
+            GenericPatternRef::Clear(pat) => FheString::trivial(self, pat.str()),
+            GenericPatternRef::Enc(pat) => pat.clone(),
+        };
+
+        let index = (index as usize).min(str.len());
+
+        let mut head = FheString {
+            enc_string: str.chars()[..index].to_vec(),
+            padded: false,
+        };
+        let tail = FheString {
+            enc_string: str.chars()[index..].to_vec(),
+            padded: str.is_padded(),
+        };
+
+        // If the string is padded the head may contain nulls (when the actual length is lower than
+        // `index`), in which case the tail only contains nulls and the pattern is appended
+        if str.is_padded() {
+            head.append_null(self);
+        }
+
+        let head_and_pat = self.concat(&head, &trivial_or_enc_pat);
+
+        self.concat(&head_and_pat, &tail)
+    }
+
     /// Returns a new encrypted string which is the original encrypted string repeated `n` times.
     ///
     /// The number of repetitions `n` is specified by a `UIntArg`, which can be either `Clear` or
@@ -375,4 +559,237 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         result
     }
+
+    /// Extends an encrypted string to the clear length `len` by appending copies of the given
+    /// pattern (either encrypted or clear), the last copy being truncated if needed.
+    ///
+    /// If the string is already at least `len` characters long, or if the pattern is empty, the
+    /// string is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s, fill) = ("ab", "-");
+    ///
+    /// let enc_s = FheString::new(&ck, s, Some(1));
+    /// let enc_fill = GenericPattern::Enc(FheString::new(&ck, "xy", None));
+    /// let clear_fill = GenericPattern::Clear(ClearString::new(fill.to_string()));
+    ///
+    /// let result_enc = sk.fill_to(&enc_s, enc_fill.as_ref(), 5);
+    /// let result_clear = sk.fill_to(&enc_s, clear_fill.as_ref(), 5);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result_enc), "abxyx");
+    /// assert_eq!(ck.decrypt_ascii(&result_clear), "ab---");
+    /// ```
+    pub fn fill_to(&self, str: &FheString, pat: GenericPatternRef<'_>, len: u32) -> FheString {
+        let sk = self.inner();
+        let len = len as usize;
+
+        let trivial_or_enc_pat = match pat {
+            GenericPatternRef::Clear(pat) => FheString::trivial(self, pat.str()),
+            GenericPatternRef::Enc(pat) => pat.clone(),
+        };
+        let pat_chars = trivial_or_enc_pat.chars();
+
+        if pat_chars.is_empty() || (str.len() >= len && !str.is_padded()) {
+            return str.clone();
+        }
+
+        // The pattern repeated up to `len` chars
+        let fill = match self.len(&trivial_or_enc_pat) {
+            FheStringLen::NoPadding(pat_len) => FheString {
+                enc_string: (0..len).map(|i| pat_chars[i % pat_len].clone()).collect(),
+                padded: false,
+            },
+
+            // The i-th char is the (i % pat_len)-th char of the pattern, we select it among the
+            // possible pattern lengths. If the pattern is empty the fill only contains nulls
+            FheStringLen::Padding(pat_len) => {
+                let is_pat_len: Vec<_> = (1..=pat_chars.len())
+                    .into_par_iter()
+                    .map(|possible_len| sk.scalar_eq_parallelized(&pat_len, possible_len as u32))
+                    .collect();
+
+                let enc_string = (0..len)
+                    .into_par_iter()
+                    .map(|i| {
+                        let mut char = FheAsciiChar::null(self);
+                        for (possible_len, is_pat_len) in (1..).zip(is_pat_len.iter()) {
+                            let selected = sk.if_then_else_parallelized(
+                                is_pat_len,
+                                pat_chars[i % possible_len].ciphertext(),
+                                char.ciphertext(),
+                            );
+                            *char.ciphertext_mut() = selected;
+                        }
+                        char
+                    })
+                    .collect();
+
+                // Not marked as padded, as the last char may not be null
+                FheString {
+                    enc_string,
+                    padded: false,
+                }
+            }
+        };
+
+        match self.len(str) {
+            FheStringLen::NoPadding(str_len) => {
+                let fill = FheString {
+                    enc_string: fill.chars()[..len - str_len].to_vec(),
+                    padded: false,
+                };
+
+                let mut result = self.concat(str, &fill);
+
+                // The fill may end with nulls if the pattern is padded
+                if trivial_or_enc_pat.is_padded() {
+                    result.append_null(self);
+                }
+
+                result
+            }
+
+            // The concatenation has str_len + len chars, the ones after index `len` are kept only
+            // if they belong to str
+            FheStringLen::Padding(str_len) => {
+                let mut result = self.concat(str, &fill);
+
+                result.chars_vec().truncate(str.len().max(len));
+
+                let null = FheAsciiChar::null(self);
+                result.chars_mut()[len..]
+                    .par_iter_mut()
+                    .enumerate()
+                    .for_each(|(i, char)| {
+                        let is_in_str = sk.scalar_gt_parallelized(&str_len, (len + i) as u32);
+                        *char.ciphertext_mut() = sk.if_then_else_parallelized(
+                            &is_in_str,
+                            char.ciphertext(),
+                            null.ciphertext(),
+                        );
+                    });
+
+                // Otherwise the last char is a null of str
+                if len >= str.len() {
+                    result.append_null(self);
+                }
+
+                result
+            }
+        }
+    }
+
+    /// Returns a new encrypted string with the characters of the input in reverse order.
+    ///
+    /// Only the actual characters are reversed, the padding nulls (if any) stay at the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (abc, ab) = ("abc", "ab");
+    ///
+    /// let enc_abc = FheString::new(&ck, abc, None);
+    /// let enc_ab = FheString::new(&ck, ab, Some(2));
+    ///
+    /// let result1 = sk.reverse(&enc_abc);
+    /// let result2 = sk.reverse(&enc_ab);
+    ///
+    /// assert_eq!(ck.decrypt_ascii(&result1), "cba");
+    /// assert_eq!(ck.decrypt_ascii(&result2), "ba");
+    /// ```
+    pub fn reverse(&self, str: &FheString) -> FheString {
+        let sk = self.inner();
+
+        let mut result = str.clone();
+        result.chars_vec().reverse();
+
+        match self.len(str) {
+            FheStringLen::NoPadding(_) => result,
+
+            // The nulls are now at the start, we shift the chars left to move them back to the end
+            FheStringLen::Padding(len) => {
+                let padded_len = sk.create_trivial_radix(str.len() as u32, 16);
+                let number_of_nulls = sk.sub_parallelized(&padded_len, &len);
+
+                result = self.left_shift_chars(&result, &number_of_nulls);
+
+                result.set_is_padded(true);
+
+                result
+            }
+        }
+    }
+
+    /// Returns a new encrypted string where every occurrence of the clear byte `from` is replaced
+    /// by the clear byte `to`.
+    ///
+    /// This only needs a comparison and a selection per character, so it is cheaper than
+    /// [`Self::replace`] for single character substitutions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is not ASCII, or if `to` is 0 (null bytes are reserved for the
+    /// padding).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "a b c";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let result = sk.translate(&enc_s, b' ', b'_');
+    /// let translated = ck.decrypt_ascii(&result);
+    ///
+    /// assert_eq!(translated, "a_b_c");
+    /// ```
+    pub fn translate(&self, str: &FheString, from: u8, to: u8) -> FheString {
+        assert!(from.is_ascii() && to.is_ascii(), "Bytes must be ASCII");
+        assert_ne!(to, 0, "Cannot translate a character into a null byte");
+
+        let sk = self.inner();
+
+        let mut result = str.clone();
+
+        // Nulls are only used as padding, there is no actual character to translate
+        if from == 0 || from == to {
+            return result;
+        }
+
+        let to = sk.create_trivial_radix(to, self.num_ascii_blocks());
+
+        result.chars_mut().par_iter_mut().for_each(|char| {
+            let is_from = sk.scalar_eq_parallelized(char.ciphertext(), from);
+
+            *char.ciphertext_mut() = sk.if_then_else_parallelized(&is_from, &to, char.ciphertext());
+        });
+
+        result
+    }
 }
@@ -3,7 +3,7 @@ use crate::integer::{
     BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey as IntegerServerKey,
 };
 use crate::strings::char_iter::CharIter;
-use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPatternRef};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPattern, GenericPatternRef};
 use crate::strings::server_key::pattern::IsMatch;
 use crate::strings::server_key::ServerKey;
 use itertools::Itertools;
@@ -286,4 +286,184 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             }
         }
     }
+
+    /// Returns `true` if any of the given patterns (either encrypted or clear) matches a prefix
+    /// of this encrypted string.
+    ///
+    /// Returns `false` if none of the patterns match the prefix, including when `pats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (bananas, ba, nan) = ("bananas", "ba", "nan");
+    ///
+    /// let enc_bananas = FheString::new(&ck, bananas, None);
+    /// let enc_ba = GenericPattern::Enc(FheString::new(&ck, ba, None));
+    /// let clear_nan = GenericPattern::Clear(ClearString::new(nan.to_string()));
+    ///
+    /// let result1 = sk.starts_with_any(&enc_bananas, &[enc_ba, clear_nan.clone()]);
+    /// let result2 = sk.starts_with_any(&enc_bananas, &[clear_nan]);
+    ///
+    /// let should_be_true = ck.inner().decrypt_bool(&result1);
+    /// let should_be_false = ck.inner().decrypt_bool(&result2);
+    ///
+    /// assert!(should_be_true);
+    /// assert!(!should_be_false);
+    /// ```
+    pub fn starts_with_any(&self, str: &FheString, pats: &[GenericPattern]) -> BooleanBlock {
+        let sk = self.inner();
+
+        pats.par_iter()
+            .map(|pat| self.starts_with(str, pat.as_ref()))
+            .reduce_with(|a, b| sk.boolean_bitor(&a, &b))
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
+    /// Returns `true` if any of the given patterns (either encrypted or clear) matches a suffix
+    /// of this encrypted string.
+    ///
+    /// Returns `false` if none of the patterns match the suffix, including when `pats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (bananas, anas, nana) = ("bananas", "anas", "nana");
+    ///
+    /// let enc_bananas = FheString::new(&ck, bananas, None);
+    /// let enc_anas = GenericPattern::Enc(FheString::new(&ck, anas, None));
+    /// let clear_nana = GenericPattern::Clear(ClearString::new(nana.to_string()));
+    ///
+    /// let result1 = sk.ends_with_any(&enc_bananas, &[clear_nana.clone(), enc_anas]);
+    /// let result2 = sk.ends_with_any(&enc_bananas, &[clear_nana]);
+    ///
+    /// let should_be_true = ck.inner().decrypt_bool(&result1);
+    /// let should_be_false = ck.inner().decrypt_bool(&result2);
+    ///
+    /// assert!(should_be_true);
+    /// assert!(!should_be_false);
+    /// ```
+    pub fn ends_with_any(&self, str: &FheString, pats: &[GenericPattern]) -> BooleanBlock {
+        let sk = self.inner();
+
+        pats.par_iter()
+            .map(|pat| self.ends_with(str, pat.as_ref()))
+            .reduce_with(|a, b| sk.boolean_bitor(&a, &b))
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
+    /// Returns `true` if the given clear ASCII character is contained in this encrypted string.
+    ///
+    /// Returns `false` if the character is not found. As an encrypted string cannot contain null
+    /// characters (the padding ones are not part of the string), searching for `0` always returns
+    /// `false`.
+    ///
+    /// This is cheaper than [`Self::contains`] with a single char pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (axb, ab) = ("axb", "ab");
+    ///
+    /// let enc_axb = FheString::new(&ck, axb, None);
+    /// let enc_ab = FheString::new(&ck, ab, Some(1));
+    ///
+    /// let result1 = sk.contains_char(&enc_axb, b'x');
+    /// let result2 = sk.contains_char(&enc_ab, b'x');
+    ///
+    /// let should_be_true = ck.inner().decrypt_bool(&result1);
+    /// let should_be_false = ck.inner().decrypt_bool(&result2);
+    ///
+    /// assert!(should_be_true);
+    /// assert!(!should_be_false);
+    /// ```
+    pub fn contains_char(&self, str: &FheString, c: u8) -> BooleanBlock {
+        let sk = self.inner();
+
+        assert!(c.is_ascii(), "The character must be ASCII");
+
+        if c == 0 {
+            return sk.create_trivial_boolean_block(false);
+        }
+
+        str.chars()
+            .par_iter()
+            .map(|char| sk.scalar_eq_parallelized(char.ciphertext(), c))
+            .reduce_with(|a, b| sk.boolean_bitor(&a, &b))
+            .unwrap_or_else(|| sk.create_trivial_boolean_block(false))
+    }
+
+    /// Returns the number of occurrences of the given clear ASCII character in this encrypted
+    /// string, as an encrypted `RadixCiphertext`.
+    ///
+    /// As padding nulls are not part of the string, counting `0` always returns 0.
+    ///
+    /// This is cheaper than counting the matches of a single char pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (banana, xyz) = ("banana", "xyz");
+    ///
+    /// let enc_banana = FheString::new(&ck, banana, None);
+    /// let enc_xyz = FheString::new(&ck, xyz, Some(1));
+    ///
+    /// let result1 = sk.count_char(&enc_banana, b'a');
+    /// let result2 = sk.count_char(&enc_xyz, b'a');
+    ///
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&result1), 3);
+    /// assert_eq!(ck.inner().decrypt_radix::<u32>(&result2), 0);
+    /// ```
+    pub fn count_char(&self, str: &FheString, c: u8) -> RadixCiphertext {
+        let sk = self.inner();
+
+        assert!(c.is_ascii(), "The character must be ASCII");
+
+        if c == 0 {
+            return sk.create_trivial_zero_radix(16);
+        }
+
+        let is_c: Vec<_> = str
+            .chars()
+            .par_iter()
+            .map(|char| {
+                let bool = sk.scalar_eq_parallelized(char.ciphertext(), c);
+                bool.into_radix(16, sk)
+            })
+            .collect();
+
+        sk.sum_ciphertexts_parallelized(is_c.iter())
+            .unwrap_or_else(|| sk.create_trivial_zero_radix(16))
+    }
 }
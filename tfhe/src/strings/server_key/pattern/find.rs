@@ -168,6 +168,61 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         }
     }
 
+    /// Returns a tuple containing the byte index of the first character of this encrypted string
+    /// that matches the given encrypted pattern, the encrypted length of the match, and a boolean
+    /// indicating if a match was found.
+    ///
+    /// As a padded encrypted pattern has an unknown length, the matched length allows advancing
+    /// over the match. If the pattern doesn’t match, the index and the length are both 0 and the
+    /// boolean is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (haystack, needle) = ("hello world", "world");
+    ///
+    /// let enc_haystack = FheString::new(&ck, haystack, None);
+    /// let enc_needle = FheString::new(&ck, needle, Some(2));
+    ///
+    /// let (index, len, found) = sk.find_with_len(&enc_haystack, &enc_needle);
+    ///
+    /// let index = ck.inner().decrypt_radix::<u32>(&index);
+    /// let len = ck.inner().decrypt_radix::<u32>(&len);
+    /// let found = ck.inner().decrypt_bool(&found);
+    ///
+    /// assert!(found);
+    /// assert_eq!(index, 6);
+    /// assert_eq!(len, 5);
+    /// ```
+    pub fn find_with_len(
+        &self,
+        str: &FheString,
+        pat: &FheString,
+    ) -> (RadixCiphertext, RadixCiphertext, BooleanBlock) {
+        let sk = self.inner();
+
+        let ((index, found), pat_len) = rayon::join(
+            || self.find(str, GenericPatternRef::Enc(pat)),
+            || match self.len(pat) {
+                FheStringLen::Padding(cipher_len) => cipher_len,
+                FheStringLen::NoPadding(len) => sk.create_trivial_radix(len as u32, 16),
+            },
+        );
+
+        let zero = sk.create_trivial_zero_radix(16);
+        let matched_len = sk.if_then_else_parallelized(&found, &pat_len, &zero);
+
+        (index, matched_len, found)
+    }
+
     /// Returns a tuple containing the byte index of the first character from the end of this
     /// encrypted string that matches the given pattern (either encrypted or clear), and a
     /// boolean indicating if a match was found.
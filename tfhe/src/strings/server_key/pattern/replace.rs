@@ -1,8 +1,11 @@
 use crate::integer::prelude::*;
 use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
-use crate::strings::ciphertext::{FheString, GenericPatternRef, UIntArg};
+use crate::strings::ciphertext::{
+    ClearString, FheAsciiChar, FheString, GenericPatternRef, UIntArg,
+};
 use crate::strings::server_key::pattern::IsMatch;
 use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
+use rayon::prelude::*;
 use std::borrow::Borrow;
 
 impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
@@ -155,6 +158,196 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         }
     }
 
+    // Returns, for each char index of `str`, whether a match of the non-empty `pat` starts there.
+    // If `pat` is encrypted it must not be padded, so that its length is known.
+    // Like `str::match_indices`, overlapping matches are discarded scanning from left to right
+    fn match_indices(&self, str: &FheString, pat: GenericPatternRef<'_>) -> Vec<BooleanBlock> {
+        let sk = self.inner();
+
+        let str_len = str.len();
+        let pat_len = known_pattern_len(pat);
+
+        let raw_matches: Vec<_> = (0..str_len)
+            .into_par_iter()
+            .map(|start| {
+                if start + pat_len > str_len {
+                    return sk.create_trivial_boolean_block(false);
+                }
+
+                let window = str.chars()[start..start + pat_len].iter();
+                match pat {
+                    GenericPatternRef::Clear(pat) => self.clear_asciis_eq(window, pat.str()),
+                    GenericPatternRef::Enc(pat) => self.asciis_eq(window, pat.chars().iter()),
+                }
+            })
+            .collect();
+
+        let mut matches: Vec<BooleanBlock> = Vec::with_capacity(str_len);
+
+        for (start, raw_match) in raw_matches.into_iter().enumerate() {
+            // A match is discarded if it overlaps with a previous (kept) match
+            let prev = &matches[(start + 1).saturating_sub(pat_len)..];
+
+            let is_match = match prev.split_first() {
+                Some((first, rest)) => {
+                    let mut overlaps = first.clone();
+                    for prev_match in rest {
+                        sk.boolean_bitor_assign(&mut overlaps, prev_match);
+                    }
+
+                    sk.boolean_bitand(&raw_match, &sk.boolean_bitnot(&overlaps))
+                }
+                None => raw_match,
+            };
+
+            matches.push(is_match);
+        }
+
+        matches
+    }
+
+    // Replaces all the matches of the non-empty `from` (clear, or encrypted and non-padded) by the
+    // non-padded `to` in a single pass. As all the match positions are known upfront each output
+    // char is directly selected among the chars that can land on its position, instead of
+    // searching the str again after every replacement like `replace_n_times` does
+    fn replace_all_known_len(
+        &self,
+        str: &FheString,
+        from: GenericPatternRef<'_>,
+        to: &FheString,
+    ) -> FheString {
+        let sk = self.inner();
+
+        let str_len = str.len();
+        let from_len = known_pattern_len(from);
+        let delta = to.len() as isize - from_len as isize;
+
+        let matches = self.match_indices(str, from);
+
+        // A char is kept if it's not part of any match
+        let kept: Vec<_> = (0..str_len)
+            .into_par_iter()
+            .map(|i| {
+                let mut covered = matches[(i + 1).saturating_sub(from_len)].clone();
+                for is_match in &matches[(i + 1).saturating_sub(from_len) + 1..=i] {
+                    sk.boolean_bitor_assign(&mut covered, is_match);
+                }
+
+                sk.boolean_bitnot(&covered)
+            })
+            .collect();
+
+        // Each match before a char shifts it by `delta` positions. `num_prev_matches[i][k]` is
+        // true iff there are exactly k matches before the i-th char. If `delta` is 0 the number of
+        // matches doesn't change positions, so we don't need to compute it
+        let max_matches = if delta == 0 { 0 } else { str_len / from_len };
+
+        let mut num_prev_matches = Vec::with_capacity(str_len);
+        let mut current: Vec<_> = (0..=max_matches)
+            .map(|k| sk.create_trivial_boolean_block(k == 0))
+            .collect();
+
+        for (i, is_match) in matches.iter().enumerate() {
+            // At most (i / from_len) + 1 matches can be found up to the i-th char (included)
+            let reachable = max_matches.min(i / from_len + 1);
+            let not_match = sk.boolean_bitnot(is_match);
+
+            let next = (0..=max_matches)
+                .into_par_iter()
+                .map(|k| {
+                    if k > reachable {
+                        return sk.create_trivial_boolean_block(false);
+                    }
+
+                    let stays = sk.boolean_bitand(&not_match, &current[k]);
+
+                    if k == 0 {
+                        stays
+                    } else {
+                        let moves = sk.boolean_bitand(is_match, &current[k - 1]);
+                        sk.boolean_bitor(&stays, &moves)
+                    }
+                })
+                .collect();
+
+            num_prev_matches.push(std::mem::replace(&mut current, next));
+        }
+
+        // For each char index `i` and number of previous matches `k`, the conditions under which
+        // str[i] is kept at `i + delta * k`, and `to` is written starting at `i + delta * k`
+        let conditions: Vec<Vec<(BooleanBlock, BooleanBlock)>> = (0..str_len)
+            .into_par_iter()
+            .map(|i| {
+                (0..=max_matches.min(i / from_len))
+                    .into_par_iter()
+                    .map(|k| {
+                        if delta == 0 {
+                            (kept[i].clone(), matches[i].clone())
+                        } else {
+                            rayon::join(
+                                || sk.boolean_bitand(&kept[i], &num_prev_matches[i][k]),
+                                || sk.boolean_bitand(&matches[i], &num_prev_matches[i][k]),
+                            )
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let out_len = if delta > 0 {
+            str_len + delta as usize * max_matches
+        } else {
+            str_len
+        };
+
+        let mut candidates: Vec<Vec<(&BooleanBlock, &RadixCiphertext)>> = vec![vec![]; out_len];
+
+        for (i, conditions_i) in conditions.iter().enumerate() {
+            for (k, (keep_cond, match_cond)) in conditions_i.iter().enumerate() {
+                let pos = (i as isize + delta * k as isize) as usize;
+
+                candidates[pos].push((keep_cond, str.chars()[i].ciphertext()));
+
+                // No match can start at the last `from_len - 1` chars
+                if i + from_len > str_len {
+                    continue;
+                }
+
+                for (j, to_char) in to.chars().iter().enumerate() {
+                    candidates[pos + j].push((match_cond, to_char.ciphertext()));
+                }
+            }
+        }
+
+        let zero = sk.create_trivial_zero_radix(self.num_ascii_blocks());
+
+        // At most one candidate condition is true for each position, the others are zeroed out
+        let enc_string = candidates
+            .into_par_iter()
+            .map(|pos_candidates| {
+                let enc_char = pos_candidates
+                    .into_par_iter()
+                    .map(|(condition, ct)| sk.if_then_else_parallelized(condition, ct, &zero))
+                    .reduce_with(|acc, ct| sk.bitor_parallelized(&acc, &ct))
+                    .unwrap_or_else(|| zero.clone());
+
+                FheAsciiChar { enc_char }
+            })
+            .collect();
+
+        let mut result = FheString {
+            enc_string,
+            padded: str.is_padded(),
+        };
+
+        // If the length changes there may be nulls at the end
+        if delta != 0 {
+            result.append_null(self);
+        }
+
+        result
+    }
+
     fn no_more_matches(
         &self,
         str_len: &FheStringLen,
@@ -395,12 +588,131 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             _ => (),
         }
 
+        if let GenericPatternRef::Clear(clear_from) = from {
+            if !clear_from.str().is_empty() && !to.is_padded() {
+                return self.replace_all_known_len(str, from, to);
+            }
+        }
+
         let max = max_matches(str, &trivial_or_enc_from);
 
         self.replace_n_times(max, &mut result, from, to, None);
 
         result
     }
+
+    /// Returns a new encrypted string with all non-overlapping occurrences of a pattern (either
+    /// encrypted or clear) replaced by another encrypted pattern of the same length.
+    ///
+    /// As the replacement has the same length as the pattern, the length of the string never
+    /// changes and each match is simply overwritten, which is much cheaper than
+    /// [`Self::replace`].
+    ///
+    /// The pattern to search for can be either `GenericPatternRef::Clear` for a clear string or
+    /// `GenericPatternRef::Enc` for an encrypted string, while the replacement pattern is always
+    /// encrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` or the encrypted `from` is padded (as their length wouldn't be known), or if
+    /// `from` and `to` don't have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s, from, to) = ("ababc", "ab", "XY");
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    /// let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
+    /// let enc_to = FheString::new(&ck, to, None);
+    ///
+    /// let result = sk.replace_equal_len(&enc_s, clear_from.as_ref(), &enc_to);
+    /// let replaced = ck.decrypt_ascii(&result);
+    ///
+    /// assert_eq!(replaced, "XYXYc");
+    /// ```
+    pub fn replace_equal_len(
+        &self,
+        str: &FheString,
+        from: GenericPatternRef<'_>,
+        to: &FheString,
+    ) -> FheString {
+        assert!(
+            !to.is_padded(),
+            "The replacement pattern must not be padded"
+        );
+        if let GenericPatternRef::Enc(from) = from {
+            assert!(
+                !from.is_padded(),
+                "The encrypted pattern must not be padded"
+            );
+        }
+
+        let from_len = known_pattern_len(from);
+        assert_eq!(
+            from_len,
+            to.len(),
+            "The pattern and its replacement must have the same length"
+        );
+
+        // An empty pattern is replaced by an empty pattern, and a pattern longer than str can't
+        // match
+        if from_len == 0 || from_len > str.len() {
+            return str.clone();
+        }
+
+        self.replace_all_known_len(str, from, to)
+    }
+
+    /// Returns a new encrypted string where all the "\r\n" sequences are replaced by "\n".
+    ///
+    /// A "\r" which is not directly followed by a "\n" is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = "a\r\nb\r";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let result = sk.normalize_newlines(&enc_s);
+    /// let normalized = ck.decrypt_ascii(&result);
+    ///
+    /// assert_eq!(normalized, "a\nb\r");
+    /// ```
+    pub fn normalize_newlines(&self, str: &FheString) -> FheString {
+        let crlf = ClearString::new("\r\n".to_string());
+        let lf = FheString::trivial(self, "\n");
+
+        self.replace(str, GenericPatternRef::Clear(&crlf), &lf)
+    }
+}
+
+// Length of a pattern that is either clear or encrypted without padding
+fn known_pattern_len(pat: GenericPatternRef<'_>) -> usize {
+    match pat {
+        GenericPatternRef::Clear(pat) => pat.str().len(),
+        GenericPatternRef::Enc(pat) => {
+            debug_assert!(!pat.is_padded());
+            pat.len()
+        }
+    }
 }
 
 fn max_matches(str: &FheString, pat: &FheString) -> u16 {
@@ -1,6 +1,6 @@
 use super::{clear_ends_with_cases, ends_with_cases};
 use crate::integer::prelude::*;
-use crate::integer::{BooleanBlock, ServerKey as IntegerServerKey};
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
 use crate::strings::char_iter::CharIter;
 use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPatternRef};
 use crate::strings::server_key::pattern::IsMatch;
@@ -143,6 +143,47 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         str: &FheString,
         pat: GenericPatternRef<'_>,
     ) -> (FheString, BooleanBlock) {
+        let (result, _, starts_with) = self.strip_prefix_with_len(str, pat);
+
+        (result, starts_with)
+    }
+
+    /// Same as [`Self::strip_prefix`], but also returns the number of characters that were
+    /// removed from the start of the encrypted string.
+    ///
+    /// This is the length of the pattern if it matched, or `0` otherwise. It is mostly useful
+    /// when the pattern is an encrypted (possibly padded) string, as its length is then unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s, prefix) = ("hello world", "hello");
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    /// let enc_prefix = GenericPattern::Enc(FheString::new(&ck, prefix, Some(2)));
+    ///
+    /// let (result, removed_len, found) = sk.strip_prefix_with_len(&enc_s, enc_prefix.as_ref());
+    /// let stripped = ck.decrypt_ascii(&result);
+    /// let removed_len = ck.inner().decrypt_radix::<u32>(&removed_len);
+    /// let found = ck.inner().decrypt_bool(&found);
+    ///
+    /// assert!(found);
+    /// assert_eq!(stripped, " world");
+    /// assert_eq!(removed_len, 5);
+    /// ```
+    pub fn strip_prefix_with_len(
+        &self,
+        str: &FheString,
+        pat: GenericPatternRef<'_>,
+    ) -> (FheString, RadixCiphertext, BooleanBlock) {
         let sk = self.inner();
 
         let mut result = str.clone();
@@ -153,10 +194,16 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
 
         match self.length_checks(str, &trivial_or_enc_pat) {
             // If IsMatch is Clear we return the same string (a true means the pattern is empty)
-            IsMatch::Clear(bool) => return (result, sk.create_trivial_boolean_block(bool)),
+            IsMatch::Clear(bool) => {
+                return (
+                    result,
+                    sk.create_trivial_zero_radix(16),
+                    sk.create_trivial_boolean_block(bool),
+                )
+            }
 
             // If IsMatch is Cipher it means str is empty so in any case we return the same string
-            IsMatch::Cipher(val) => return (result, val),
+            IsMatch::Cipher(val) => return (result, sk.create_trivial_zero_radix(16), val),
             IsMatch::None => (),
         }
 
@@ -187,7 +234,51 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
             result.append_null(self);
         }
 
-        (result, starts_with)
+        (result, shift_left, starts_with)
+    }
+
+    /// Returns a new encrypted string with at most `n` consecutive occurrences of the specified
+    /// pattern (either encrypted or clear) removed from the start of this encrypted string.
+    ///
+    /// The pattern to search for can be specified as either `GenericPatternRef::Clear` for a clear
+    /// string or `GenericPatternRef::Enc` for an encrypted string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let (s, prefix) = ("ababab", "ab");
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    /// let clear_prefix = GenericPattern::Clear(ClearString::new(prefix.to_string()));
+    ///
+    /// let result = sk.trim_start_matches_n(&enc_s, clear_prefix.as_ref(), 2);
+    /// let trimmed = ck.decrypt_ascii(&result);
+    ///
+    /// assert_eq!(trimmed, "ab"); // Only the first two "ab" are removed
+    /// ```
+    pub fn trim_start_matches_n(
+        &self,
+        str: &FheString,
+        pat: GenericPatternRef<'_>,
+        n: u32,
+    ) -> FheString {
+        let mut result = str.clone();
+
+        // Once the pattern does not match, the string is left unchanged, so the next
+        // iterations will not match either
+        for _ in 0..n {
+            (result, _) = self.strip_prefix(&result, pat);
+        }
+
+        result
     }
 
     /// Returns a new encrypted string with the specified pattern (either encrypted or clear)
@@ -119,9 +119,116 @@ impl SplitAsciiWhitespace {
     }
 }
 
+pub struct SplitInclusiveWhitespace {
+    state: FheString,
+}
+
+impl<T: Borrow<IntegerServerKey> + Sync> FheStringIterator<T> for SplitInclusiveWhitespace {
+    fn next(&mut self, sk: &ServerKey<T>) -> (FheString, BooleanBlock) {
+        let sk_integer = sk.inner();
+
+        if self.state.is_empty() {
+            return (
+                FheString::empty(),
+                sk_integer.create_trivial_boolean_block(false),
+            );
+        }
+
+        // The remaining string is empty when only nulls are left
+        let is_some = match sk.is_empty(&self.state) {
+            FheStringIsEmpty::Padding(val) => sk_integer.boolean_bitnot(&val),
+            FheStringIsEmpty::NoPadding(val) => sk_integer.create_trivial_boolean_block(!val),
+        };
+
+        (self.next_segment(sk), is_some)
+    }
+}
+
+impl SplitInclusiveWhitespace {
+    // Returns the first segment of the state, i.e. the chars up to (excluded) the first non
+    // whitespace char that follows a whitespace, and removes it from the state
+    fn next_segment<T: Borrow<IntegerServerKey> + Sync>(&mut self, sk: &ServerKey<T>) -> FheString {
+        let sk_integer = sk.inner();
+
+        let is_ws: Vec<BooleanBlock> = self
+            .state
+            .chars()
+            .par_iter()
+            .map(|char| sk.is_whitespace(char, false))
+            .collect();
+
+        // A new segment starts at each non whitespace char preceded by a whitespace
+        let starts_segment: Vec<BooleanBlock> = is_ws
+            .par_windows(2)
+            .map(|window| {
+                let is_not_ws = sk_integer.boolean_bitnot(&window[1]);
+                sk_integer.boolean_bitand(&window[0], &is_not_ws)
+            })
+            .collect();
+
+        let mut in_segment = vec![sk_integer.create_trivial_boolean_block(true)];
+        for starts_segment in &starts_segment {
+            let not_starts_segment = sk_integer.boolean_bitnot(starts_segment);
+            let prev = in_segment.last().unwrap();
+            in_segment.push(sk_integer.boolean_bitand(prev, &not_starts_segment));
+        }
+
+        let (result, segment_len) = rayon::join(
+            || {
+                let mut result = self.state.clone();
+                result
+                    .chars_mut()
+                    .par_iter_mut()
+                    .zip(in_segment.par_iter())
+                    .for_each(|(char, in_segment)| {
+                        let mut mask_u8 = in_segment
+                            .clone()
+                            .into_radix(sk.num_ascii_blocks(), sk_integer);
+
+                        // 0u8 is kept the same, but 1u8 is transformed into 255u8
+                        sk_integer.scalar_sub_assign_parallelized(&mut mask_u8, 1);
+                        sk_integer.bitnot_assign(&mut mask_u8);
+
+                        sk_integer.bitand_assign_parallelized(char.ciphertext_mut(), &mask_u8);
+                    });
+
+                // The chars after the segment are now null, so the result is padded
+                if !self.state.is_padded() {
+                    result.append_null(sk);
+                }
+                result
+            },
+            || {
+                let in_segment: Vec<RadixCiphertext> = in_segment
+                    .par_iter()
+                    .map(|in_segment| in_segment.clone().into_radix(16, sk_integer))
+                    .collect();
+
+                sk_integer
+                    .sum_ciphertexts_parallelized(in_segment.iter())
+                    .unwrap_or_else(|| sk_integer.create_trivial_zero_radix(16))
+            },
+        );
+
+        let padded = self.state.is_padded();
+
+        self.state = sk.left_shift_chars(&self.state, &segment_len);
+
+        if padded {
+            self.state.set_is_padded(true);
+        } else {
+            // If it was not padded now we cannot assume it's not padded (because of the left shift)
+            // so we add a null to ensure it's always padded
+            self.state.append_null(sk);
+        }
+
+        result
+    }
+}
+
 impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // As specified in https://doc.rust-lang.org/core/primitive.char.html#method.is_ascii_whitespace
-    fn is_whitespace(&self, char: &FheAsciiChar, or_null: bool) -> BooleanBlock {
+    pub(super) fn is_whitespace(&self, char: &FheAsciiChar, or_null: bool) -> BooleanBlock {
         let sk = self.inner();
 
         let (((is_space, is_tab), (is_new_line, is_form_feed)), (is_carriage_return, op_is_null)) =
@@ -326,6 +433,64 @@ impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
         let result = self.trim_start(str);
         self.trim_end(&result)
     }
+
+    /// Returns the number of whitespace-delimited words in the encrypted string, as an encrypted
+    /// `u16`.
+    ///
+    /// This is equivalent to `str.split_ascii_whitespace().count()`, but cheaper than iterating
+    /// with [split_ascii_whitespace] as the words are not extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+    /// use tfhe::strings::ciphertext::FheString;
+    ///
+    /// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+    /// let sk = ServerKey::new_radix_server_key(&ck);
+    /// let ck = tfhe::strings::ClientKey::new(ck);
+    /// let sk = tfhe::strings::ServerKey::new(sk);
+    /// let s = " hello \t world ";
+    ///
+    /// let enc_s = FheString::new(&ck, s, None);
+    ///
+    /// let result = sk.count_words(&enc_s);
+    /// let count = ck.inner().decrypt_radix::<u16>(&result);
+    ///
+    /// assert_eq!(count, 2);
+    /// ```
+    pub fn count_words(&self, str: &FheString) -> RadixCiphertext {
+        let sk = self.inner();
+
+        if str.is_empty() {
+            return sk.create_trivial_zero_radix(16);
+        }
+
+        // Padding nulls are treated as whitespace so that they never start a word
+        let is_ws: Vec<BooleanBlock> = str
+            .chars()
+            .par_iter()
+            .map(|char| self.is_whitespace(char, str.is_padded()))
+            .collect();
+
+        // A word starts at each non whitespace char that is the first one or follows a whitespace
+        let starts_word: Vec<RadixCiphertext> = (0..is_ws.len())
+            .into_par_iter()
+            .map(|i| {
+                let is_not_ws = sk.boolean_bitnot(&is_ws[i]);
+                let starts_word = if i == 0 {
+                    is_not_ws
+                } else {
+                    sk.boolean_bitand(&is_ws[i - 1], &is_not_ws)
+                };
+                starts_word.into_radix(16, sk)
+            })
+            .collect();
+
+        sk.sum_ciphertexts_parallelized(starts_word.iter())
+            .unwrap_or_else(|| sk.create_trivial_zero_radix(16))
+    }
 }
 
 /// Creates an iterator over the substrings of this encrypted string, separated by any amount of
@@ -380,3 +545,55 @@ pub fn split_ascii_whitespace(str: &FheString) -> SplitAsciiWhitespace {
         current_mask: None,
     }
 }
+
+/// Creates an iterator over the substrings of this encrypted string, each substring being followed
+/// by the whitespace that separates it from the next one.
+///
+/// Unlike [`split_ascii_whitespace`], no char is dropped, so concatenating all the substrings gives
+/// back the original string. If the string starts with whitespace, this whitespace is returned as
+/// the first substring.
+///
+/// Each call to `next` on the iterator returns a tuple with the next encrypted substring and a
+/// boolean indicating `Some` (true) or `None` (false) when no more substrings are available.
+///
+/// When the boolean is `true`, the iterator will yield non-empty encrypted substrings. When the
+/// boolean is `false`, the returned encrypted string is always empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use tfhe::integer::{ClientKey, ServerKey};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+/// use tfhe::strings::ciphertext::FheString;
+/// use tfhe::strings::server_key::{split_inclusive_whitespace, FheStringIterator};
+///
+/// let ck = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+/// let sk = ServerKey::new_radix_server_key(&ck);
+/// let ck = tfhe::strings::ClientKey::new(ck);
+/// let sk = tfhe::strings::ServerKey::new(sk);
+/// let s = "hello \t\nworld ";
+///
+/// let enc_s = FheString::new(&ck, s, None);
+///
+/// let mut whitespace_iter = split_inclusive_whitespace(&enc_s);
+/// let (first_item, first_is_some) = whitespace_iter.next(&sk);
+/// let (second_item, second_is_some) = whitespace_iter.next(&sk);
+/// let (empty, no_more_items) = whitespace_iter.next(&sk); // Attempting to get a third item
+///
+/// let first_decrypted = ck.decrypt_ascii(&first_item);
+/// let first_is_some = ck.inner().decrypt_bool(&first_is_some);
+/// let second_decrypted = ck.decrypt_ascii(&second_item);
+/// let second_is_some = ck.inner().decrypt_bool(&second_is_some);
+/// let empty = ck.decrypt_ascii(&empty);
+/// let no_more_items = ck.inner().decrypt_bool(&no_more_items);
+///
+/// assert_eq!(first_decrypted, "hello \t\n");
+/// assert!(first_is_some);
+/// assert_eq!(second_decrypted, "world ");
+/// assert!(second_is_some);
+/// assert_eq!(empty, ""); // There are no more items so we get an empty string
+/// assert!(!no_more_items);
+/// ```
+pub fn split_inclusive_whitespace(str: &FheString) -> SplitInclusiveWhitespace {
+    SplitInclusiveWhitespace { state: str.clone() }
+}
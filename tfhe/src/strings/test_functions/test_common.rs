@@ -1,7 +1,9 @@
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_unsigned::{CpuFunctionExecutor, NotTuple};
-use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey as IntegerServerKey};
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey as IntegerServerKey,
+};
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
 use crate::shortint::PBSParameters;
 use crate::strings::ciphertext::{ClearString, FheString, GenericPattern, GenericPatternRef};
@@ -181,6 +183,64 @@ where
     }
 }
 
+#[test]
+fn ascii_char_count_test_parameterized() {
+    ascii_char_count_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ascii_char_count_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.ascii_char_count(str)
+    });
+    ascii_char_count_test_impl(param, executor);
+}
+
+pub(crate) fn ascii_char_count_test_impl<P, T>(param: P, mut ascii_char_count_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, RadixCiphertext>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    ascii_char_count_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str in ["", "a", "abc"] {
+        for pad in [None, Some(0), Some(1), Some(2)] {
+            let expected_result = str.len() as u32;
+
+            let enc_str = FheString::new_trivial(&cks, str, pad);
+
+            let result = ascii_char_count_executor.execute(&enc_str);
+
+            assert_eq!(cks.inner().decrypt_radix::<u32>(&result), expected_result);
+        }
+    }
+    // encrypted
+    {
+        let str = "abc";
+
+        for pad in [None, Some(1)] {
+            let expected_result = str.len() as u32;
+
+            let enc_str = FheString::new(&cks, str, pad);
+
+            let result = ascii_char_count_executor.execute(&enc_str);
+
+            assert_eq!(cks.inner().decrypt_radix::<u32>(&result), expected_result);
+        }
+    }
+}
+
 #[test]
 fn strip_test_parameterized() {
     strip_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
@@ -289,6 +349,169 @@ pub(crate) fn strip_test_impl<P, T>(
     }
 }
 
+#[test]
+fn strip_prefix_with_len_test_parameterized() {
+    strip_prefix_with_len_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn strip_prefix_with_len_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   str: &FheString,
+                                   pat: GenericPatternRef<'_>| {
+            let sk = ServerKey::new(sk);
+            sk.strip_prefix_with_len(str, pat)
+        });
+    strip_prefix_with_len_test_impl(param, executor);
+}
+
+pub(crate) fn strip_prefix_with_len_test_impl<P, T>(param: P, mut strip_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a FheString, GenericPatternRef<'a>),
+        (FheString, RadixCiphertext, BooleanBlock),
+    >,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    strip_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let assert_result =
+        |str: &str, pat: &str, result: (FheString, RadixCiphertext, BooleanBlock)| {
+            let (expected_str, expected_len, expected_found) =
+                str.strip_prefix(pat).map_or((str, 0, false), |stripped| {
+                    (stripped, pat.len() as u32, true)
+                });
+
+            assert_eq!(expected_str, cks.decrypt_ascii(&result.0));
+            assert_eq!(expected_len, cks.inner().decrypt_radix::<u32>(&result.1));
+            assert_eq!(expected_found, cks.inner().decrypt_bool(&result.2));
+        };
+
+    // trivial
+    for str_pad in 0..2 {
+        for pat_pad in 0..2 {
+            for pat in ["", "a", "abc"] {
+                for str in ["", "a", "abc", "b", "ab", "dddabc", "abceeee"] {
+                    let enc_lhs = FheString::new_trivial(&cks, str, Some(str_pad));
+                    let enc_rhs =
+                        GenericPattern::Enc(FheString::new_trivial(&cks, pat, Some(pat_pad)));
+                    let clear_rhs = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+                    for rhs in [enc_rhs, clear_rhs] {
+                        let result = strip_executor.execute((&enc_lhs, rhs.as_ref()));
+
+                        assert_result(str, pat, result);
+                    }
+                }
+            }
+        }
+    }
+    // encrypted, with a padded pattern
+    {
+        let str_pad = 1;
+        let pat_pad = 2;
+
+        for (str, pat) in [("abcd", "ab"), ("abcd", "bc"), ("ab", "abc")] {
+            let enc_lhs = FheString::new(&cks, str, Some(str_pad));
+            let enc_rhs = GenericPattern::Enc(FheString::new(&cks, pat, Some(pat_pad)));
+
+            let result = strip_executor.execute((&enc_lhs, enc_rhs.as_ref()));
+
+            assert_result(str, pat, result);
+        }
+    }
+}
+
+#[test]
+fn trim_start_matches_n_test_parameterized() {
+    trim_start_matches_n_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn trim_start_matches_n_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   str: &FheString,
+                                   pat: GenericPatternRef<'_>,
+                                   n: u32| {
+            let sk = ServerKey::new(sk);
+            sk.trim_start_matches_n(str, pat, n)
+        });
+    trim_start_matches_n_test_impl(param, executor);
+}
+
+pub(crate) fn trim_start_matches_n_test_impl<P, T>(param: P, mut trim_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, GenericPatternRef<'a>, u32), FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    trim_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_trim_start_matches_n = |mut str: &str, pat: &str, n: u32| {
+        for _ in 0..n {
+            str = str.strip_prefix(pat).unwrap_or(str);
+        }
+        str.to_string()
+    };
+
+    // trivial
+    for str_pad in 0..2 {
+        for (str, pat) in [
+            ("ababab", "ab"),
+            ("abab", "ab"),
+            ("abcab", "ab"),
+            ("aa", ""),
+        ] {
+            for n in 0..4 {
+                let expected = clear_trim_start_matches_n(str, pat, n);
+
+                let enc_lhs = FheString::new_trivial(&cks, str, Some(str_pad));
+                let enc_rhs = GenericPattern::Enc(FheString::new_trivial(&cks, pat, Some(1)));
+                let clear_rhs = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+                for rhs in [enc_rhs, clear_rhs] {
+                    let result = trim_executor.execute((&enc_lhs, rhs.as_ref(), n));
+
+                    assert_eq!(expected, cks.decrypt_ascii(&result));
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let (str, pat, n) = ("ababab", "ab", 2);
+
+        let enc_lhs = FheString::new(&cks, str, Some(1));
+        let enc_rhs = GenericPattern::Enc(FheString::new(&cks, pat, Some(1)));
+        let clear_rhs = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+        for rhs in [enc_rhs, clear_rhs] {
+            let result = trim_executor.execute((&enc_lhs, rhs.as_ref(), n));
+
+            assert_eq!("ab", cks.decrypt_ascii(&result));
+        }
+    }
+}
+
 const TEST_CASES_COMP: [&str; 5] = ["", "a", "aa", "ab", "abc"];
 
 #[test]
@@ -391,3 +614,352 @@ pub(crate) fn comp_test_impl<P, T>(
         }
     }
 }
+
+#[test]
+fn is_uniform_test_parameterized() {
+    is_uniform_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn is_uniform_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.is_uniform(str)
+    });
+    is_uniform_test_impl(param, executor);
+}
+
+pub(crate) fn is_uniform_test_impl<P, T>(param: P, mut is_uniform_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    is_uniform_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_is_uniform = |str: &str| !str.is_empty() && str.chars().all(|c| str.starts_with(c));
+
+    // trivial
+    for str in ["", "a", "aaaa", "aaab", "baaa", "abab"] {
+        for pad in 0..3 {
+            let expected_result = clear_is_uniform(str);
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let result = is_uniform_executor.execute(&enc_str);
+
+            assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+        }
+    }
+    // encrypted
+    {
+        let pad = 1;
+
+        for str in ["", "a", "aa", "ab"] {
+            let expected_result = clear_is_uniform(str);
+
+            let enc_str = FheString::new(&cks, str, Some(pad));
+
+            let result = is_uniform_executor.execute(&enc_str);
+
+            assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+        }
+    }
+}
+
+#[test]
+fn is_palindrome_test_parameterized() {
+    is_palindrome_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn is_palindrome_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.is_palindrome(str)
+    });
+    is_palindrome_test_impl(param, executor);
+}
+
+pub(crate) fn is_palindrome_test_impl<P, T>(param: P, mut is_palindrome_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    is_palindrome_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_is_palindrome = |str: &str| str.bytes().eq(str.bytes().rev());
+
+    // trivial
+    for str in ["", "a", "aba", "abc", "noon", "noan", "ab", "aa"] {
+        for pad in 0..3 {
+            let expected_result = clear_is_palindrome(str);
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            let result = is_palindrome_executor.execute(&enc_str);
+
+            assert_eq!(
+                cks.inner().decrypt_bool(&result),
+                expected_result,
+                "Invalid result for {str:?} with padding {pad}"
+            );
+        }
+    }
+    // encrypted
+    {
+        for (str, pad) in [("aba", 0), ("abc", 0), ("", 0), ("noon", 2)] {
+            let expected_result = clear_is_palindrome(str);
+
+            let enc_str = FheString::new(&cks, str, Some(pad));
+
+            let result = is_palindrome_executor.execute(&enc_str);
+
+            assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+        }
+    }
+}
+
+#[test]
+fn common_prefix_len_test_parameterized() {
+    common_prefix_len_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn common_prefix_len_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   lhs: &FheString,
+                                   rhs: GenericPatternRef<'_>| {
+            let sk = ServerKey::new(sk);
+            sk.common_prefix_len(lhs, rhs)
+        });
+    common_prefix_len_test_impl(param, executor);
+}
+
+pub(crate) fn common_prefix_len_test_impl<P, T>(param: P, mut common_prefix_len_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, GenericPatternRef<'a>), RadixCiphertext>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    common_prefix_len_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_common_prefix_len = |lhs: &str, rhs: &str| {
+        lhs.bytes()
+            .zip(rhs.bytes())
+            .take_while(|(l, r)| l == r)
+            .count() as u32
+    };
+
+    // trivial
+    for (str, rhs) in [
+        ("", ""),
+        ("", "ab"),
+        ("abcd", "abxy"),
+        ("abcd", "abcd"),
+        ("abcd", "ab"),
+        ("ab", "abcd"),
+        ("abcd", "xbcd"),
+    ] {
+        for (str_pad, rhs_pad) in [(0, 0), (0, 2), (2, 0), (1, 1)] {
+            let expected_result = clear_common_prefix_len(str, rhs);
+
+            let enc_lhs = FheString::new_trivial(&cks, str, Some(str_pad));
+            let enc_rhs = GenericPattern::Enc(FheString::new_trivial(&cks, rhs, Some(rhs_pad)));
+            let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+            for rhs in [enc_rhs, clear_rhs] {
+                let result = common_prefix_len_executor.execute((&enc_lhs, rhs.as_ref()));
+
+                assert_eq!(cks.inner().decrypt_radix::<u32>(&result), expected_result);
+            }
+        }
+    }
+    // encrypted
+    {
+        let (str_pad, rhs_pad) = (1, 1);
+
+        for (str, rhs) in [("ab", "ax"), ("ab", "ab")] {
+            let expected_result = clear_common_prefix_len(str, rhs);
+
+            let enc_lhs = FheString::new(&cks, str, Some(str_pad));
+            let enc_rhs = GenericPattern::Enc(FheString::new(&cks, rhs, Some(rhs_pad)));
+            let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+            for rhs in [enc_rhs, clear_rhs] {
+                let result = common_prefix_len_executor.execute((&enc_lhs, rhs.as_ref()));
+
+                assert_eq!(cks.inner().decrypt_radix::<u32>(&result), expected_result);
+            }
+        }
+    }
+}
+
+#[test]
+fn bytes_eq_test_parameterized() {
+    bytes_eq_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn bytes_eq_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString, bytes: &[u8]| {
+            let sk = ServerKey::new(sk);
+            sk.bytes_eq(str, bytes)
+        });
+    bytes_eq_test_impl(param, executor);
+}
+
+pub(crate) fn bytes_eq_test_impl<P, T>(param: P, mut bytes_eq_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, &'a [u8]), BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    bytes_eq_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let bytes_list: [&[u8]; 6] = [b"", b"secret", b"secreT", b"Secret", b"secre", b"secrets"];
+
+    // trivial
+    for str in ["", "secret"] {
+        for pad in 0..3 {
+            let enc_str = FheString::new_trivial(&cks, str, Some(pad));
+
+            for bytes in bytes_list {
+                let expected_result = str.as_bytes() == bytes;
+
+                let result = bytes_eq_executor.execute((&enc_str, bytes));
+
+                assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+            }
+        }
+    }
+    // encrypted
+    {
+        let pad = 1;
+        let str = "secret";
+
+        let enc_str = FheString::new(&cks, str, Some(pad));
+
+        for bytes in [b"secret", b"secreT"] {
+            let expected_result = str.as_bytes() == bytes;
+
+            let result = bytes_eq_executor.execute((&enc_str, bytes.as_slice()));
+
+            assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+        }
+    }
+}
+
+#[test]
+fn eq_any_test_parameterized() {
+    eq_any_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn eq_any_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   lhs: &FheString,
+                                   rhs: &[GenericPattern]| {
+            let sk = ServerKey::new(sk);
+            sk.eq_any(lhs, rhs)
+        });
+    eq_any_test_impl(param, executor);
+}
+
+pub(crate) fn eq_any_test_impl<P, T>(param: P, mut eq_any_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, &'a [GenericPattern]), BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    eq_any_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let candidates = ["yes", "no", "maybe"];
+
+    // trivial
+    for str in ["", "yes", "no", "maybe", "ye", "yess", "nope"] {
+        for pad in 0..2 {
+            for pats in [&[][..], &candidates[..]] {
+                let expected_result = pats.contains(&str);
+
+                let enc_lhs = FheString::new_trivial(&cks, str, Some(pad));
+                let enc_rhs = pats
+                    .iter()
+                    .map(|pat| GenericPattern::Enc(FheString::new_trivial(&cks, pat, Some(1))))
+                    .collect::<Vec<_>>();
+                let clear_rhs = pats
+                    .iter()
+                    .map(|pat| GenericPattern::Clear(ClearString::new(pat.to_string())))
+                    .collect::<Vec<_>>();
+
+                for rhs in [enc_rhs, clear_rhs] {
+                    let result = eq_any_executor.execute((&enc_lhs, &rhs));
+
+                    assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let pad = 1;
+
+        for str in ["no", "nope"] {
+            let expected_result = candidates.contains(&str);
+
+            let enc_lhs = FheString::new(&cks, str, Some(pad));
+            let enc_rhs = candidates
+                .iter()
+                .map(|pat| GenericPattern::Enc(FheString::new(&cks, pat, Some(pad))))
+                .collect::<Vec<_>>();
+
+            let result = eq_any_executor.execute((&enc_lhs, &enc_rhs));
+
+            assert_eq!(cks.inner().decrypt_bool(&result), expected_result);
+        }
+    }
+}
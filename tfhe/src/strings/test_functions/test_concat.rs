@@ -4,7 +4,9 @@ use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecu
 use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey as IntegerServerKey};
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
 use crate::shortint::PBSParameters;
-use crate::strings::ciphertext::{FheString, UIntArg};
+use crate::strings::ciphertext::{
+    ClearString, FheString, GenericPattern, GenericPatternRef, UIntArg,
+};
 use crate::strings::client_key::ClientKey;
 use crate::strings::server_key::ServerKey;
 use std::sync::Arc;
@@ -76,6 +78,91 @@ where
     }
 }
 
+#[test]
+fn insert_test_parameterized() {
+    insert_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn insert_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   str: &FheString,
+                                   index: u32,
+                                   pat: GenericPatternRef<'_>| {
+            let sk = ServerKey::new(sk);
+            sk.insert(str, index, pat)
+        });
+    insert_test_impl(param, executor);
+}
+
+pub(crate) fn insert_test_impl<P, T>(param: P, mut insert_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, u32, GenericPatternRef<'a>), FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    insert_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_insert = |str: &str, index: u32, pat: &str| {
+        let index = (index as usize).min(str.len());
+        let mut result = str.to_owned();
+        result.insert_str(index, pat);
+        result
+    };
+
+    // trivial
+    for str_pad in [None, Some(1), Some(2)] {
+        for pat_pad in [None, Some(1)] {
+            for str in ["", "a", "abc"] {
+                for pat in ["", "XY"] {
+                    for index in [0, 1, 3, 5] {
+                        let expected_result = clear_insert(str, index, pat);
+
+                        let enc_str = FheString::new_trivial(&cks, str, str_pad);
+                        let enc_pat =
+                            GenericPattern::Enc(FheString::new_trivial(&cks, pat, pat_pad));
+                        let clear_pat = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+                        for pat in [enc_pat, clear_pat] {
+                            let result = insert_executor.execute((&enc_str, index, pat.as_ref()));
+
+                            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str = "abc";
+        let pat = "XY";
+
+        for (str_pad, index) in [(None, 1), (Some(1), 1), (Some(1), 5)] {
+            let expected_result = clear_insert(str, index, pat);
+
+            let enc_str = FheString::new(&cks, str, str_pad);
+            let enc_pat = GenericPattern::Enc(FheString::new(&cks, pat, Some(1)));
+            let clear_pat = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+            for pat in [enc_pat, clear_pat] {
+                let result = insert_executor.execute((&enc_str, index, pat.as_ref()));
+
+                assert_eq!(expected_result, cks.decrypt_ascii(&result));
+            }
+        }
+    }
+}
+
 #[test]
 fn repeat_test_parameterized() {
     repeat_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
@@ -150,3 +237,167 @@ where
         }
     }
 }
+
+#[test]
+fn fill_to_test_parameterized() {
+    fill_to_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn fill_to_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   str: &FheString,
+                                   pat: GenericPatternRef<'_>,
+                                   len: u32| {
+            let sk = ServerKey::new(sk);
+            sk.fill_to(str, pat, len)
+        });
+    fill_to_test_impl(param, executor);
+}
+
+pub(crate) fn fill_to_test_impl<P, T>(param: P, mut fill_to_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, GenericPatternRef<'a>, u32), FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    fill_to_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    let clear_fill_to = |str: &str, pat: &str, len: u32| {
+        let len = len as usize;
+        if str.len() >= len {
+            return str.to_owned();
+        }
+        str.chars()
+            .chain(pat.chars().cycle())
+            .take(if pat.is_empty() { str.len() } else { len })
+            .collect::<String>()
+    };
+
+    // trivial
+    for str_pad in [None, Some(1), Some(2)] {
+        for pat_pad in [None, Some(1)] {
+            for str in ["", "a", "abc"] {
+                for pat in ["", "-", "XY"] {
+                    for len in [0, 2, 5] {
+                        let expected_result = clear_fill_to(str, pat, len);
+
+                        let enc_str = FheString::new_trivial(&cks, str, str_pad);
+                        let enc_pat =
+                            GenericPattern::Enc(FheString::new_trivial(&cks, pat, pat_pad));
+                        let clear_pat = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+                        for pat in [enc_pat, clear_pat] {
+                            let result = fill_to_executor.execute((&enc_str, pat.as_ref(), len));
+
+                            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str = "ab";
+        let pat = "-";
+        let len = 5;
+
+        let expected_result = clear_fill_to(str, pat, len);
+        assert_eq!(expected_result, "ab---");
+
+        for str_pad in [None, Some(1)] {
+            let enc_str = FheString::new(&cks, str, str_pad);
+            let enc_pat = GenericPattern::Enc(FheString::new(&cks, pat, Some(1)));
+            let clear_pat = GenericPattern::Clear(ClearString::new(pat.to_string()));
+
+            for pat in [enc_pat, clear_pat] {
+                let result = fill_to_executor.execute((&enc_str, pat.as_ref(), len));
+
+                assert_eq!(expected_result, cks.decrypt_ascii(&result));
+            }
+        }
+    }
+    // encrypted, already padded patterns: the fill ends with nulls when the actual pattern is
+    // empty, decrypt_ascii checks the result is still null terminated
+    for (pat, pat_pad) in [("-", 2), ("", 1)] {
+        let str = "ab";
+        let len = 5;
+
+        let expected_result = clear_fill_to(str, pat, len);
+
+        for str_pad in [None, Some(1)] {
+            let enc_str = FheString::new(&cks, str, str_pad);
+            let enc_pat = GenericPattern::Enc(FheString::new(&cks, pat, Some(pat_pad)));
+
+            let result = fill_to_executor.execute((&enc_str, enc_pat.as_ref(), len));
+
+            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+        }
+    }
+}
+
+#[test]
+fn reverse_test_parameterized() {
+    reverse_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reverse_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.reverse(str)
+    });
+    reverse_test_impl(param, executor);
+}
+
+pub(crate) fn reverse_test_impl<P, T>(param: P, mut reverse_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    reverse_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str in ["", "a", "ab", "abc", "abcd"] {
+        for pad in [None, Some(1), Some(2)] {
+            let expected_result: String = str.chars().rev().collect();
+
+            let enc_str = FheString::new_trivial(&cks, str, pad);
+
+            let result = reverse_executor.execute(&enc_str);
+
+            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+        }
+    }
+    // encrypted
+    {
+        for (str, pad) in [("abc", None), ("", None), ("ab", Some(1))] {
+            let expected_result: String = str.chars().rev().collect();
+
+            let enc_str = FheString::new(&cks, str, pad);
+
+            let result = reverse_executor.execute(&enc_str);
+
+            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+        }
+    }
+}
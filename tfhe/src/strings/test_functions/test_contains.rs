@@ -1,7 +1,9 @@
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
-use crate::integer::{BooleanBlock, IntegerKeyKind, RadixClientKey, ServerKey as IntegerServerKey};
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey as IntegerServerKey,
+};
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
 use crate::shortint::PBSParameters;
 use crate::strings::ciphertext::{ClearString, FheString, GenericPattern, GenericPatternRef};
@@ -98,7 +100,8 @@ pub(crate) fn contains_test_impl<P, T>(
         let str_pad = 1;
         let rhs_pad = 1;
 
-        for rhs in ["a", "b", "c"] {
+        // "abc" is longer than the haystack
+        for rhs in ["a", "b", "c", "abc"] {
             let expected_result = clear_function(str, rhs);
 
             let enc_lhs = FheString::new(&cks, str, Some(str_pad));
@@ -113,3 +116,233 @@ pub(crate) fn contains_test_impl<P, T>(
         }
     }
 }
+
+#[test]
+fn contains_any_test_parameterized() {
+    contains_any_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn contains_any_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    #[allow(clippy::type_complexity)]
+    let ops: [(
+        for<'a> fn(&'a str, &'a [&'a str]) -> bool,
+        fn(&IntegerServerKey, &FheString, &[GenericPattern]) -> BooleanBlock,
+    ); 2] = [
+        (
+            |lhs, rhs| rhs.iter().any(|pat| lhs.starts_with(pat)),
+            |sk, lhs, rhs| {
+                let sk = ServerKey::new(sk);
+                sk.starts_with_any(lhs, rhs)
+            },
+        ),
+        (
+            |lhs, rhs| rhs.iter().any(|pat| lhs.ends_with(pat)),
+            |sk, lhs, rhs| {
+                let sk = ServerKey::new(sk);
+                sk.ends_with_any(lhs, rhs)
+            },
+        ),
+    ];
+
+    let param = param.into();
+
+    for (clear_op, encrypted_op) in ops {
+        let executor = CpuFunctionExecutor::new(&encrypted_op);
+        contains_any_test_impl(param, executor, clear_op);
+    }
+}
+
+pub(crate) fn contains_any_test_impl<P, T>(
+    param: P,
+    mut contains_any_executor: T,
+    clear_function: for<'a> fn(&'a str, &'a [&'a str]) -> bool,
+) where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, &'a [GenericPattern]), BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    contains_any_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in ["", "https://x", "http://x", "ftp://x", "x.rs"] {
+            for pats in [
+                &[][..],
+                &["http://", "https://"][..],
+                &["x", ".rs", "ftp://"][..],
+            ] {
+                let expected_result = clear_function(str, pats);
+
+                let enc_lhs = FheString::new_trivial(&cks, str, Some(str_pad));
+                let enc_rhs = pats
+                    .iter()
+                    .map(|pat| GenericPattern::Enc(FheString::new_trivial(&cks, pat, Some(1))))
+                    .collect::<Vec<_>>();
+                let clear_rhs = pats
+                    .iter()
+                    .map(|pat| GenericPattern::Clear(ClearString::new(pat.to_string())))
+                    .collect::<Vec<_>>();
+
+                for rhs in [enc_rhs, clear_rhs] {
+                    let result = contains_any_executor.execute((&enc_lhs, &rhs));
+
+                    assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str = "https://x";
+        let pats = ["http://", "https://"];
+
+        let expected_result = clear_function(str, &pats);
+
+        let enc_lhs = FheString::new(&cks, str, Some(1));
+        let enc_rhs = pats
+            .iter()
+            .map(|pat| GenericPattern::Enc(FheString::new(&cks, pat, Some(1))))
+            .collect::<Vec<_>>();
+        let clear_rhs = pats
+            .iter()
+            .map(|pat| GenericPattern::Clear(ClearString::new(pat.to_string())))
+            .collect::<Vec<_>>();
+
+        for rhs in [enc_rhs, clear_rhs] {
+            let result = contains_any_executor.execute((&enc_lhs, &rhs));
+
+            assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+        }
+    }
+}
+
+#[test]
+fn contains_char_test_parameterized() {
+    contains_char_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn contains_char_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString, c: u8| {
+        let sk = ServerKey::new(sk);
+        sk.contains_char(str, c)
+    });
+    contains_char_test_impl(param, executor);
+}
+
+pub(crate) fn contains_char_test_impl<P, T>(param: P, mut contains_char_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, u8), BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    contains_char_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in ["", "x", "axb", "ab", "bax"] {
+            for c in [b'x', b'a', 0] {
+                let expected_result = c != 0 && str.contains(c as char);
+
+                let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+                let result = contains_char_executor.execute((&enc_str, c));
+
+                assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+            }
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for str in ["axb", "ab"] {
+            let expected_result = str.contains('x');
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = contains_char_executor.execute((&enc_str, b'x'));
+
+            assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+        }
+    }
+}
+
+#[test]
+fn count_char_test_parameterized() {
+    count_char_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn count_char_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString, c: u8| {
+        let sk = ServerKey::new(sk);
+        sk.count_char(str, c)
+    });
+    count_char_test_impl(param, executor);
+}
+
+pub(crate) fn count_char_test_impl<P, T>(param: P, mut count_char_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, u8), RadixCiphertext>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    count_char_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in ["", "a", "banana", "xyz", "aab"] {
+            for c in [b'a', b'b', 0] {
+                let expected_result = if c == 0 {
+                    0
+                } else {
+                    str.matches(c as char).count() as u32
+                };
+
+                let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+                let result = count_char_executor.execute((&enc_str, c));
+
+                assert_eq!(expected_result, cks.inner().decrypt_radix::<u32>(&result));
+            }
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for (str, expected_result) in [("banana", 3), ("xyz", 0)] {
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = count_char_executor.execute((&enc_str, b'a'));
+
+            assert_eq!(expected_result, cks.inner().decrypt_radix::<u32>(&result));
+        }
+    }
+}
@@ -107,7 +107,8 @@ pub(crate) fn find_test_impl<P, T>(
         let str_pad = 1;
         let rhs_pad = 1;
 
-        for rhs in ["a", "c"] {
+        // The empty pattern matches at the end of the padded string for rfind
+        for rhs in ["a", "c", ""] {
             let expected_result = clear_function(str, rhs);
 
             let enc_lhs = FheString::new(&cks, str, Some(str_pad));
@@ -128,6 +129,90 @@ pub(crate) fn find_test_impl<P, T>(
     }
 }
 
+#[test]
+fn find_with_len_test_parameterized() {
+    find_with_len_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn find_with_len_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString, pat: &FheString| {
+            let sk = ServerKey::new(sk);
+            sk.find_with_len(str, pat)
+        });
+    find_with_len_test_impl(param, executor);
+}
+
+pub(crate) fn find_with_len_test_impl<P, T>(param: P, mut find_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a FheString, &'a FheString),
+        (RadixCiphertext, RadixCiphertext, BooleanBlock),
+    >,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    find_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for pat_pad in 0..2 {
+            for str in TEST_CASES_FIND {
+                for pat in PATTERN_FIND {
+                    let expected_result = str.find(pat).map(|index| (index, pat.len()));
+
+                    let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+                    let enc_pat = FheString::new_trivial(&cks, pat, Some(pat_pad));
+
+                    let (index, len, is_some) = find_executor.execute((&enc_str, &enc_pat));
+
+                    let dec_index = cks.inner().decrypt_radix::<u32>(&index);
+                    let dec_len = cks.inner().decrypt_radix::<u32>(&len);
+                    let dec_is_some = cks.inner().decrypt_bool(&is_some);
+
+                    let dec = dec_is_some.then_some((dec_index as usize, dec_len as usize));
+
+                    assert_eq!(dec, expected_result);
+                    if !dec_is_some {
+                        assert_eq!(dec_len, 0);
+                    }
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str = "xabx";
+        let pat_pad = 2;
+
+        for pat in ["ab", "ba"] {
+            let expected_result = str.find(pat).map(|index| (index, pat.len()));
+
+            let enc_str = FheString::new(&cks, str, None);
+            let enc_pat = FheString::new(&cks, pat, Some(pat_pad));
+
+            let (index, len, is_some) = find_executor.execute((&enc_str, &enc_pat));
+
+            let dec_index = cks.inner().decrypt_radix::<u32>(&index);
+            let dec_len = cks.inner().decrypt_radix::<u32>(&len);
+            let dec_is_some = cks.inner().decrypt_bool(&is_some);
+
+            let dec = dec_is_some.then_some((dec_index as usize, dec_len as usize));
+
+            assert_eq!(dec, expected_result);
+        }
+    }
+}
+
 #[test]
 fn replace_test_parameterized() {
     replace_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
@@ -207,11 +292,11 @@ where
         for from in ["a", "c"] {
             let expected_result = str.replace(from, to);
 
-            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
-            let enc_from = GenericPattern::Enc(FheString::new_trivial(&cks, from, Some(from_pad)));
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+            let enc_from = GenericPattern::Enc(FheString::new(&cks, from, Some(from_pad)));
             let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
 
-            let enc_to = FheString::new_trivial(&cks, to, Some(to_pad));
+            let enc_to = FheString::new(&cks, to, Some(to_pad));
 
             for from in [enc_from, clear_from] {
                 let result = replace_executor.execute((&enc_str, from.as_ref(), &enc_to));
@@ -222,6 +307,237 @@ where
             }
         }
     }
+    // many matches
+    for (str, from, to) in [
+        ("aaaaaa", "a", "bb"),
+        ("aaaaa", "aa", "b"),
+        ("abcabcab", "ab", ""),
+        ("xyxyx", "xyx", "z"),
+    ] {
+        let expected_result = str.replace(from, to);
+
+        for str_pad in 0..2 {
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+            let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
+            let enc_to = FheString::new(&cks, to, None);
+
+            let result = replace_executor.execute((&enc_str, clear_from.as_ref(), &enc_to));
+
+            let dec_result = cks.decrypt_ascii(&result);
+
+            assert_eq!(dec_result, expected_result);
+        }
+    }
+}
+
+#[test]
+fn replace_equal_len_test_parameterized() {
+    replace_equal_len_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn replace_equal_len_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   str: &FheString,
+                                   from: GenericPatternRef<'_>,
+                                   to: &FheString| {
+            let sk = ServerKey::new(sk);
+            sk.replace_equal_len(str, from, to)
+        });
+    replace_equal_len_test_impl(param, executor);
+}
+
+pub(crate) fn replace_equal_len_test_impl<P, T>(param: P, mut replace_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, GenericPatternRef<'a>, &'a FheString), FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    replace_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in TEST_CASES_FIND {
+            for (from, to) in [
+                ("", ""),
+                ("a", "x"),
+                ("ab", "XY"),
+                ("abc", "xyz"),
+                ("bc", "bb"),
+            ] {
+                let expected_result = str.replace(from, to);
+
+                let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+                let enc_from = GenericPattern::Enc(FheString::new_trivial(&cks, from, None));
+                let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
+
+                let enc_to = FheString::new_trivial(&cks, to, None);
+
+                for from in [enc_from, clear_from] {
+                    let result = replace_executor.execute((&enc_str, from.as_ref(), &enc_to));
+
+                    let dec_result = cks.decrypt_ascii(&result);
+
+                    assert_eq!(dec_result, expected_result);
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str = "ababc";
+        let str_pad = 1;
+
+        for from in ["ab", "ca"] {
+            let to = "XY";
+            let expected_result = str.replace(from, to);
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+            let enc_from = GenericPattern::Enc(FheString::new(&cks, from, None));
+            let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
+
+            let enc_to = FheString::new(&cks, to, None);
+
+            for from in [enc_from, clear_from] {
+                let result = replace_executor.execute((&enc_str, from.as_ref(), &enc_to));
+
+                let dec_result = cks.decrypt_ascii(&result);
+
+                assert_eq!(dec_result, expected_result);
+            }
+        }
+    }
+}
+
+#[test]
+fn normalize_newlines_test_parameterized() {
+    normalize_newlines_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn normalize_newlines_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.normalize_newlines(str)
+    });
+    normalize_newlines_test_impl(param, executor);
+}
+
+pub(crate) fn normalize_newlines_test_impl<P, T>(param: P, mut normalize_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    normalize_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in ["", "\r\n", "a\r\nb\r\n", "a\rb", "\r\r\n", "\n\r", "ab"] {
+            let expected_result = str.replace("\r\n", "\n");
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            let result = normalize_executor.execute(&enc_str);
+
+            assert_eq!(cks.decrypt_ascii(&result), expected_result);
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for str in ["a\r\nb\r\n", "a\rb"] {
+            let expected_result = str.replace("\r\n", "\n");
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = normalize_executor.execute(&enc_str);
+
+            assert_eq!(cks.decrypt_ascii(&result), expected_result);
+        }
+    }
+}
+
+#[test]
+fn translate_test_parameterized() {
+    translate_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn translate_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(
+        &|sk: &IntegerServerKey, str: &FheString, from: u8, to: u8| {
+            let sk = ServerKey::new(sk);
+            sk.translate(str, from, to)
+        },
+    );
+    translate_test_impl(param, executor);
+}
+
+pub(crate) fn translate_test_impl<P, T>(param: P, mut translate_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, u8, u8), FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    translate_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in ["", "a b c", "  ", "abc"] {
+            for (from, to) in [(b' ', b'_'), (b'a', b'z'), (b'b', b'b'), (0, b'x')] {
+                let expected_result = if from == 0 {
+                    str.to_owned()
+                } else {
+                    str.replace(from as char, &(to as char).to_string())
+                };
+
+                let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+                let result = translate_executor.execute((&enc_str, from, to));
+
+                assert_eq!(cks.decrypt_ascii(&result), expected_result);
+            }
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for (str, expected_result) in [("a b c", "a_b_c"), ("abc", "abc")] {
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = translate_executor.execute((&enc_str, b' ', b'_'));
+
+            assert_eq!(cks.decrypt_ascii(&result), expected_result);
+        }
+    }
 }
 
 #[test]
@@ -327,11 +643,11 @@ where
         for from in ["a", "c"] {
             let expected_result = str.replacen(from, to, n as usize);
 
-            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
-            let enc_from = GenericPattern::Enc(FheString::new_trivial(&cks, from, Some(from_pad)));
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+            let enc_from = GenericPattern::Enc(FheString::new(&cks, from, Some(from_pad)));
             let clear_from = GenericPattern::Clear(ClearString::new(from.to_string()));
 
-            let enc_to = FheString::new_trivial(&cks, to, Some(to_pad));
+            let enc_to = FheString::new(&cks, to, Some(to_pad));
 
             let clear_n = UIntArg::Clear(n);
             let enc_n = UIntArg::Enc(cks.encrypt_u16(n, Some(max)));
@@ -276,10 +276,12 @@ pub(crate) fn split_test_impl<P, T>(
         let str_pad = 1;
         let rhs_pad = 1;
 
-        for rhs in ["a", "c"] {
+        // The empty pattern is only bounded by the encrypted length of the padded string
+        for rhs in ["a", "c", ""] {
+            // Once exhausted, the iterator keeps returning None
             let expected: Vec<_> = clear_function(str, rhs)
                 .map(Some)
-                .chain(once(None))
+                .chain([None, None])
                 .collect();
 
             let enc_lhs = FheString::new(&cks, str, Some(str_pad));
@@ -102,6 +102,86 @@ pub(crate) fn to_lower_upper_case_test_impl<P, T>(
     }
 }
 
+fn clear_to_titlecase(str: &str) -> String {
+    let mut prev_is_whitespace = true;
+
+    str.chars()
+        .map(|c| {
+            let result = if prev_is_whitespace {
+                c.to_ascii_uppercase()
+            } else {
+                c.to_ascii_lowercase()
+            };
+            prev_is_whitespace = c.is_ascii_whitespace();
+            result
+        })
+        .collect()
+}
+
+#[test]
+fn to_titlecase_test_parameterized() {
+    to_titlecase_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn to_titlecase_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.to_titlecase(str)
+    });
+    to_titlecase_test_impl(param, executor);
+}
+
+pub(crate) fn to_titlecase_test_impl<P, T>(param: P, mut to_titlecase_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, FheString>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    to_titlecase_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    assert_eq!(clear_to_titlecase("hello world"), "Hello World");
+    assert_eq!(clear_to_titlecase("aBC dEf"), "Abc Def");
+
+    // trivial
+    for str_pad in 0..2 {
+        for str in UP_LOW_CASE
+            .into_iter()
+            .chain(["hello world", "aBC dEf", "a\tb\nc", "  x"])
+        {
+            let expected_result = clear_to_titlecase(str);
+
+            let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+            let result = to_titlecase_executor.execute(&enc_str);
+
+            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for str in ["aB c", "Ab C"] {
+            let expected_result = clear_to_titlecase(str);
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = to_titlecase_executor.execute(&enc_str);
+
+            assert_eq!(expected_result, cks.decrypt_ascii(&result));
+        }
+    }
+}
+
 #[test]
 fn eq_ignore_case_test_parameterized() {
     eq_ignore_case_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
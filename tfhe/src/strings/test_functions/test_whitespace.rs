@@ -1,12 +1,16 @@
 use crate::integer::keycache::KEY_CACHE;
 use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
 use crate::integer::server_key::radix_parallel::tests_unsigned::CpuFunctionExecutor;
-use crate::integer::{IntegerKeyKind, RadixClientKey, ServerKey as IntegerServerKey};
+use crate::integer::{
+    BooleanBlock, IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey as IntegerServerKey,
+};
 use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
 use crate::shortint::PBSParameters;
-use crate::strings::ciphertext::FheString;
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern, GenericPatternRef};
 use crate::strings::client_key::ClientKey;
-use crate::strings::server_key::{split_ascii_whitespace, FheStringIterator, ServerKey};
+use crate::strings::server_key::{
+    split_ascii_whitespace, split_inclusive_whitespace, FheStringIterator, ServerKey,
+};
 use std::iter::once;
 use std::sync::Arc;
 
@@ -112,6 +116,87 @@ pub(crate) fn trim_test_impl<P, T>(
     }
 }
 
+#[test]
+fn eq_ignore_trailing_whitespace_test_parameterized() {
+    eq_ignore_trailing_whitespace_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn eq_ignore_trailing_whitespace_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor =
+        CpuFunctionExecutor::new(&|sk: &IntegerServerKey,
+                                   lhs: &FheString,
+                                   rhs: GenericPatternRef<'_>| {
+            let sk = ServerKey::new(sk);
+
+            sk.eq_ignore_trailing_whitespace(lhs, rhs)
+        });
+    eq_ignore_trailing_whitespace_test_impl(param, executor);
+}
+
+pub(crate) fn eq_ignore_trailing_whitespace_test_impl<P, T>(param: P, mut eq_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a FheString, GenericPatternRef<'a>), BooleanBlock>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    eq_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for rhs_pad in 0..2 {
+            for (str, rhs) in [
+                ("abc ", "abc"),
+                ("abc", "abc\t\n"),
+                ("ab c", "abc"),
+                (" abc", "abc"),
+                ("", " "),
+                ("a", ""),
+            ] {
+                let expected_result = str.trim_end() == rhs.trim_end();
+
+                let enc_str = FheString::new_trivial(&cks, str, Some(str_pad));
+
+                let enc_rhs = GenericPattern::Enc(FheString::new_trivial(&cks, rhs, Some(rhs_pad)));
+                let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+                for rhs in [enc_rhs, clear_rhs] {
+                    let result = eq_executor.execute((&enc_str, rhs.as_ref()));
+
+                    assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+                }
+            }
+        }
+    }
+    // encrypted
+    {
+        let str_pad = 1;
+        let rhs_pad = 1;
+
+        for (str, rhs) in [("abc ", "abc"), ("ab c", "abc")] {
+            let expected_result = str.trim_end() == rhs.trim_end();
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+            let enc_rhs = GenericPattern::Enc(FheString::new(&cks, rhs, Some(rhs_pad)));
+            let clear_rhs = GenericPattern::Clear(ClearString::new(rhs.to_string()));
+
+            for rhs in [enc_rhs, clear_rhs] {
+                let result = eq_executor.execute((&enc_str, rhs.as_ref()));
+
+                assert_eq!(expected_result, cks.inner().decrypt_bool(&result));
+            }
+        }
+    }
+}
+
 #[test]
 fn split_whitespace_test_parameterized() {
     split_whitespace_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
@@ -222,3 +307,206 @@ where
         }
     }
 }
+
+#[test]
+fn split_inclusive_whitespace_test_parameterized() {
+    split_inclusive_whitespace_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn split_inclusive_whitespace_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    #[allow(clippy::type_complexity)]
+    let fhe_func: fn(
+        &IntegerServerKey,
+        &FheString,
+    ) -> Box<dyn for<'a> FheStringIterator<&'a IntegerServerKey>> =
+        |_sk, str| Box::new(split_inclusive_whitespace(str));
+
+    let executor = CpuFunctionExecutor::new(&fhe_func);
+
+    split_inclusive_whitespace_test_impl(param, executor);
+}
+
+pub(crate) fn split_inclusive_whitespace_test_impl<P, T>(
+    param: P,
+    mut split_inclusive_whitespace_executor: T,
+) where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        &'a FheString,
+        Box<dyn for<'b> FheStringIterator<&'b IntegerServerKey>>,
+    >,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    split_inclusive_whitespace_executor.setup(&cks2, sks.clone());
+
+    let sks = ServerKey::new(&*sks);
+    let cks = ClientKey::new(cks);
+
+    // A new segment starts at each non whitespace char that follows a whitespace
+    let clear_split_inclusive_whitespace = |str: &str| {
+        let mut segments = vec![];
+        let mut current = String::new();
+        let mut prev_is_ws = false;
+        for c in str.chars() {
+            if prev_is_ws && !c.is_ascii_whitespace() {
+                segments.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+            prev_is_ws = c.is_ascii_whitespace();
+        }
+        if !current.is_empty() {
+            segments.push(current);
+        }
+        segments
+    };
+
+    let mut check = |str: &str, enc_str: &FheString| {
+        let segments = clear_split_inclusive_whitespace(str);
+        let expected: Vec<_> = segments
+            .iter()
+            .map(|segment| Some(segment.as_str()))
+            .chain(once(None))
+            .collect();
+
+        let mut iterator = split_inclusive_whitespace_executor.execute(enc_str);
+
+        let mut concatenated = String::new();
+        for expected in &expected {
+            let (split, is_some) = iterator.next(&sks);
+
+            let dec_split = cks.decrypt_ascii(&split);
+            let dec_is_some = cks.inner().decrypt_bool(&is_some);
+
+            if dec_is_some {
+                concatenated.push_str(&dec_split);
+            }
+
+            let dec = dec_is_some.then_some(dec_split);
+
+            assert_eq!(expected, &dec.as_deref())
+        }
+
+        assert_eq!(concatenated, str);
+    };
+
+    // trivial
+    for str_pad in 0..2 {
+        for ws in WHITESPACES {
+            #[allow(clippy::useless_format)]
+            for str in [
+                format!(""),
+                format!("{ws}"),
+                format!("a{ws}"),
+                format!("{ws}a"),
+                format!("a{ws}a"),
+                format!("{ws}{ws}a"),
+                format!("a{ws}{ws}a"),
+                format!("{ws}a{ws}a{ws}"),
+                format!("ab{ws} {ws}cd{ws}"),
+            ] {
+                let enc_str = FheString::new_trivial(&cks, &str, Some(str_pad));
+
+                check(&str, &enc_str);
+            }
+        }
+    }
+
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for str in [" a", "a b"] {
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            check(str, &enc_str);
+        }
+    }
+}
+
+#[test]
+fn count_words_test_parameterized() {
+    count_words_test(PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn count_words_test<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&|sk: &IntegerServerKey, str: &FheString| {
+        let sk = ServerKey::new(sk);
+        sk.count_words(str)
+    });
+    count_words_test_impl(param, executor);
+}
+
+pub(crate) fn count_words_test_impl<P, T>(param: P, mut count_words_executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<&'a FheString, RadixCiphertext>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks2 = RadixClientKey::from((cks.clone(), 0));
+
+    count_words_executor.setup(&cks2, sks);
+
+    let cks = ClientKey::new(cks);
+
+    // trivial
+    for str_pad in 0..2 {
+        for ws in WHITESPACES {
+            #[allow(clippy::useless_format)]
+            for str in [
+                format!(""),
+                format!("{ws}"),
+                format!("a"),
+                format!("{ws}{ws}"),
+                format!("a{ws}"),
+                format!("{ws}a"),
+                format!("ab{ws}c"),
+                format!("a{ws}{ws}b"),
+                format!("{ws}a{ws}b{ws}"),
+                format!("a{ws} {ws}bc{ws}d"),
+            ] {
+                let expected_result = str.split_whitespace().count();
+
+                let enc_str = FheString::new_trivial(&cks, &str, Some(str_pad));
+
+                let result = count_words_executor.execute(&enc_str);
+
+                assert_eq!(
+                    cks.inner().decrypt_radix::<u16>(&result),
+                    expected_result as u16,
+                    "Invalid word count of {str:?}"
+                );
+            }
+        }
+    }
+
+    // encrypted
+    {
+        let str_pad = 1;
+
+        for str in ["a b", " ab ", "a\t\nb c"] {
+            let expected_result = str.split_whitespace().count();
+
+            let enc_str = FheString::new(&cks, str, Some(str_pad));
+
+            let result = count_words_executor.execute(&enc_str);
+
+            assert_eq!(
+                cks.inner().decrypt_radix::<u16>(&result),
+                expected_result as u16,
+                "Invalid word count of {str:?}"
+            );
+        }
+    }
+}